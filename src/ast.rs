@@ -1,13 +1,76 @@
+//! The Abstract Syntax Tree of parsed expressions
+//!
+//! A tree can be evaluated any number of times against different reference
+//! datetimes. With the `serde` feature every node implements `Serialize`
+//! and `Deserialize`, so parsed expressions can be stored and re-evaluated
+//! later.
+//!
+//! ```rust
+//! use chrono::{NaiveDate, NaiveTime};
+//! use fuzzydate::{ast::DateTime, lexer::Lexeme};
+//!
+//! let lexemes = Lexeme::lex_line("3 days after tomorrow").unwrap();
+//! let (tree, _) = DateTime::parse(&lexemes).unwrap();
+//!
+//! let now = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap().and_time(NaiveTime::MIN);
+//! let date = tree.to_chrono(NaiveTime::MIN, now).unwrap();
+//! assert_eq!(date.to_string(), "2024-06-05 00:00:00");
+//! ```
+//!
+//! Trees can also be built by hand, e.g. by tools generating expressions,
+//! and are evaluated exactly like parsed ones.
+//!
+//! ```rust
+//! use chrono::{NaiveDate, NaiveTime};
+//! use fuzzydate::ast::{Date, DateTime, Duration, Time, Unit};
+//!
+//! let tree = DateTime::after(Duration::new(3, Unit::Day), Date::Tomorrow.into())
+//!     .with_time(Time::HourMinPM(5, 0));
+//!
+//! let now = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap().and_time(NaiveTime::MIN);
+//! let date = tree.to_chrono(NaiveTime::MIN, now).unwrap();
+//! assert_eq!(date.to_string(), "2024-06-05 17:00:00");
+//! ```
+//!
+//! The [`visit`] module walks trees, to inspect or rewrite their nodes.
+//!
+//! ## Stability
+//!
+//! The tree is part of the public API and follows semantic versioning: a
+//! node keeps its meaning, and evaluates to the same result, until the next
+//! breaking release. New kinds of expressions may be added in any release,
+//! so the enums which may grow are `#[non_exhaustive]`. Nodes referring to
+//! options by index, such as [`Date::Named`], are only meaningful with the
+//! options they were parsed with.
+
 use chrono::{
-    Datelike, Duration as ChronoDuration, Local, NaiveDate as ChronoDate,
-    NaiveDateTime as ChronoDateTime, NaiveTime as ChronoTime, Weekday as ChronoWeekday,
+    Datelike, Duration as ChronoDuration, NaiveDate as ChronoDate, NaiveDateTime as ChronoDateTime,
+    NaiveTime as ChronoTime, Timelike, Weekday as ChronoWeekday,
 };
 
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Bound;
+
 use crate::lexer::Lexeme;
+use crate::memo::{self, Rule};
+use crate::stats;
+use crate::{
+    Bounds, Connector, DateOrder, DayPart, HalfHour, MonthOverflow, ParseOptions, Quantity,
+    WeekdayCheck,
+};
+
+pub mod visit;
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(clippy::enum_variant_names)]
 /// Root of the Abstract Syntax Tree, represents a fully parsed DateTime
+#[non_exhaustive]
 pub enum DateTime {
     /// Standard date and time
     DateTime(Date, Time),
@@ -21,56 +84,324 @@ pub enum DateTime {
     Ago(Duration),
     /// The current datetime
     Now,
+    /// The unix epoch
+    Epoch,
+    /// The nth weekday after a datetime, e.g. "the Friday after next month"
+    /// or "3 Sundays after Easter"
+    WeekdayAfter(u32, Weekday, Box<DateTime>),
+    /// The nth weekday before a datetime
+    WeekdayBefore(u32, Weekday, Box<DateTime>),
+    /// A datetime whose time is replaced, e.g. "(3 hours after noon) at 5pm"
+    WithTime(Box<DateTime>, Time),
+    /// Minutes past the current hour, e.g. "10 past the hour" or "at half
+    /// past"
+    PastHour(u32),
 }
 
 impl DateTime {
-    /// Parse a datetime from a slice of lexemes
+    /// Parse a datetime from a slice of lexemes.
+    ///
+    /// A time following a comma applies to the whole expression, e.g. in
+    /// "2 hours before the end of the month, at 5pm" the result is at 5pm.
     pub fn parse(l: &[Lexeme]) -> Option<(Self, usize)> {
+        memo::scoped(Rule::DateTime, l, Self::parse_scoped)
+    }
+
+    fn parse_scoped(l: &[Lexeme]) -> Option<(Self, usize)> {
+        stats::attempt();
+        let (datetime, mut tokens) = Self::parse_expr(l)?;
+
+        if let Some(&Lexeme::Comma | &Lexeme::WhichIs) = l.get(tokens) {
+            let mut t = 1;
+            if l.get(tokens) == Some(&Lexeme::Comma) && l.get(tokens + 1) == Some(&Lexeme::WhichIs)
+            {
+                t += 1;
+            }
+
+            if let Some((time, t2)) = Time::parse_at(&l[tokens + t..]) {
+                if time != Time::Empty {
+                    tokens += t + t2;
+                    return Some((Self::WithTime(stats::boxed(datetime), time), tokens));
+                }
+            }
+        }
+
+        Some((datetime, tokens))
+    }
+
+    /// Parse a datetime, including trailing offsets but not a trailing time
+    fn parse_expr(l: &[Lexeme]) -> Option<(Self, usize)> {
+        stats::attempt();
+        let (mut datetime, mut tokens) = Self::parse_single(l)?;
+
+        // Any datetime may be followed by offsets, e.g. "2024-150 + 12h"
+        loop {
+            let op = l.get(tokens);
+            if op != Some(&Lexeme::Plus) && op != Some(&Lexeme::Dash) {
+                break;
+            }
+
+            let Some((dur, t)) = Duration::parse(&l[tokens + 1..]) else {
+                break;
+            };
+            tokens += t + 1;
+
+            datetime = if op == Some(&Lexeme::Plus) {
+                Self::After(dur, stats::boxed(datetime))
+            } else {
+                Self::Before(dur, stats::boxed(datetime))
+            };
+        }
+
+        Some((datetime, tokens))
+    }
+
+    /// Parse a datetime without trailing offsets
+    fn parse_single(l: &[Lexeme]) -> Option<(Self, usize)> {
+        stats::attempt();
         let mut tokens = 0;
         if l.get(tokens) == Some(&Lexeme::Now) {
             tokens += 1;
             return Some((Self::Now, tokens));
         }
 
+        // Minutes past the current hour, e.g. "10 minutes past the hour" or
+        // "at half past" at the end of the input, but not past a given
+        // hour, e.g. "10 past 5" or "10 past noon"
+        stats::backtrack(tokens);
+        tokens = 0;
+        if l.get(tokens) == Some(&Lexeme::At) {
+            tokens += 1;
+        }
+
+        let min = match l.get(tokens) {
+            Some(Lexeme::Half) => Some((30, 1)),
+            Some(Lexeme::Quarter) => Some((15, 1)),
+            _ => Num::parse(&l[tokens..]).map(|(min, t)| match l.get(tokens + t) {
+                Some(Lexeme::Minute) => (min, t + 1),
+                _ => (min, t),
+            }),
+        };
+
+        if let Some((min, t)) = min {
+            if l.get(tokens + t) == Some(&Lexeme::Past) {
+                tokens += t + 1;
+
+                if l.get(tokens) == Some(&Lexeme::The) && l.get(tokens + 1) == Some(&Lexeme::Hour) {
+                    tokens += 2;
+                    return Some((Self::PastHour(min), tokens));
+                }
+
+                if l.get(tokens).is_none() {
+                    return Some((Self::PastHour(min), tokens));
+                }
+            }
+        }
+
+        // A part of today, e.g. "tonight" or "this evening"
+        stats::backtrack(tokens);
+        tokens = 0;
+        if l.get(tokens) == Some(&Lexeme::Tonight) {
+            tokens += 1;
+
+            let (time, t) = Time::parse_day_part(DayPart::Night, &l[tokens..]);
+            tokens += t;
+            return Some((Self::DateTime(Date::Today, time), tokens));
+        }
+
+        if l.get(tokens) == Some(&Lexeme::This) {
+            if let Some(part) = day_part(l.get(tokens + 1)) {
+                tokens += 2;
+
+                let (time, t) = Time::parse_day_part(part, &l[tokens..]);
+                tokens += t;
+                return Some((Self::DateTime(Date::Today, time), tokens));
+            }
+        }
+
+        // Parentheses group an expression, which may be followed by a time
+        // applying to the whole group, e.g. "(3 hours after noon) at 5pm"
+        stats::backtrack(tokens);
+        tokens = 0;
+        if l.get(tokens) == Some(&Lexeme::LParen) {
+            tokens += 1;
+
+            let (datetime, t) = DateTime::parse(&l[tokens..])?;
+            tokens += t;
+
+            if l.get(tokens) != Some(&Lexeme::RParen) {
+                return None;
+            }
+            tokens += 1;
+
+            if let Some((time, t)) = Time::parse_at(&l[tokens..]) {
+                if time != Time::Empty {
+                    tokens += t;
+                    return Some((Self::WithTime(stats::boxed(datetime), time), tokens));
+                }
+            }
+
+            return Some((datetime, tokens));
+        }
+
+        // "epoch" may be introduced by an article, e.g. "after the epoch"
+        stats::backtrack(tokens);
+        tokens = 0;
+        if l.get(tokens) == Some(&Lexeme::The) {
+            tokens += 1;
+        }
+
+        if l.get(tokens) == Some(&Lexeme::Epoch) {
+            tokens += 1;
+            return Some((Self::Epoch, tokens));
+        }
+
+        // A duration from now, e.g. "in 3 days"
+        stats::backtrack(tokens);
+        tokens = 0;
+        if l.get(tokens) == Some(&Lexeme::In) {
+            tokens += 1;
+
+            if let Some((dur, t)) = Duration::parse(&l[tokens..]) {
+                tokens += t;
+                return Some((Self::After(dur, stats::boxed(Self::Now)), tokens));
+            }
+        }
+
+        // Some languages put "ago" first, e.g. "vor 3 Tagen" or "hace 3 días"
+        stats::backtrack(tokens);
+        tokens = 0;
+        if l.get(tokens) == Some(&Lexeme::Ago) {
+            tokens += 1;
+
+            if let Some((dur, t)) = Duration::parse(&l[tokens..]) {
+                tokens += t;
+                return Some((Self::Ago(dur), tokens));
+            }
+        }
+
+        stats::backtrack(tokens);
         tokens = 0;
+
         if let Some((dur, t)) = Duration::parse(&l[tokens..]) {
             tokens += t;
 
             if Some(&Lexeme::After) == l.get(tokens) || Some(&Lexeme::From) == l.get(tokens) {
                 tokens += 1;
 
-                if let Some((datetime, t)) = DateTime::parse(&l[tokens..]) {
+                if let Some((datetime, t)) = DateTime::parse_expr(&l[tokens..]) {
                     tokens += t;
-                    return Some((Self::After(dur, Box::new(datetime)), tokens));
+                    return Some((Self::After(dur, stats::boxed(datetime)), tokens));
                 }
             } else if Some(&Lexeme::Before) == l.get(tokens) {
                 tokens += 1;
 
-                if let Some((datetime, t)) = DateTime::parse(&l[tokens..]) {
+                if let Some((datetime, t)) = DateTime::parse_expr(&l[tokens..]) {
                     tokens += t;
-                    return Some((Self::Before(dur, Box::new(datetime)), tokens));
+                    return Some((Self::Before(dur, stats::boxed(datetime)), tokens));
                 }
             } else if Some(&Lexeme::Ago) == l.get(tokens) {
                 tokens += 1;
+
+                // "ago" may be anchored to a datetime, e.g. "50 years ago today"
+                if let Some((datetime, t)) = DateTime::parse_expr(&l[tokens..]) {
+                    tokens += t;
+                    return Some((Self::Before(dur, stats::boxed(datetime)), tokens));
+                }
+
                 return Some((Self::Ago(dur), tokens));
             }
         }
 
+        // The next or previous business day around a datetime, e.g. "the
+        // following working day after July 4th"
+        stats::backtrack(tokens);
         tokens = 0;
-        if let Some((date, t)) = Date::parse(&l[tokens..]) {
-            tokens += t;
-            if l.get(tokens) == Some(&Lexeme::Comma) {
+        if l.get(tokens) == Some(&Lexeme::The) {
+            tokens += 1;
+        }
+
+        let relspec = l.get(tokens);
+        if let Some(&Lexeme::Next | &Lexeme::Last) = relspec {
+            tokens += 1;
+
+            if l.get(tokens) == Some(&Lexeme::BusinessDay) {
                 tokens += 1;
+
+                let dur = Duration::Article(Unit::BusinessDay);
+                match (relspec, l.get(tokens)) {
+                    (Some(Lexeme::Next), Some(Lexeme::After)) => {
+                        tokens += 1;
+                        if let Some((datetime, t)) = DateTime::parse_expr(&l[tokens..]) {
+                            tokens += t;
+                            return Some((Self::After(dur, stats::boxed(datetime)), tokens));
+                        }
+                    }
+                    (Some(Lexeme::Last), Some(Lexeme::Before)) => {
+                        tokens += 1;
+                        if let Some((datetime, t)) = DateTime::parse_expr(&l[tokens..]) {
+                            tokens += t;
+                            return Some((Self::Before(dur, stats::boxed(datetime)), tokens));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // A weekday relative to a datetime, e.g. "the Friday after Easter",
+        // which may be counted, e.g. "3 Sundays after Easter"
+        stats::backtrack(tokens);
+        tokens = 0;
+        let mut n = 1;
+        if l.get(tokens) == Some(&Lexeme::The) {
+            tokens += 1;
+        } else if let Some((count, t)) = Num::parse(&l[tokens..]) {
+            tokens += t;
+            n = count;
+        }
+
+        if let Some((weekday, t)) = Weekday::parse(&l[tokens..]).filter(|_| n > 0) {
+            tokens += t;
+
+            match l.get(tokens) {
+                Some(Lexeme::After) => {
+                    tokens += 1;
+                    if let Some((datetime, t)) = DateTime::parse_expr(&l[tokens..]) {
+                        tokens += t;
+                        let datetime = stats::boxed(datetime);
+                        return Some((Self::WeekdayAfter(n, weekday, datetime), tokens));
+                    }
+                }
+                Some(Lexeme::Before) => {
+                    tokens += 1;
+                    if let Some((datetime, t)) = DateTime::parse_expr(&l[tokens..]) {
+                        tokens += t;
+                        let datetime = stats::boxed(datetime);
+                        return Some((Self::WeekdayBefore(n, weekday, datetime), tokens));
+                    }
+                }
+                _ => {}
             }
+        }
+
+        stats::backtrack(tokens);
+        tokens = 0;
 
-            if let Some((time, t)) = Time::parse(&l[tokens..]) {
+        if let Some((date, t)) = Date::parse(&l[tokens..]) {
+            tokens += t;
+
+            // A time after a comma is left for the whole expression
+            if let Some((time, t)) = Time::parse_at(&l[tokens..]) {
                 tokens += t;
                 return Some((Self::DateTime(date, time), tokens));
             }
         }
 
+        stats::backtrack(tokens);
         tokens = 0;
-        if let Some((time, t)) = Time::parse(&l[tokens..]) {
+
+        if let Some((time, t)) = Time::parse_at(&l[tokens..]) {
             tokens += t;
             if l.get(tokens) == Some(&Lexeme::Comma) {
                 tokens += 1;
@@ -85,83 +416,1255 @@ impl DateTime {
         None
     }
 
+    /// Whether the datetime only uses the documented grammar, see
+    /// [`crate::ParseOptions::minimal_grammar`]
+    pub(crate) fn is_documented(&self) -> bool {
+        match self {
+            DateTime::DateTime(date, _) => date.is_documented(),
+            // A time before its date, e.g. "5pm tomorrow"
+            DateTime::TimeDate(..) => false,
+            DateTime::After(_, anchor)
+            | DateTime::Before(_, anchor)
+            | DateTime::WeekdayAfter(_, _, anchor)
+            | DateTime::WeekdayBefore(_, _, anchor)
+            | DateTime::WithTime(anchor, _) => anchor.is_documented(),
+            DateTime::Ago(_) | DateTime::Now | DateTime::Epoch | DateTime::PastHour(_) => true,
+        }
+    }
+
+    /// Describe how the datetime is resolved in words, e.g. "3 days after
+    /// the first Friday on or after a week from today, at 5:00 pm"
+    ///
+    /// ```rust
+    /// use fuzzydate::{ast::DateTime, lexer::Lexeme, ParseOptions};
+    ///
+    /// let lexemes = Lexeme::lex_line("2 days after tomorrow at 5pm").unwrap();
+    /// let (tree, _) = DateTime::parse(&lexemes).unwrap();
+    /// let description = tree.describe(&ParseOptions::new());
+    /// assert_eq!(description, "2 days after tomorrow at 5:00 pm");
+    /// ```
+    pub fn describe(&self, options: &ParseOptions) -> String {
+        let with_time = |date: &Date, time: &Time| match time.describe() {
+            Some(time) => format!("{} {time}", date.describe(options)),
+            None => date.describe(options),
+        };
+
+        match self {
+            DateTime::DateTime(date, time) | DateTime::TimeDate(time, date) => {
+                with_time(date, time)
+            }
+            DateTime::After(dur, anchor) => format!("{dur} after {}", anchor.describe(options)),
+            DateTime::Before(dur, anchor) => format!("{dur} before {}", anchor.describe(options)),
+            DateTime::Ago(dur) => format!("{dur} ago"),
+            DateTime::Now => "now".to_owned(),
+            DateTime::Epoch => "the unix epoch".to_owned(),
+            DateTime::PastHour(min) => format!("{min} minutes past the current hour"),
+            DateTime::WeekdayAfter(n, weekday, anchor) => format!(
+                "the {} {weekday:?} after {}",
+                ordinal(*n),
+                anchor.describe(options)
+            ),
+            DateTime::WeekdayBefore(n, weekday, anchor) => format!(
+                "the {} {weekday:?} before {}",
+                ordinal(*n),
+                anchor.describe(options)
+            ),
+            DateTime::WithTime(datetime, time) => match time.describe() {
+                Some(time) => format!("{}, {time}", datetime.describe(options)),
+                None => datetime.describe(options),
+            },
+        }
+    }
+
+    /// Write the datetime in English the way the parser reads it back,
+    /// e.g. to echo a normalized version of the input
+    ///
+    /// ```rust
+    /// use fuzzydate::{ast::DateTime, lexer::Lexeme, ParseOptions};
+    ///
+    /// let lexemes = Lexeme::lex_line("2d4h after Tomorrow at 5pm").unwrap();
+    /// let (tree, _) = DateTime::parse(&lexemes).unwrap();
+    /// let canonical = tree.to_canonical_string(&ParseOptions::new());
+    /// assert_eq!(canonical, "2 days and 4 hours after tomorrow at 5:00 pm");
+    ///
+    /// let lexemes = Lexeme::lex_line(&canonical).unwrap();
+    /// assert_eq!(DateTime::parse(&lexemes).unwrap().0, tree);
+    /// ```
+    pub fn to_canonical_string(&self, options: &ParseOptions) -> String {
+        // Anchors keep their own trailing time, so one applying to the
+        // whole anchor is wrapped to stop it applying to the expression
+        let anchor = |datetime: &DateTime| match datetime {
+            DateTime::WithTime(..) => format!("({})", datetime.to_canonical_string(options)),
+            _ => datetime.to_canonical_string(options),
+        };
+        let weekdays = |n: u32, weekday: &Weekday| match n {
+            1 => format!("the {}", weekday.canonical()),
+            n => format!("{n} {}s", weekday.canonical()),
+        };
+
+        match self {
+            DateTime::DateTime(date, time) => match time.canonical() {
+                Some(time) => format!("{} {time}", date.canonical(options)),
+                None => date.canonical(options),
+            },
+            DateTime::TimeDate(time, date) => match time.canonical() {
+                Some(time) => format!("{time} {}", date.canonical(options)),
+                None => date.canonical(options),
+            },
+            DateTime::After(dur, datetime) => {
+                format!("{} after {}", dur.canonical(), anchor(datetime))
+            }
+            DateTime::Before(dur, datetime) => {
+                format!("{} before {}", dur.canonical(), anchor(datetime))
+            }
+            DateTime::Ago(dur) => format!("{} ago", dur.canonical()),
+            DateTime::Now => "now".to_owned(),
+            DateTime::Epoch => "epoch".to_owned(),
+            DateTime::PastHour(min) => format!("{min} minutes past the hour"),
+            DateTime::WeekdayAfter(n, weekday, datetime) => {
+                format!("{} after {}", weekdays(*n, weekday), anchor(datetime))
+            }
+            DateTime::WeekdayBefore(n, weekday, datetime) => {
+                format!("{} before {}", weekdays(*n, weekday), anchor(datetime))
+            }
+            DateTime::WithTime(datetime, time) => match time.canonical() {
+                Some(time) => format!("{}, {time}", anchor(datetime)),
+                None => datetime.to_canonical_string(options),
+            },
+        }
+    }
+
+    /// Where the explicit time of the datetime, if any, applies
+    pub fn time_binding(&self) -> crate::TimeBinding {
+        use crate::TimeBinding;
+
+        match self {
+            DateTime::DateTime(_, Time::Empty) | DateTime::TimeDate(Time::Empty, _) => {
+                TimeBinding::Default
+            }
+            DateTime::DateTime(..)
+            | DateTime::TimeDate(..)
+            | DateTime::WithTime(..)
+            | DateTime::PastHour(_) => TimeBinding::Expression,
+            DateTime::After(_, anchor)
+            | DateTime::Before(_, anchor)
+            | DateTime::WeekdayAfter(_, _, anchor)
+            | DateTime::WeekdayBefore(_, _, anchor) => match anchor.time_binding() {
+                TimeBinding::Default => TimeBinding::Default,
+                _ => TimeBinding::Anchor,
+            },
+            DateTime::Ago(_) | DateTime::Now | DateTime::Epoch => TimeBinding::Default,
+        }
+    }
+
+    /// Whether the datetime is a whole day, given without a time and only
+    /// moved by whole days, e.g. "friday" or "2 weeks after June 3"
+    pub(crate) fn is_date_only(&self) -> bool {
+        match self {
+            DateTime::DateTime(_, Time::Empty) | DateTime::TimeDate(Time::Empty, _) => true,
+            DateTime::After(dur, anchor) | DateTime::Before(dur, anchor) => {
+                let mut whole_days = true;
+                dur.for_each_part(&mut |_, unit| {
+                    whole_days &= !matches!(unit, Unit::Hour | Unit::Minute);
+                });
+                whole_days && anchor.is_date_only()
+            }
+            DateTime::WeekdayAfter(_, _, anchor) | DateTime::WeekdayBefore(_, _, anchor) => {
+                anchor.is_date_only()
+            }
+            DateTime::DateTime(..)
+            | DateTime::TimeDate(..)
+            | DateTime::WithTime(..)
+            | DateTime::PastHour(_)
+            | DateTime::Ago(_)
+            | DateTime::Now
+            | DateTime::Epoch => false,
+        }
+    }
+
+    /// The first date led by a weekday it doesn't fall on, see
+    /// [`crate::WeekdayCheck::Warn`]
+    pub(crate) fn weekday_mismatch(
+        &self,
+        today: ChronoDate,
+        options: &ParseOptions,
+    ) -> Option<crate::WeekdayMismatch> {
+        match self {
+            DateTime::DateTime(date, _) | DateTime::TimeDate(_, date) => {
+                date.weekday_mismatch(today, options)
+            }
+            DateTime::WithTime(datetime, _)
+            | DateTime::After(_, datetime)
+            | DateTime::Before(_, datetime)
+            | DateTime::WeekdayAfter(_, _, datetime)
+            | DateTime::WeekdayBefore(_, _, datetime) => datetime.weekday_mismatch(today, options),
+            DateTime::Ago(_) | DateTime::Now | DateTime::Epoch | DateTime::PastHour(_) => None,
+        }
+    }
+
+    /// A date at a time, e.g. "tomorrow at 5pm"
+    pub fn at(date: Date, time: Time) -> Self {
+        DateTime::DateTime(date, time)
+    }
+
+    /// A duration after a datetime, e.g. "3 days after tomorrow"
+    pub fn after(duration: Duration, anchor: DateTime) -> Self {
+        DateTime::After(duration, Box::new(anchor))
+    }
+
+    /// A duration before a datetime, e.g. "3 days before tomorrow"
+    pub fn before(duration: Duration, anchor: DateTime) -> Self {
+        DateTime::Before(duration, Box::new(anchor))
+    }
+
+    /// A duration before the current datetime, e.g. "3 days ago"
+    pub fn ago(duration: Duration) -> Self {
+        DateTime::Ago(duration)
+    }
+
+    /// The datetime with its time replaced, e.g. "3 days after tomorrow,
+    /// at 5pm"
+    pub fn with_time(self, time: Time) -> Self {
+        DateTime::WithTime(Box::new(self), time)
+    }
+
     /// Convert a parsed DateTime to chrono's NaiveDateTime
     pub fn to_chrono(
         &self,
         default: ChronoTime,
-        relative_to: Option<ChronoDateTime>,
+        now: ChronoDateTime,
+    ) -> Result<ChronoDateTime, crate::Error> {
+        self.to_chrono_with(default, now, &ParseOptions::default())
+    }
+
+    /// Convert a parsed DateTime to chrono's NaiveDateTime, interpreting
+    /// it according to the given options
+    pub fn to_chrono_with(
+        &self,
+        default: ChronoTime,
+        now: ChronoDateTime,
+        options: &ParseOptions,
     ) -> Result<ChronoDateTime, crate::Error> {
-        let now = relative_to.unwrap_or(Local::now().naive_local());
         Ok(match self {
             DateTime::Now => now,
+            DateTime::Epoch => options.epoch(),
+            DateTime::PastHour(min) => {
+                now.date()
+                    .and_hms_opt(now.hour(), *min, 0)
+                    .ok_or(crate::Error::invalid_date(format!(
+                        "Invalid time: {min} minutes past the hour"
+                    )))?
+            }
+            DateTime::WithTime(datetime, time) => {
+                let datetime = datetime.to_chrono_with(default, now, options)?;
+                let date = time.date_from(datetime.date())?;
+                ChronoDateTime::new(date, time.to_chrono(default, options)?)
+            }
             DateTime::DateTime(date, time) => {
-                let date = date.to_chrono(Some(now.date()))?;
-                let time = time.to_chrono(default)?;
+                let date = time.date_from(date.to_chrono(now.date(), options)?)?;
+                let time = time.to_chrono(default, options)?;
 
                 ChronoDateTime::new(date, time)
             }
             DateTime::TimeDate(time, date) => {
-                let date = date.to_chrono(Some(now.date()))?;
-                let time = time.to_chrono(default)?;
+                let date = time.date_from(date.to_chrono(now.date(), options)?)?;
+                let time = time.to_chrono(default, options)?;
 
                 ChronoDateTime::new(date, time)
             }
             DateTime::After(dur, date) => {
-                let date = date.to_chrono(default, relative_to)?;
-                dur.after(date)
+                let date = date.to_chrono_with(default, now, options)?;
+                dur.after(date, options)?
             }
             DateTime::Before(dur, date) => {
-                let date = date.to_chrono(default, relative_to)?;
-                dur.before(date)
+                let date = date.to_chrono_with(default, now, options)?;
+                dur.before(date, options)?
+            }
+            DateTime::Ago(dur) => dur.before(now, options)?,
+            DateTime::WeekdayAfter(n, weekday, anchor) => {
+                let anchor = anchor.to_chrono_with(default, now, options)?;
+                let days = weekdays_apart(anchor.weekday(), weekday.to_chrono(), *n);
+                anchor.checked_add_days(chrono::Days::new(days)).ok_or(
+                    crate::Error::invalid_date("Date out of representable date range."),
+                )?
+            }
+            DateTime::WeekdayBefore(n, weekday, anchor) => {
+                let anchor = anchor.to_chrono_with(default, now, options)?;
+                let days = weekdays_apart(weekday.to_chrono(), anchor.weekday(), *n);
+                anchor.checked_sub_days(chrono::Days::new(days)).ok_or(
+                    crate::Error::invalid_date("Date out of representable date range."),
+                )?
             }
-            DateTime::Ago(dur) => dur.before(now),
         })
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
-/// A Parsed Date
-pub enum Date {
-    MonthNumDayYear(u32, u32, u32),
-    MonthDayYear(Month, u32, u32),
-    MonthNumDay(u32, u32),
-    MonthDay(Month, u32),
-    UnitRelative(RelativeSpecifier, Unit),
-    Relative(RelativeSpecifier, Weekday),
-    Weekday(Weekday),
-    Today,
-    Tomorrow,
-    Yesterday,
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Root of the Abstract Syntax Tree for expressions spanning a period of time
+#[non_exhaustive]
+pub enum Range {
+    /// A counted period ending at the current datetime, e.g. "last 3 months"
+    Last(Duration),
+    /// A counted period starting at the current datetime, e.g. "next 2 weeks"
+    Next(Duration),
+    /// An ISO 8601 interval between two datetimes, e.g. "2024-01-01/2024-02-01"
+    Interval(DateTime, DateTime),
+    /// An ISO 8601 interval from a start and a duration, e.g. "2024-01-01/P1M"
+    IntervalAfter(DateTime, Duration),
+    /// An ISO 8601 interval from a duration and an end, e.g. "P1M/2024-02-01"
+    IntervalBefore(Duration, DateTime),
+    /// A registered named period, holding its index in the options,
+    /// e.g. "the fall semester"
+    Named(usize, RelativeSpecifier),
+    /// Two datetimes joined by words, e.g. "monday through friday"
+    Connected(DateTime, Connector, DateTime),
+    /// Two times of the same day joined by words, e.g. "tomorrow 9am to
+    /// 5pm", today unless a date is given
+    Times(Date, Time, Connector, Time),
+    /// From a datetime up to now, e.g. "since last March"
+    Since(DateTime),
 }
 
-impl Date {
-    fn parse(l: &[Lexeme]) -> Option<(Self, usize)> {
-        let mut tokens = 0;
+impl Range {
+    /// Parse a range from a slice of lexemes
+    pub fn parse(l: &[Lexeme]) -> Option<(Self, usize)> {
+        memo::scoped(Rule::Range, l, Self::parse_scoped)
+    }
+
+    fn parse_scoped(l: &[Lexeme]) -> Option<(Self, usize)> {
+        stats::attempt();
+        let mut tokens = 0;
+        if let Some((relspec, t)) = RelativeSpecifier::parse(&l[tokens..]) {
+            tokens += t;
+
+            // Only counted durations form a period, "last month" is a date
+            if let Some((dur @ (Duration::Specific(..) | Duration::Vague(..)), t)) =
+                Duration::parse(&l[tokens..])
+            {
+                tokens += t;
+                match relspec {
+                    RelativeSpecifier::Last => return Some((Self::Last(dur), tokens)),
+                    RelativeSpecifier::Next => return Some((Self::Next(dur), tokens)),
+                    RelativeSpecifier::This => {}
+                }
+            }
+        }
+
+        // Two times of one day, which may be given before or after them,
+        // e.g. "tomorrow from 9am to 5pm" or "between 14:00 and 16:30 on friday"
+        stats::backtrack(tokens);
+        tokens = 0;
+        let date = Date::parse(l).map(|(date, t)| {
+            tokens += t;
+            date
+        });
+
+        let between = l.get(tokens) == Some(&Lexeme::Between);
+        if between || l.get(tokens) == Some(&Lexeme::From) {
+            tokens += 1;
+        }
+
+        let time = |l: &[Lexeme]| Time::parse_at(l).filter(|(time, _)| *time != Time::Empty);
+        if let Some((start, t)) = time(&l[tokens..]) {
+            tokens += t;
+
+            if let Some(connector) = Self::connector(between, l.get(tokens)) {
+                tokens += 1;
+
+                if let Some((end, t)) = time(&l[tokens..]) {
+                    tokens += t;
+
+                    let date = date.unwrap_or_else(|| {
+                        let on = usize::from(l.get(tokens) == Some(&Lexeme::On));
+                        match Date::parse(&l[tokens + on..]) {
+                            Some((date, t)) => {
+                                tokens += on + t;
+                                date
+                            }
+                            None => Date::Today,
+                        }
+                    });
+                    return Some((Self::Times(date, start, connector, end), tokens));
+                }
+            }
+        }
+
+        // Two datetimes joined by words, which may start with "from" or
+        // "between", e.g. "from monday until friday"
+        stats::backtrack(tokens);
+        tokens = 0;
+        let between = l.first() == Some(&Lexeme::Between);
+        if between || l.first() == Some(&Lexeme::From) {
+            tokens += 1;
+        }
+
+        if let Some((start, t)) = DateTime::parse(&l[tokens..]) {
+            tokens += t;
+
+            if let Some(connector) = Self::connector(between, l.get(tokens)) {
+                tokens += 1;
+
+                if let Some((end, t)) = DateTime::parse(&l[tokens..]) {
+                    tokens += t;
+                    return Some((Self::Connected(start, connector, end), tokens));
+                }
+            }
+        }
+
+        stats::backtrack(tokens);
+        tokens = 0;
+
+        if let Some((start, t)) = DateTime::parse(&l[tokens..]) {
+            tokens += t;
+
+            if l.get(tokens) == Some(&Lexeme::Slash) {
+                tokens += 1;
+
+                if let Some((end, t)) = DateTime::parse(&l[tokens..]) {
+                    tokens += t;
+                    return Some((Self::Interval(start, end), tokens));
+                }
+
+                if let Some((dur, t)) = Duration::parse(&l[tokens..]) {
+                    tokens += t;
+                    return Some((Self::IntervalAfter(start, dur), tokens));
+                }
+            }
+        }
+
+        stats::backtrack(tokens);
+        tokens = 0;
+
+        if let Some((dur, t)) = Duration::parse(&l[tokens..]) {
+            tokens += t;
+
+            if l.get(tokens) == Some(&Lexeme::Slash) {
+                tokens += 1;
+
+                if let Some((end, t)) = DateTime::parse(&l[tokens..]) {
+                    tokens += t;
+                    return Some((Self::IntervalBefore(dur, end), tokens));
+                }
+            }
+        }
+
+        // From a datetime up to now, e.g. "since tuesday", which may be a
+        // time today, e.g. "since 9am"
+        stats::backtrack(tokens);
+        tokens = 0;
+        if l.get(tokens) == Some(&Lexeme::Since) {
+            tokens += 1;
+
+            if let Some((since, t)) = DateTime::parse(&l[tokens..]) {
+                tokens += t;
+                return Some((Self::Since(since), tokens));
+            }
+
+            // A month on its own is from its first day, e.g. "since december"
+            if let Some((month, t)) = Month::parse(&l[tokens..]) {
+                tokens += t;
+                let since = DateTime::DateTime(Date::MonthDay(month, 1), Time::Empty);
+                return Some((Self::Since(since), tokens));
+            }
+
+            let (time, t) = Time::parse_at(&l[tokens..])?;
+            if time == Time::Empty {
+                return None;
+            }
+
+            tokens += t;
+            return Some((Self::Since(DateTime::DateTime(Date::Today, time)), tokens));
+        }
+
+        stats::backtrack(tokens);
+        tokens = 0;
+
+        if l.get(tokens) == Some(&Lexeme::The) {
+            tokens += 1;
+        }
+
+        let relspec = match RelativeSpecifier::parse(&l[tokens..]) {
+            Some((relspec, t)) => {
+                tokens += t;
+                relspec
+            }
+            None => RelativeSpecifier::This,
+        };
+
+        if let Some(&Lexeme::Named(i)) = l.get(tokens) {
+            tokens += 1;
+            return Some((Self::Named(i, relspec), tokens));
+        }
+
+        None
+    }
+
+    /// The connector joining the start and end of a range, which is "and"
+    /// after "between"
+    fn connector(between: bool, l: Option<&Lexeme>) -> Option<Connector> {
+        match (between, l?) {
+            (true, Lexeme::And) => Some(Connector::Between),
+            (false, Lexeme::To) => Some(Connector::To),
+            (false, Lexeme::Through) => Some(Connector::Through),
+            (false, Lexeme::Until) => Some(Connector::Until),
+            _ => None,
+        }
+    }
+
+    /// Whether the range only uses the documented grammar, see
+    /// [`crate::ParseOptions::minimal_grammar`]
+    pub(crate) fn is_documented(&self) -> bool {
+        match self {
+            Range::Interval(start, end) | Range::Connected(start, _, end) => {
+                start.is_documented() && end.is_documented()
+            }
+            Range::Times(date, ..) => date.is_documented(),
+            Range::Since(since) => since.is_documented(),
+            Range::IntervalAfter(datetime, _) | Range::IntervalBefore(_, datetime) => {
+                datetime.is_documented()
+            }
+            Range::Last(_) | Range::Next(_) | Range::Named(..) => true,
+        }
+    }
+
+    /// Whether the range includes its end, which depends on the words
+    /// joining a connected range and is half-open otherwise
+    pub fn bounds(&self, options: &ParseOptions) -> Bounds {
+        match self {
+            Range::Connected(_, connector, _) | Range::Times(_, _, connector, _) => {
+                options.bounds(*connector)
+            }
+            _ => Bounds::HalfOpen,
+        }
+    }
+
+    /// Convert a parsed Range to a pair of chrono NaiveDateTimes
+    pub fn to_chrono(
+        &self,
+        default: ChronoTime,
+        now: ChronoDateTime,
+        options: &ParseOptions,
+    ) -> Result<(ChronoDateTime, ChronoDateTime), crate::Error> {
+        // Endpoints without a time are whole days, which start at midnight
+        // rather than at the current time
+        let default_of = |dt: &DateTime| match dt.is_date_only() {
+            true => ChronoTime::MIN,
+            false => default,
+        };
+        let datetime = |dt: &DateTime| dt.to_chrono_with(default_of(dt), now, options);
+
+        Ok(match self {
+            Range::Last(dur) => (dur.before(now, options)?, now),
+            Range::Next(dur) => (now, dur.after(now, options)?),
+            Range::Interval(start, end) => (datetime(start)?, datetime(end)?),
+            Range::Connected(start, connector, end_tree) => {
+                let start = datetime(start)?;
+                let mut end = datetime(end_tree)?;
+
+                // An end before the start is read from the start instead,
+                // e.g. "monday to friday" on a wednesday, and a month and
+                // day may fall in the next year, e.g. "December 28 to
+                // January 3"
+                if end < start {
+                    end = end_tree.to_chrono_with(default_of(end_tree), start, options)?;
+                }
+                if end < start {
+                    if let DateTime::DateTime(Date::MonthDay(..), _) = end_tree {
+                        end = end.with_year(end.year() + 1).unwrap_or(end);
+                    }
+                }
+
+                // An included end without a time includes the whole day,
+                // e.g. "monday to friday" ends as friday does
+                if end_tree.is_date_only() && options.bounds(*connector) == Bounds::Closed {
+                    end = ChronoDateTime::new(end.date(), END_OF_DAY);
+                }
+
+                (start, end)
+            }
+            Range::Times(date, start, _, end) => {
+                let start = datetime(&DateTime::DateTime(date.clone(), start.clone()))?;
+                let mut end = datetime(&DateTime::DateTime(date.clone(), end.clone()))?;
+
+                // An end before the start is on the next day, e.g. "10pm to 2am"
+                if end < start {
+                    end += ChronoDuration::days(1);
+                }
+
+                (start, end)
+            }
+            Range::Since(since) => {
+                // Without a time the range starts at the start of the day
+                let mut start = since.to_chrono_with(ChronoTime::MIN, now, options)?;
+
+                // A weekday, a time, or a month and day on their own are the
+                // last ones, e.g. "since tuesday", "since 11pm" in the
+                // morning, or "since december" in october
+                if start > now {
+                    match since {
+                        DateTime::DateTime(Date::Weekday(_), _) => {
+                            start -= ChronoDuration::weeks(1);
+                        }
+                        DateTime::DateTime(Date::Today, _) => start -= ChronoDuration::days(1),
+                        DateTime::DateTime(Date::MonthDay(..), _) => {
+                            start = start.checked_sub_months(chrono::Months::new(12)).ok_or(
+                                crate::Error::invalid_date("Date out of representable date range."),
+                            )?;
+                        }
+                        _ => {}
+                    }
+                }
+
+                if start > now {
+                    return Err(crate::Error::invalid_date(format!(
+                        "Since {start}, which is after {now}"
+                    )));
+                }
+
+                (start, now)
+            }
+            Range::IntervalAfter(start, dur) => {
+                let start = datetime(start)?;
+                (start, dur.after(start, options)?)
+            }
+            Range::IntervalBefore(dur, end) => {
+                let end = datetime(end)?;
+                (dur.before(end, options)?, end)
+            }
+            Range::Named(i, relspec) => {
+                let start = Date::Named(*i, None, *relspec).to_chrono(now.date(), options)?;
+                let end = Date::EndOfNamed(*i, *relspec).to_chrono(now.date(), options)?;
+                let end = end
+                    .succ_opt()
+                    .ok_or(crate::Error::invalid_date(format!("No day after {end}")))?;
+                (start.into(), end.into())
+            }
+        })
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Root of the Abstract Syntax Tree for recurring expressions
+#[non_exhaustive]
+pub enum Recurrence {
+    /// Every given weekday, e.g. "every tuesday at 9am"
+    Weekday(Weekday, Time),
+    /// A fixed number of units apart, e.g. "every 2 weeks" or "daily at 9am"
+    Interval(u32, Unit, Time),
+}
+
+impl Recurrence {
+    /// Parse a recurrence from a slice of lexemes
+    pub fn parse(l: &[Lexeme]) -> Option<(Self, usize)> {
+        memo::scoped(Rule::Recurrence, l, Self::parse_scoped)
+    }
+
+    fn parse_scoped(l: &[Lexeme]) -> Option<(Self, usize)> {
+        stats::attempt();
+        let mut tokens = 0;
+        let unit = match l.get(tokens) {
+            Some(Lexeme::Daily) => Some(Unit::Day),
+            Some(Lexeme::Weekly) => Some(Unit::Week),
+            Some(Lexeme::Monthly) => Some(Unit::Month),
+            Some(Lexeme::Yearly) => Some(Unit::Year),
+            _ => None,
+        };
+
+        if let Some(unit) = unit {
+            tokens += 1;
+
+            let (time, t) = Time::parse_at(&l[tokens..])?;
+            tokens += t;
+            return Some((Self::Interval(1, unit, time), tokens));
+        }
+
+        if l.get(tokens) != Some(&Lexeme::Every) {
+            return None;
+        }
+        tokens += 1;
+
+        if let Some((weekday, t)) = Weekday::parse(&l[tokens..]) {
+            tokens += t;
+
+            let (time, t) = Time::parse_at(&l[tokens..])?;
+            tokens += t;
+            return Some((Self::Weekday(weekday, time), tokens));
+        }
+
+        // The count may be left out, e.g. "every week"
+        let mut n = 1;
+        if let Some((count, t)) = Num::parse(&l[tokens..]) {
+            tokens += t;
+            n = count;
+        }
+
+        let (unit, t) =
+            Unit::parse(&l[tokens..]).filter(|&(unit, _)| n > 0 && unit != Unit::BusinessDay)?;
+        tokens += t;
+
+        let (time, t) = Time::parse_at(&l[tokens..])?;
+        tokens += t;
+        Some((Self::Interval(n, unit, time), tokens))
+    }
+
+    /// Convert a parsed Recurrence to its first occurrence at or after the
+    /// current datetime and the interval between occurrences
+    pub fn to_chrono(
+        &self,
+        default: ChronoTime,
+        now: ChronoDateTime,
+        options: &ParseOptions,
+    ) -> Result<crate::Recurrence, crate::Error> {
+        use crate::Frequency;
+
+        let (frequency, interval, weekday, time) = match self {
+            Recurrence::Weekday(weekday, time) => (Frequency::Weekly, 1, Some(weekday), time),
+            Recurrence::Interval(n, unit, time) => {
+                let frequency = match unit {
+                    Unit::Minute => Frequency::Minutely,
+                    Unit::Hour => Frequency::Hourly,
+                    Unit::Day => Frequency::Daily,
+                    Unit::Week => Frequency::Weekly,
+                    Unit::Month => Frequency::Monthly,
+                    Unit::Year => Frequency::Yearly,
+                    Unit::BusinessDay => {
+                        return Err(crate::Error::invalid_date(
+                            "Business days can't recur at a fixed frequency",
+                        ))
+                    }
+                };
+                (frequency, *n, None, time)
+            }
+        };
+
+        let mut recurrence = crate::Recurrence {
+            frequency,
+            interval,
+            weekday: weekday.map(Weekday::to_chrono),
+            time: (*time != Time::Empty)
+                .then(|| time.to_chrono(default, options))
+                .transpose()?,
+            start: ChronoDateTime::new(now.date(), time.to_chrono(default, options)?),
+        };
+
+        // Move to the first occurrence which isn't in the past
+        if let Some(weekday) = recurrence.weekday {
+            while recurrence.start.weekday() != weekday || recurrence.start < now {
+                recurrence.start += ChronoDuration::days(1);
+            }
+        }
+
+        while recurrence.start < now {
+            recurrence.start = recurrence.nth(1).ok_or(crate::Error::invalid_date(format!(
+                "No occurrence after {now}"
+            )))?;
+        }
+
+        Ok(recurrence)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Root of the Abstract Syntax Tree for random datetimes within a range,
+/// e.g. "a random day between June 1 and June 30"
+pub struct Random(pub Unit, pub Range);
+
+impl Random {
+    /// Parse a random datetime from a slice of lexemes
+    pub fn parse(l: &[Lexeme]) -> Option<(Self, usize)> {
+        memo::scoped(Rule::Random, l, Self::parse_scoped)
+    }
+
+    fn parse_scoped(l: &[Lexeme]) -> Option<(Self, usize)> {
+        stats::attempt();
+        let mut tokens = 0;
+        if let Some((_, t)) = Article::parse(l) {
+            tokens += t;
+        }
+
+        if l.get(tokens) != Some(&Lexeme::Random) {
+            return None;
+        }
+        tokens += 1;
+
+        let (unit, t) = Unit::parse(&l[tokens..])?;
+        tokens += t;
+
+        let (range, t) = Range::parse(&l[tokens..])?;
+        tokens += t;
+        Some((Self(unit, range), tokens))
+    }
+
+    /// Convert a parsed Random to a chrono NaiveDateTime, a whole number of
+    /// units after the start of the range chosen uniformly with `rng`
+    pub fn to_chrono(
+        &self,
+        default: ChronoTime,
+        now: ChronoDateTime,
+        options: &ParseOptions,
+        rng: &mut impl FnMut() -> u64,
+    ) -> Result<ChronoDateTime, crate::Error> {
+        let Random(unit, range) = self;
+        let (start, end) = range.to_chrono(default, now, options)?;
+        let inside = |date: ChronoDateTime| match range.bounds(options) {
+            Bounds::HalfOpen => date < end,
+            Bounds::Closed => date <= end,
+        };
+        let nth = |n: u32| Duration::Specific(n, *unit).after(start, options);
+
+        // The number of whole units from the start which are in the range
+        let count: u64 = if end < start {
+            0
+        } else if Duration::Article(*unit).convertable() {
+            let step = Duration::Article(*unit)
+                .to_chrono()
+                .expect("a single fixed unit is in range")
+                .num_seconds();
+            let last = u32::try_from((end - start).num_seconds() / step).unwrap_or(u32::MAX);
+            u64::from(last) + u64::from(nth(last).is_ok_and(inside))
+        } else {
+            (0..).take_while(|&n| nth(n).is_ok_and(inside)).count() as u64
+        };
+
+        if count == 0 {
+            return Err(crate::Error::invalid_date(format!(
+                "Empty range: {start} to {end}"
+            )));
+        }
+
+        // Scale the random number onto the count, which is uniform up to a
+        // bias of less than one in 2^32 for any count of units that fits
+        let n = ((u128::from(rng()) * u128::from(count)) >> 64) as u32;
+        nth(n)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Root of the Abstract Syntax Tree for open-ended bounds on a datetime,
+/// e.g. "after 5pm tomorrow"
+#[non_exhaustive]
+pub enum Constraint {
+    /// Later than the datetime, e.g. "after 5pm"
+    After(DateTime),
+    /// Earlier than the datetime, e.g. "before noon"
+    Before(DateTime),
+    /// The datetime or later, e.g. "no earlier than monday"
+    NotBefore(DateTime),
+    /// The datetime or earlier, e.g. "no later than friday" or the deadline
+    /// "by friday"
+    NotAfter(DateTime),
+}
+
+impl Constraint {
+    /// Parse an open-ended bound from a slice of lexemes
+    pub fn parse(l: &[Lexeme]) -> Option<(Self, usize)> {
+        memo::scoped(Rule::Constraint, l, Self::parse_scoped)
+    }
+
+    fn parse_scoped(l: &[Lexeme]) -> Option<(Self, usize)> {
+        stats::attempt();
+        let mut tokens = 0;
+        let constraint: fn(DateTime) -> Self = match l.get(tokens)? {
+            Lexeme::After => Self::After,
+            Lexeme::Before => Self::Before,
+            Lexeme::NoEarlierThan => Self::NotBefore,
+            Lexeme::NoLaterThan | Lexeme::By | Lexeme::Until => Self::NotAfter,
+            _ => return None,
+        };
+        tokens += 1;
+
+        if let Some((datetime, t)) = DateTime::parse(&l[tokens..]) {
+            tokens += t;
+            return Some((constraint(datetime), tokens));
+        }
+
+        // A time on its own is today, e.g. "after 5pm"
+        let (time, t) = Time::parse_at(&l[tokens..])?;
+        if time == Time::Empty {
+            return None;
+        }
+
+        tokens += t;
+        Some((constraint(DateTime::DateTime(Date::Today, time)), tokens))
+    }
+
+    /// Whether the constraint only uses the documented grammar, see
+    /// [`crate::ParseOptions::minimal_grammar`]
+    pub(crate) fn is_documented(&self) -> bool {
+        match self {
+            Constraint::After(datetime)
+            | Constraint::Before(datetime)
+            | Constraint::NotBefore(datetime)
+            | Constraint::NotAfter(datetime) => datetime.is_documented(),
+        }
+    }
+
+    /// Convert a parsed Constraint to the datetime bounding it
+    pub fn to_chrono(
+        &self,
+        default: ChronoTime,
+        now: ChronoDateTime,
+        options: &ParseOptions,
+    ) -> Result<crate::Constraint, crate::Error> {
+        use crate::Bound;
+
+        let (Constraint::After(datetime)
+        | Constraint::Before(datetime)
+        | Constraint::NotBefore(datetime)
+        | Constraint::NotAfter(datetime)) = self;
+
+        // A date without a time bounds the whole day, so "after friday"
+        // starts once friday is over and "before friday" ends as it begins.
+        // A deadline includes the whole day unless the options set its time
+        if datetime.is_date_only() {
+            let day = datetime.to_chrono_with(ChronoTime::MIN, now, options)?;
+            let next_day = || {
+                day.checked_add_days(chrono::Days::new(1))
+                    .ok_or(crate::Error::invalid_date(format!("No day after {day}")))
+            };
+            let (bound, datetime, inclusive) = match self {
+                Constraint::After(_) => (Bound::Lower, next_day()?, true),
+                Constraint::Before(_) => (Bound::Upper, day, false),
+                Constraint::NotBefore(_) => (Bound::Lower, day, true),
+                Constraint::NotAfter(_) => match options.deadline_time {
+                    Some(time) => (Bound::Upper, ChronoDateTime::new(day.date(), time), true),
+                    None => (Bound::Upper, next_day()?, false),
+                },
+            };
+            return Ok(crate::Constraint {
+                bound,
+                datetime,
+                inclusive,
+            });
+        }
+
+        let (bound, inclusive) = match self {
+            Constraint::After(_) => (Bound::Lower, false),
+            Constraint::Before(_) => (Bound::Upper, false),
+            Constraint::NotBefore(_) => (Bound::Lower, true),
+            Constraint::NotAfter(_) => (Bound::Upper, true),
+        };
+
+        Ok(crate::Constraint {
+            bound,
+            datetime: datetime.to_chrono_with(default, now, options)?,
+            inclusive,
+        })
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A Parsed Date
+#[non_exhaustive]
+pub enum Date {
+    /// Numeric date whose field order depends on the separator and the
+    /// configured date order, e.g. "5/12/2023" or "19.12."
+    Numeric(DateSeparator, u32, u32, Option<u32>),
+    MonthDayYear(Month, u32, u32),
+    /// Year and day of the year, e.g. "2024-150"
+    YearDay(u32, u32),
+    /// Year and month, read as the first day of the month, e.g. "2024-06"
+    YearMonth(u32, u32),
+    /// A four digit year on its own, read as its anchor day, January 1st
+    /// by default, e.g. "2030" or "in 1999"
+    Year(u32),
+    /// Month and day numbers in a fixed order, e.g. the ISO "--06-15"
+    MonthNumDay(u32, u32),
+    MonthDay(Month, u32),
+    /// A day of the month on its own, the next one from today, e.g. "the
+    /// 15th"
+    Day(u32),
+    /// A month and four digit year without a day, read as its anchor day,
+    /// the 1st by default, e.g. "June 2025"
+    MonthYear(Month, u32),
+    UnitRelative(RelativeSpecifier, Unit),
+    /// The first day of a period, e.g. "the start of next month"
+    StartOf(RelativeSpecifier, Unit),
+    /// The last day of a period, e.g. "the end of the month"
+    EndOf(RelativeSpecifier, Unit),
+    /// The last day of a registered named period, holding its index in the
+    /// options, e.g. "the end of the fall semester"
+    EndOfNamed(usize, RelativeSpecifier),
+    /// A date registered by name, holding its index in the options, with
+    /// either the month and year or the relative specifier qualifying it
+    Named(usize, Option<(Month, Option<u32>)>, RelativeSpecifier),
+    /// The 29th of February closest before or after today,
+    /// e.g. "last leap day"
+    LeapDay(RelativeSpecifier),
+    /// The 29th of February of the closest leap year before or after this
+    /// one, e.g. "next leap year"
+    LeapYear(RelativeSpecifier),
+    Relative(RelativeSpecifier, Weekday),
+    /// A month of this year, the last one before this month, or the next
+    /// one after it, read as its anchor day, e.g. "last March"
+    RelativeMonth(RelativeSpecifier, Month),
+    /// The weekday a week after its next occurrence, e.g. "Tuesday week"
+    /// or "a week on Tuesday"
+    WeekOn(Weekday),
+    /// A full date led by its weekday, e.g. "Friday, June 13 2025"
+    WeekdayDate(Weekday, Box<Date>),
+    /// The last weekday of the month of a date, e.g. "the last Friday of
+    /// next month"
+    LastWeekdayOf(Weekday, Box<Date>),
+    Weekday(Weekday),
+    Today,
+    Tomorrow,
+    Yesterday,
+    DayAfterTomorrow,
+    DayBeforeYesterday,
+}
+
+impl From<Date> for DateTime {
+    /// The date at the default time
+    fn from(date: Date) -> Self {
+        DateTime::DateTime(date, Time::Empty)
+    }
+}
+
+impl TryFrom<ChronoDate> for Date {
+    type Error = crate::Error;
+
+    /// A fixed date, whatever the options. Dates before year 0 have no
+    /// parsed form and are an error.
+    fn try_from(date: ChronoDate) -> Result<Self, Self::Error> {
+        let year = u32::try_from(date.year())
+            .map_err(|_| crate::Error::invalid_date(format!("No parsed form of {date}")))?;
+        Ok(Date::YearDay(year, date.ordinal()))
+    }
+}
+
+impl Date {
+    fn parse(l: &[Lexeme]) -> Option<(Self, usize)> {
+        memo::memoized(Rule::Date, l, Self::parse_uncached)
+    }
+
+    fn parse_uncached(l: &[Lexeme]) -> Option<(Self, usize)> {
+        stats::attempt();
+        let mut tokens = 0;
+
+        if let Some(&Lexeme::Today) = l.get(tokens) {
+            tokens += 1;
+            return Some((Self::Today, tokens));
+        }
+
+        stats::backtrack(tokens);
+        tokens = 0;
+
+        if let Some(&Lexeme::Tomorrow) = l.get(tokens) {
+            tokens += 1;
+            return Some((Self::Tomorrow, tokens));
+        }
+
+        stats::backtrack(tokens);
+        tokens = 0;
+
+        if let Some(&Lexeme::Yesterday) = l.get(tokens) {
+            tokens += 1;
+            return Some((Self::Yesterday, tokens));
+        }
+
+        stats::backtrack(tokens);
+        tokens = 0;
+
+        if let Some(&Lexeme::DayAfterTomorrow) = l.get(tokens) {
+            tokens += 1;
+            return Some((Self::DayAfterTomorrow, tokens));
+        }
+
+        stats::backtrack(tokens);
+        tokens = 0;
+
+        if let Some(&Lexeme::DayBeforeYesterday) = l.get(tokens) {
+            tokens += 1;
+            return Some((Self::DayBeforeYesterday, tokens));
+        }
+
+        stats::backtrack(tokens);
+        tokens = 0;
+
+        if let (Some(Lexeme::A), Some(Lexeme::Week), Some(Lexeme::On)) =
+            (l.first(), l.get(1), l.get(2))
+        {
+            tokens += 3;
+
+            let (weekday, t) = Weekday::parse(&l[tokens..])?;
+            tokens += t;
+            return Some((Self::WeekOn(weekday), tokens));
+        }
+
+        // The start or end of a period, e.g. "the end of next month"
+        stats::backtrack(tokens);
+        tokens = 0;
+        if l.get(tokens) == Some(&Lexeme::The) {
+            tokens += 1;
+        }
+
+        if let (Some(&boundary @ (Lexeme::Start | Lexeme::End)), Some(Lexeme::Of)) =
+            (l.get(tokens), l.get(tokens + 1))
+        {
+            tokens += 2;
+
+            let relspec = if let Some((relspec, t)) = RelativeSpecifier::parse(&l[tokens..]) {
+                tokens += t;
+                relspec
+            } else {
+                if l.get(tokens) == Some(&Lexeme::The) {
+                    tokens += 1;
+                }
+                RelativeSpecifier::This
+            };
+
+            // The start of a named period is its occurrence
+            if let Some(&Lexeme::Named(i)) = l.get(tokens) {
+                tokens += 1;
+
+                if boundary == Lexeme::Start {
+                    return Some((Self::Named(i, None, relspec), tokens));
+                } else {
+                    return Some((Self::EndOfNamed(i, relspec), tokens));
+                }
+            }
+
+            let (unit, t) = Unit::parse(&l[tokens..])?;
+            tokens += t;
+
+            if boundary == Lexeme::Start {
+                return Some((Self::StartOf(relspec, unit), tokens));
+            } else {
+                return Some((Self::EndOf(relspec, unit), tokens));
+            }
+        }
+
+        // A named date, e.g. "the ides of March" or "the next full moon"
+        stats::backtrack(tokens);
+        tokens = 0;
+        if l.get(tokens) == Some(&Lexeme::The) {
+            tokens += 1;
+        }
+
+        let relspec = RelativeSpecifier::parse(&l[tokens..]).map(|(relspec, t)| {
+            tokens += t;
+            relspec
+        });
+
+        if let Some(&Lexeme::Named(i)) = l.get(tokens) {
+            tokens += 1;
+
+            if let Some(relspec) = relspec {
+                return Some((Self::Named(i, None, relspec), tokens));
+            }
+
+            if l.get(tokens) == Some(&Lexeme::Of) {
+                if let Some((month, t)) = Month::parse(&l[tokens + 1..]) {
+                    tokens += t + 1;
+
+                    let year = Num::parse(&l[tokens..]).map(|(year, t)| {
+                        tokens += t;
+                        year
+                    });
+
+                    let qualifier = Some((month, year));
+                    return Some((Self::Named(i, qualifier, RelativeSpecifier::This), tokens));
+                }
+            }
+
+            return Some((Self::Named(i, None, RelativeSpecifier::This), tokens));
+        }
 
-        if let Some(&Lexeme::Today) = l.get(tokens) {
+        // The last weekday of a month, e.g. "the last Friday of the month"
+        stats::backtrack(tokens);
+        tokens = 0;
+        if l.get(tokens) == Some(&Lexeme::The) {
             tokens += 1;
-            return Some((Self::Today, tokens));
         }
 
+        if l.get(tokens) == Some(&Lexeme::Last) {
+            if let Some((weekday, t)) = Weekday::parse(&l[tokens + 1..]) {
+                if l.get(tokens + 1 + t) == Some(&Lexeme::Of) {
+                    tokens += t + 2;
+
+                    let (month, t) = Date::parse_month_of(&l[tokens..])?;
+                    tokens += t;
+                    return Some((Self::LastWeekdayOf(weekday, stats::boxed(month)), tokens));
+                }
+            }
+        }
+
+        // ISO month and day without a year, e.g. "--06-15"
+        stats::backtrack(tokens);
         tokens = 0;
-        if let Some(&Lexeme::Tomorrow) = l.get(tokens) {
+        if let (Some(Lexeme::Dash), Some(Lexeme::Dash)) = (l.first(), l.get(1)) {
+            tokens += 2;
+
+            let (month, t) = Num::parse(&l[tokens..])?;
+            tokens += t;
+
+            if l.get(tokens) != Some(&Lexeme::Dash) {
+                return None;
+            }
             tokens += 1;
-            return Some((Self::Tomorrow, tokens));
+
+            let (day, t) = Num::parse(&l[tokens..])?;
+            tokens += t;
+            return Some((Self::MonthNumDay(month, day), tokens));
         }
 
+        // The day before the month, e.g. "the 3rd of June 2024"
+        stats::backtrack(tokens);
         tokens = 0;
-        if let Some(&Lexeme::Yesterday) = l.get(tokens) {
+        let the = l.get(tokens) == Some(&Lexeme::The);
+        if the {
             tokens += 1;
-            return Some((Self::Yesterday, tokens));
         }
 
+        // An ordinal word is tried first, as the tens of "twenty-first"
+        // would otherwise be read as a number
+        let day = Ordinal::parse(&l[tokens..]).or_else(|| Num::parse(&l[tokens..]));
+        if let Some((day, t)) = day {
+            if l.get(tokens + t) == Some(&Lexeme::Of) {
+                if let Some((month, m)) = Month::parse(&l[tokens + t + 1..]) {
+                    tokens += t + 1 + m;
+
+                    if let Some((year, t)) = Num::parse(&l[tokens..]) {
+                        tokens += t;
+                        return Some((Self::MonthDayYear(month, day, year), tokens));
+                    } else {
+                        return Some((Self::MonthDay(month, day), tokens));
+                    }
+                }
+            }
+
+            // Without a month, e.g. "the 15th", unless it counts a unit or
+            // a weekday, e.g. "the first monday of june"
+            if the
+                && (1..=31).contains(&day)
+                && Unit::parse(&l[tokens + t..]).is_none()
+                && Weekday::parse(&l[tokens + t..]).is_none()
+            {
+                tokens += t;
+                return Some((Self::Day(day), tokens));
+            }
+        }
+
+        stats::backtrack(tokens);
         tokens = 0;
+
         if let Some((month, t)) = Month::parse(&l[tokens..]) {
             tokens += t;
 
-            let (day, t) = Num::parse(&l[tokens..])?;
+            let (day, t) = Ordinal::parse(&l[tokens..]).or_else(|| Num::parse(&l[tokens..]))?;
             tokens += t;
 
+            // A four digit number is a year rather than a day, e.g. "June 2025"
+            if day >= 1000 {
+                return Some((Self::MonthYear(month, day), tokens));
+            }
+
             if let Some((year, t)) = Num::parse(&l[tokens..]) {
                 tokens += t;
                 return Some((Self::MonthDayYear(month, day, year), tokens));
@@ -170,102 +1673,457 @@ impl Date {
             }
         }
 
+        stats::backtrack(tokens);
         tokens = 0;
+
         if let Some((relspec, t)) = RelativeSpecifier::parse(&l[tokens..]) {
             tokens += t;
 
+            if l.get(tokens) == Some(&Lexeme::Leap) {
+                match l.get(tokens + 1) {
+                    Some(Lexeme::Day) => return Some((Self::LeapDay(relspec), tokens + 2)),
+                    Some(Lexeme::Year) => return Some((Self::LeapYear(relspec), tokens + 2)),
+                    _ => return None,
+                }
+            }
+
             if let Some((weekday, t)) = Weekday::parse(&l[tokens..]) {
                 tokens += t;
                 return Some((Self::Relative(relspec, weekday), tokens));
             }
 
+            if let Some((month, t)) = Month::parse(&l[tokens..]) {
+                tokens += t;
+                return Some((Self::RelativeMonth(relspec, month), tokens));
+            }
+
             if let Some((unit, t)) = Unit::parse(&l[tokens..]) {
                 tokens += t;
                 return Some((Self::UnitRelative(relspec, unit), tokens));
             }
         } else if let Some((weekday, t)) = Weekday::parse(&l[tokens..]) {
             tokens += t;
+
+            if let Some(Lexeme::Week) = l.get(tokens) {
+                tokens += 1;
+                return Some((Self::WeekOn(weekday), tokens));
+            }
+
+            // Calendar exports often lead with the weekday, which may be
+            // followed by a comma
+            let comma = usize::from(l.get(tokens) == Some(&Lexeme::Comma));
+            if let Some((date, t)) = Date::parse(&l[tokens + comma..]) {
+                if matches!(
+                    date,
+                    Self::Numeric(..) | Self::MonthDayYear(..) | Self::MonthDay(..)
+                ) {
+                    tokens += comma + t;
+                    return Some((Self::WeekdayDate(weekday, stats::boxed(date)), tokens));
+                }
+            }
+
             return Some((Self::Weekday(weekday), tokens));
         } else if let Some((num1, t)) = Num::parse(&l[tokens..]) {
             tokens += t;
-            if let Some(delim) = l.get(tokens) {
-                if delim == &Lexeme::Slash || delim == &Lexeme::Dash || delim == &Lexeme::Dot {
-                    // Consume slash or dash
-                    tokens += 1;
 
-                    if let Some((num2, t)) = Num::parse(&l[tokens..]) {
-                        tokens += t;
-                        if l.get(tokens)? == delim {
-                            // Consume slash or dash
-                            tokens += 1;
+            if let (Some(Lexeme::Dash), Some(&Lexeme::DayOfYear(day))) =
+                (l.get(tokens), l.get(tokens + 1))
+            {
+                tokens += 2;
+                return Some((Self::YearDay(num1, day), tokens));
+            }
 
-                            let (num3, t) = Num::parse(&l[tokens..])?;
-                            tokens += t;
+            if let Some((sep, t)) = DateSeparator::parse(&l[tokens..]) {
+                // Consume separator
+                tokens += t;
 
-                            // If delim is dot use DMY, otherwise MDY
-                            if delim == &Lexeme::Dot {
-                                return Some((Self::MonthNumDayYear(num2, num1, num3), tokens));
-                            } else {
-                                return Some((Self::MonthNumDayYear(num1, num2, num3), tokens));
-                            }
-                        } else {
-                            // If delim is dot use DMY, otherwise MDY
-                            if delim == &Lexeme::Dot {
-                                return Some((Self::MonthNumDay(num2, num1), tokens));
-                            } else {
-                                return Some((Self::MonthNumDay(num1, num2), tokens));
-                            }
-                        }
+                if let Some((num2, t)) = Num::parse(&l[tokens..]) {
+                    tokens += t;
+
+                    // The third field must use the same separator
+                    if DateSeparator::parse(&l[tokens..]).map(|(s, _)| s) == Some(sep) {
+                        tokens += 1;
+
+                        let (num3, t) = Num::parse(&l[tokens..])?;
+                        tokens += t;
+                        return Some((Self::Numeric(sep, num1, num2, Some(num3)), tokens));
+                    }
+
+                    // A four digit year followed by a month, e.g. "2024-06"
+                    if num1 >= 1000 {
+                        return Some((Self::YearMonth(num1, num2), tokens));
                     }
+
+                    return Some((Self::Numeric(sep, num1, num2, None), tokens));
                 }
             }
         }
 
+        // A four digit year on its own, which may be introduced by "in",
+        // e.g. "in 1999"
+        stats::backtrack(tokens);
+        tokens = 0;
+        if l.get(tokens) == Some(&Lexeme::In) {
+            tokens += 1;
+        }
+
+        if let Some((year, t)) = Num::parse(&l[tokens..]).filter(|(year, _)| *year >= 1000) {
+            tokens += t;
+            return Some((Self::Year(year), tokens));
+        }
+
         None
     }
 
-    fn to_chrono(&self, relative_to: Option<ChronoDate>) -> Result<ChronoDate, crate::Error> {
-        let mut today = relative_to.unwrap_or(Local::now().naive_local().date());
+    /// Parse the month a weekday is counted in, e.g. the "next month" of
+    /// "the last Friday of next month", as a date in that month
+    fn parse_month_of(l: &[Lexeme]) -> Option<(Self, usize)> {
+        stats::attempt();
+        let mut tokens = 0;
+
+        let relspec = if l.get(tokens) == Some(&Lexeme::The) {
+            tokens += 1;
+            Some(RelativeSpecifier::This)
+        } else {
+            RelativeSpecifier::parse(&l[tokens..]).map(|(relspec, t)| {
+                tokens += t;
+                relspec
+            })
+        };
+
+        if let Some(relspec) = relspec {
+            if let Some((Unit::Month, t)) = Unit::parse(&l[tokens..]) {
+                tokens += t;
+                return Some((Self::UnitRelative(relspec, Unit::Month), tokens));
+            }
+
+            // A relative month, e.g. "next June"
+            if let Some((month, t)) = Month::parse(&l[tokens..]) {
+                tokens += t;
+                return Some((Self::RelativeMonth(relspec, month), tokens));
+            }
+        }
+
+        // A month of this year unless a year is given, e.g. "June 2025"
+        stats::backtrack(tokens);
+        tokens = 0;
+        let (month, t) = Month::parse(&l[tokens..])?;
+        tokens += t;
+
+        if let Some((year, t)) = Num::parse(&l[tokens..]) {
+            tokens += t;
+            return Some((Self::MonthYear(month, year), tokens));
+        }
+
+        Some((Self::RelativeMonth(RelativeSpecifier::This, month), tokens))
+    }
+
+    /// Describe how the date is resolved in words
+    fn describe(&self, options: &ParseOptions) -> String {
+        let relative = RelativeSpecifier::canonical;
+        let name = |i: usize| {
+            let named = options.named_dates.get(i);
+            let name = named.and_then(|named| named.names().first().map(|&name| name.to_owned()));
+            name.unwrap_or_else(|| "named date".to_owned())
+        };
+
+        match self {
+            Date::Numeric(sep, first, second, third) => {
+                match sep.resolve(*first, *second, *third, options) {
+                    (month, day, Some(year)) => format!("{} {day} {year}", month_name(month)),
+                    (month, day, None) => format!("{} {day} of this year", month_name(month)),
+                }
+            }
+            Date::MonthDayYear(month, day, year) => format!("{month:?} {day} {year}"),
+            Date::YearDay(year, day) => format!("day {day} of {year}"),
+            Date::YearMonth(year, month) => format!("{} 1 {year}", month_name(*month)),
+            Date::Year(year) => {
+                let (month, day) = options.year_anchor;
+                format!("{} {day} {year}", month_name(month))
+            }
+            Date::MonthNumDay(month, day) => format!("{} {day} of this year", month_name(*month)),
+            Date::MonthDay(month, day) => format!("{month:?} {day} of this year"),
+            Date::Day(day) => format!("the first {} of a month from today", ordinal(*day)),
+            Date::MonthYear(month, year) => format!("{month:?} {} {year}", options.month_anchor),
+            Date::UnitRelative(relspec, unit) => format!("{} {unit}", relative(relspec)),
+            Date::StartOf(relspec, unit) => format!("the start of {} {unit}", relative(relspec)),
+            Date::EndOf(relspec, unit) => format!("the end of {} {unit}", relative(relspec)),
+            Date::EndOfNamed(i, relspec) => {
+                format!("the end of {} {}", relative(relspec), name(*i))
+            }
+            Date::Named(i, Some((month, Some(year))), _) => {
+                format!("the {} of {month:?} {year}", name(*i))
+            }
+            Date::Named(i, Some((month, None)), _) => {
+                format!("the {} of {month:?} this year", name(*i))
+            }
+            Date::Named(i, None, relspec) => format!("{} {}", relative(relspec), name(*i)),
+            Date::LeapDay(relspec) => format!("{} leap day", relative(relspec)),
+            Date::LeapYear(relspec) => {
+                format!("February 29 of {} leap year", relative(relspec))
+            }
+            Date::Relative(RelativeSpecifier::This, weekday) => {
+                format!("the first {weekday:?} from today")
+            }
+            Date::Relative(RelativeSpecifier::Next, weekday) => {
+                format!("the first {weekday:?} on or after a week from today")
+            }
+            Date::Relative(RelativeSpecifier::Last, weekday) => {
+                format!("the first {weekday:?} on or after a week ago")
+            }
+            Date::RelativeMonth(relspec, month) => {
+                let anchor = options.month_anchor;
+                match relspec {
+                    RelativeSpecifier::This => format!("{month:?} {anchor} of this year"),
+                    RelativeSpecifier::Next => {
+                        format!("{month:?} {anchor} of the first {month:?} after this month")
+                    }
+                    RelativeSpecifier::Last => {
+                        format!("{month:?} {anchor} of the last {month:?} before this month")
+                    }
+                }
+            }
+            Date::WeekOn(weekday) => format!("a week after the next {weekday:?}"),
+            Date::WeekdayDate(weekday, date) => {
+                format!("{}, given as a {weekday:?}", date.describe(options))
+            }
+            Date::LastWeekdayOf(weekday, date) => format!(
+                "the last {weekday:?} of the month of {}",
+                date.describe(options)
+            ),
+            Date::Weekday(weekday) => format!("the first {weekday:?} from today"),
+            Date::Today => "today".to_owned(),
+            Date::Tomorrow => "tomorrow".to_owned(),
+            Date::Yesterday => "yesterday".to_owned(),
+            Date::DayAfterTomorrow => "the day after tomorrow".to_owned(),
+            Date::DayBeforeYesterday => "the day before yesterday".to_owned(),
+        }
+    }
+
+    /// Write the date in English the way the parser reads it back
+    fn canonical(&self, options: &ParseOptions) -> String {
+        let name = |i: usize| {
+            let named = options.named_dates.get(i);
+            let name = named.and_then(|named| named.names().first().map(|&name| name.to_owned()));
+            name.unwrap_or_else(|| "named date".to_owned())
+        };
+        let month = |month: &Month| format!("{month:?}").to_lowercase();
+
+        match self {
+            Date::Numeric(sep, first, second, third) => {
+                let sep = match sep {
+                    DateSeparator::Slash => '/',
+                    DateSeparator::Dash => '-',
+                    DateSeparator::Dot => '.',
+                };
+                match third {
+                    Some(third) => format!("{first}{sep}{second}{sep}{third}"),
+                    None => format!("{first}{sep}{second}"),
+                }
+            }
+            Date::MonthDayYear(m, day, year) => format!("{} {day} {year}", month(m)),
+            Date::YearDay(year, day) => format!("{year}-{day:03}"),
+            Date::YearMonth(year, month) => format!("{year}-{month:02}"),
+            Date::Year(year) => format!("in {year}"),
+            Date::MonthNumDay(month, day) => format!("--{month:02}-{day:02}"),
+            Date::MonthDay(m, day) => format!("{} {day}", month(m)),
+            Date::Day(day) => format!("the {}", ordinal(*day)),
+            Date::MonthYear(m, year) => format!("{} {year}", month(m)),
+            Date::UnitRelative(relspec, unit) => format!("{} {unit}", relspec.canonical()),
+            Date::StartOf(relspec, unit) => {
+                format!("the start of {} {unit}", relspec.canonical())
+            }
+            Date::EndOf(relspec, unit) => format!("the end of {} {unit}", relspec.canonical()),
+            Date::EndOfNamed(i, relspec) => {
+                format!("the end of {} {}", relspec.canonical(), name(*i))
+            }
+            Date::Named(i, Some((m, Some(year))), _) => {
+                format!("{} of {} {year}", name(*i), month(m))
+            }
+            Date::Named(i, Some((m, None)), _) => format!("{} of {}", name(*i), month(m)),
+            Date::Named(i, None, relspec) => format!("{} {}", relspec.canonical(), name(*i)),
+            Date::LeapDay(relspec) => format!("{} leap day", relspec.canonical()),
+            Date::LeapYear(relspec) => format!("{} leap year", relspec.canonical()),
+            Date::Relative(relspec, weekday) => {
+                format!("{} {}", relspec.canonical(), weekday.canonical())
+            }
+            Date::RelativeMonth(relspec, m) => format!("{} {}", relspec.canonical(), month(m)),
+            Date::WeekOn(weekday) => format!("{} week", weekday.canonical()),
+            Date::WeekdayDate(weekday, date) => {
+                format!("{}, {}", weekday.canonical(), date.canonical(options))
+            }
+            Date::LastWeekdayOf(weekday, date) => {
+                format!(
+                    "the last {} of {}",
+                    weekday.canonical(),
+                    date.canonical(options)
+                )
+            }
+            Date::Weekday(weekday) => weekday.canonical(),
+            Date::Today => "today".to_owned(),
+            Date::Tomorrow => "tomorrow".to_owned(),
+            Date::Yesterday => "yesterday".to_owned(),
+            Date::DayAfterTomorrow => "the day after tomorrow".to_owned(),
+            Date::DayBeforeYesterday => "the day before yesterday".to_owned(),
+        }
+    }
+
+    /// Whether the date only uses the documented grammar, see
+    /// [`crate::ParseOptions::minimal_grammar`]
+    pub(crate) fn is_documented(&self) -> bool {
+        match self {
+            // A month and day without a year, e.g. "June 5"
+            Date::MonthDay(..) => false,
+            Date::WeekdayDate(_, date) | Date::LastWeekdayOf(_, date) => date.is_documented(),
+            Date::Numeric(..)
+            | Date::MonthDayYear(..)
+            | Date::YearDay(..)
+            | Date::YearMonth(..)
+            | Date::Year(_)
+            | Date::MonthNumDay(..)
+            | Date::Day(_)
+            | Date::MonthYear(..)
+            | Date::UnitRelative(..)
+            | Date::StartOf(..)
+            | Date::EndOf(..)
+            | Date::EndOfNamed(..)
+            | Date::Named(..)
+            | Date::LeapDay(_)
+            | Date::LeapYear(_)
+            | Date::Relative(..)
+            | Date::RelativeMonth(..)
+            | Date::WeekOn(_)
+            | Date::Weekday(_)
+            | Date::Today
+            | Date::Tomorrow
+            | Date::Yesterday
+            | Date::DayAfterTomorrow
+            | Date::DayBeforeYesterday => true,
+        }
+    }
+
+    /// The date of a leading weekday it doesn't fall on
+    fn weekday_mismatch(
+        &self,
+        today: ChronoDate,
+        options: &ParseOptions,
+    ) -> Option<crate::WeekdayMismatch> {
+        let Date::WeekdayDate(weekday, date) = self else {
+            return None;
+        };
+
+        let date = date.to_chrono(today, options).ok()?;
+        let weekday = weekday.to_chrono();
+        (date.weekday() != weekday).then_some(crate::WeekdayMismatch { weekday, date })
+    }
+
+    /// Convert a Date to chrono's NaiveDate, relative to the given date or
+    /// else today
+    pub fn to_chrono(
+        &self,
+        relative_to: ChronoDate,
+        options: &ParseOptions,
+    ) -> Result<ChronoDate, crate::Error> {
+        let mut today = relative_to;
+        let signed_year = |year: u32| {
+            i32::try_from(year)
+                .map_err(|_| crate::Error::invalid_date("Date out of representable date range."))
+        };
         Ok(match self {
             Date::Today => today,
             Date::Yesterday => today - ChronoDuration::days(1),
+            Date::DayAfterTomorrow => today + ChronoDuration::days(2),
+            Date::DayBeforeYesterday => today - ChronoDuration::days(2),
             Date::Tomorrow => today + ChronoDuration::days(1),
-            Date::MonthNumDay(month, day) => ChronoDate::from_ymd_opt(today.year(), *month, *day)
-                .ok_or(crate::Error::InvalidDate(format!(
-                "Invalid month-day: {month}-{day}"
-            )))?,
-            Date::MonthNumDayYear(month, day, year) => {
-                let curr = today.year() as u32;
-                let year = if *year < 100 {
-                    if curr + 10 < 2000 + *year {
-                        1900 + *year
-                    } else {
-                        2000 + *year
-                    }
-                } else {
-                    *year
-                };
+            Date::Numeric(sep, first, second, third) => {
+                let swappable = *first <= 12 && *second <= 12 && first != second;
+                if options.strict && options.date_order.is_none() && swappable {
+                    return Err(crate::Error::ambiguous(format!(
+                        "Order of the month and day {first} and {second}"
+                    )));
+                }
 
-                ChronoDate::from_ymd_opt(year as i32, *month, *day).ok_or(
-                    crate::Error::InvalidDate(format!(
-                        "Invalid year-month-day: {year}-{month}-{day}"
-                    )),
+                match sep.resolve(*first, *second, *third, options) {
+                    (month, day, None) => ChronoDate::from_ymd_opt(today.year(), month, day)
+                        .ok_or(crate::Error::invalid_date(format!(
+                            "Invalid month-day: {month}-{day}"
+                        )))?,
+                    (month, day, Some(year)) => {
+                        let year = resolve_year(year, today, options)?;
+
+                        ChronoDate::from_ymd_opt(signed_year(year)?, month, day).ok_or(
+                            crate::Error::invalid_date(format!(
+                                "Invalid year-month-day: {year}-{month}-{day}"
+                            )),
+                        )?
+                    }
+                }
+            }
+            Date::YearDay(year, day) => ChronoDate::from_yo_opt(signed_year(*year)?, *day).ok_or(
+                crate::Error::invalid_date(format!("Invalid year-day: {year}-{day}")),
+            )?,
+            Date::YearMonth(year, month) => {
+                ChronoDate::from_ymd_opt(signed_year(*year)?, *month, 1).ok_or(
+                    crate::Error::invalid_date(format!("Invalid year-month: {year}-{month}")),
+                )?
+            }
+            Date::Year(year) => {
+                let (month, day) = options.year_anchor;
+                ChronoDate::from_ymd_opt(signed_year(*year)?, month, day).ok_or(
+                    crate::Error::invalid_date(format!("Invalid date: {year}-{month}-{day}")),
                 )?
             }
+            Date::MonthNumDay(month, day) => ChronoDate::from_ymd_opt(today.year(), *month, *day)
+                .ok_or(crate::Error::invalid_date(format!(
+                "Invalid month-day: {month}-{day}"
+            )))?,
             Date::MonthDay(month, day) => {
                 let month = *month as u32;
                 ChronoDate::from_ymd_opt(today.year(), month, *day).ok_or(
-                    crate::Error::InvalidDate(format!("Invalid month-day: {month}-{day}")),
+                    crate::Error::invalid_date(format!("Invalid month-day: {month}-{day}")),
                 )?
             }
+            Date::Day(day) => {
+                // Months too short for the day are skipped
+                let first = today - ChronoDuration::days(i64::from(today.day0()));
+                (0..12)
+                    .filter_map(|i| (first + chrono::Months::new(i)).with_day(*day))
+                    .find(|date| *date >= today)
+                    .ok_or(crate::Error::invalid_date(format!(
+                        "Invalid day of the month: {day}"
+                    )))?
+            }
+            Date::MonthYear(month, year) => {
+                let first = ChronoDate::from_ymd_opt(signed_year(*year)?, *month as u32, 1).ok_or(
+                    crate::Error::invalid_date(format!(
+                        "Invalid year-month: {year}-{}",
+                        *month as u32
+                    )),
+                )?;
+                let days = (first + chrono::Months::new(1) - first).num_days() as u32;
+
+                first + ChronoDuration::days(i64::from(options.month_anchor.clamp(1, days) - 1))
+            }
             Date::MonthDayYear(month, day, year) => {
-                ChronoDate::from_ymd_opt(*year as i32, *month as u32, *day).ok_or(
-                    crate::Error::InvalidDate(format!(
+                let year = resolve_three_digit_year(*year, options)?;
+                ChronoDate::from_ymd_opt(signed_year(year)?, *month as u32, *day).ok_or(
+                    crate::Error::invalid_date(format!(
                         "Invalid year-month-day: {}-{}-{}",
-                        *year, *month as u32, *day
+                        year, *month as u32, *day
                     )),
                 )?
             }
+            Date::RelativeMonth(relspec, month) => {
+                let year = today.year() as u32;
+                let year = match relspec {
+                    RelativeSpecifier::This => year,
+                    RelativeSpecifier::Next if *month as u32 > today.month() => year,
+                    RelativeSpecifier::Next => year + 1,
+                    RelativeSpecifier::Last if (*month as u32) < today.month() => year,
+                    RelativeSpecifier::Last => year - 1,
+                };
+                Date::MonthYear(*month, year).to_chrono(today, options)?
+            }
             Date::Relative(relspec, weekday) => {
                 let weekday = weekday.to_chrono();
 
@@ -287,19 +2145,169 @@ impl Date {
                 let mut date = today;
                 if relspec == &RelativeSpecifier::Next {
                     date = Duration::Specific(1, unit.to_owned())
-                        .after(today.into())
+                        .after(today.into(), options)?
                         .date();
                 }
 
                 if relspec == &RelativeSpecifier::Last {
                     date = Duration::Specific(1, unit.to_owned())
-                        .before(today.into())
+                        .before(today.into(), options)?
                         .date();
                 }
 
                 date
             }
+            Date::StartOf(relspec, unit) => {
+                let date = Date::UnitRelative(*relspec, *unit).to_chrono(relative_to, options)?;
+                match unit {
+                    Unit::Week => {
+                        date - ChronoDuration::days(date.weekday().num_days_from_monday().into())
+                    }
+                    Unit::Month => date.with_day(1).unwrap(),
+                    Unit::Year => date.with_ordinal(1).unwrap(),
+                    Unit::Day | Unit::BusinessDay | Unit::Hour | Unit::Minute => date,
+                }
+            }
+            Date::EndOf(relspec, unit) => {
+                let date = Date::UnitRelative(*relspec, *unit).to_chrono(relative_to, options)?;
+                match unit {
+                    Unit::Week => {
+                        date + ChronoDuration::days(
+                            (6 - date.weekday().num_days_from_monday()).into(),
+                        )
+                    }
+                    Unit::Month => {
+                        date.with_day(1).unwrap() + chrono::Months::new(1) - ChronoDuration::days(1)
+                    }
+                    Unit::Year => ChronoDate::from_ymd_opt(date.year(), 12, 31).unwrap(),
+                    Unit::Day | Unit::BusinessDay | Unit::Hour | Unit::Minute => date,
+                }
+            }
+            Date::Named(i, qualifier, relspec) => {
+                let named = options
+                    .named_dates
+                    .get(*i)
+                    .ok_or(crate::Error::invalid_date(format!(
+                        "Unknown named date: {i}"
+                    )))?;
+                let name = named
+                    .names()
+                    .first()
+                    .copied()
+                    .unwrap_or_default()
+                    .to_owned();
+                let in_progress = |start| named.period_end(start).is_some_and(|end| end >= today);
+
+                let date = match (qualifier, relspec) {
+                    (Some((month, year)), _) => {
+                        let year = match year {
+                            Some(year) => signed_year(resolve_year(*year, today, options)?)?,
+                            None => today.year(),
+                        };
+                        named.in_month(year, *month as u32)
+                    }
+                    // A period in progress is this one, but not the last one
+                    (None, RelativeSpecifier::This) => named
+                        .previous_occurrence(today)
+                        .filter(|&start| in_progress(start))
+                        .or_else(|| named.next_occurrence(today)),
+                    (None, RelativeSpecifier::Next) => today
+                        .succ_opt()
+                        .and_then(|date| named.next_occurrence(date)),
+                    (None, RelativeSpecifier::Last) => today
+                        .pred_opt()
+                        .and_then(|date| named.previous_occurrence(date))
+                        .and_then(|start| match in_progress(start) {
+                            true => start
+                                .pred_opt()
+                                .and_then(|date| named.previous_occurrence(date)),
+                            false => Some(start),
+                        }),
+                };
+
+                date.ok_or(crate::Error::invalid_date(format!(
+                    "No occurrence of {name}"
+                )))?
+            }
+            Date::EndOfNamed(i, relspec) => {
+                let start = Date::Named(*i, None, *relspec).to_chrono(relative_to, options)?;
+                let named = &options.named_dates[*i];
+                let name = named
+                    .names()
+                    .first()
+                    .copied()
+                    .unwrap_or_default()
+                    .to_owned();
+
+                named
+                    .period_end(start)
+                    .ok_or(crate::Error::invalid_date(format!(
+                        "{name} is not a period"
+                    )))?
+            }
+            Date::LeapDay(relspec) | Date::LeapYear(relspec) => {
+                // Leap years are at most eight years apart
+                let year = today.year();
+                let leap_day = |year| ChronoDate::from_ymd_opt(year, 2, 29);
+                let date = match (self, relspec) {
+                    (_, RelativeSpecifier::This) => leap_day(year),
+                    (Date::LeapDay(_), RelativeSpecifier::Next) => (year..=year + 8)
+                        .filter_map(leap_day)
+                        .find(|&date| date > today),
+                    (Date::LeapDay(_), RelativeSpecifier::Last) => (year - 8..=year)
+                        .rev()
+                        .filter_map(leap_day)
+                        .find(|&date| date < today),
+                    (_, RelativeSpecifier::Next) => (year + 1..=year + 8).find_map(leap_day),
+                    (_, RelativeSpecifier::Last) => (year - 8..year).rev().find_map(leap_day),
+                };
+
+                date.ok_or(crate::Error::invalid_date(format!(
+                    "No leap day near the year {year}"
+                )))?
+            }
+            Date::WeekdayDate(weekday, date) => {
+                let date = date.to_chrono(relative_to, options)?;
+                let weekday = weekday.to_chrono();
+                if options.weekday_check == WeekdayCheck::Error && date.weekday() != weekday {
+                    return Err(crate::Error::invalid_date(format!(
+                        "{date} is a {}, not a {weekday}",
+                        date.weekday()
+                    )));
+                }
+
+                date
+            }
+            Date::LastWeekdayOf(weekday, date) => {
+                let date = date.to_chrono(relative_to, options)?;
+                let weekday = weekday.to_chrono();
+
+                // Walk backward from the last day of the month
+                let mut date =
+                    date.with_day(1).unwrap() + chrono::Months::new(1) - ChronoDuration::days(1);
+                while date.weekday() != weekday {
+                    date -= ChronoDuration::days(1);
+                }
+
+                date
+            }
+            Date::WeekOn(weekday) => {
+                let weekday = weekday.to_chrono();
+                let mut date = today + ChronoDuration::days(1);
+
+                while date.weekday() != weekday {
+                    date += ChronoDuration::days(1);
+                }
+
+                date + ChronoDuration::weeks(1)
+            }
             Date::Weekday(weekday) => {
+                if options.strict {
+                    return Err(crate::Error::ambiguous(format!(
+                        "Week of the bare weekday {weekday:?}"
+                    )));
+                }
+
                 let weekday = weekday.to_chrono();
                 let mut date = today;
 
@@ -313,7 +2321,94 @@ impl Date {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The punctuation separating the fields of a numeric date
+pub enum DateSeparator {
+    Slash,
+    Dash,
+    Dot,
+}
+
+impl DateSeparator {
+    fn parse(l: &[Lexeme]) -> Option<(Self, usize)> {
+        stats::attempt();
+        match l.first() {
+            Some(Lexeme::Slash) => Some((Self::Slash, 1)),
+            Some(Lexeme::Dash) => Some((Self::Dash, 1)),
+            Some(Lexeme::Dot) => Some((Self::Dot, 1)),
+            _ => None,
+        }
+    }
+
+    /// Order the fields of a numeric date into month, day, and year.
+    ///
+    /// A four digit first field is always a year, otherwise the configured
+    /// order is used, defaulting to DMY for dots and MDY for everything else.
+    fn resolve(
+        self,
+        first: u32,
+        second: u32,
+        third: Option<u32>,
+        options: &ParseOptions,
+    ) -> (u32, u32, Option<u32>) {
+        let order = if first >= 1000 {
+            DateOrder::YearMonthDay
+        } else if let Some(order) = options.date_order {
+            order
+        } else if self == DateSeparator::Dot {
+            DateOrder::DayMonthYear
+        } else {
+            DateOrder::MonthDayYear
+        };
+
+        match (order, third) {
+            (DateOrder::MonthDayYear, year) => (first, second, year),
+            (DateOrder::DayMonthYear, year) => (second, first, year),
+            (DateOrder::YearMonthDay, Some(day)) => (second, day, Some(first)),
+            (DateOrder::YearMonthDay, None) => (first, second, None),
+        }
+    }
+}
+
+/// Expand two digit years to the closest century, up to ten years in the
+/// future, and apply the configured rule to three digit years
+fn resolve_year(year: u32, today: ChronoDate, options: &ParseOptions) -> Result<u32, crate::Error> {
+    if year >= 100 {
+        return resolve_three_digit_year(year, options);
+    }
+
+    if options.strict {
+        return Err(crate::Error::ambiguous(format!(
+            "Century of the two digit year {year:02}"
+        )));
+    }
+
+    let curr = today.year() as u32;
+    if curr + 10 < 2000 + year {
+        Ok(1900 + year)
+    } else {
+        Ok(2000 + year)
+    }
+}
+
+/// Apply the configured rule to years written with three digits, leaving
+/// all other years untouched
+fn resolve_three_digit_year(year: u32, options: &ParseOptions) -> Result<u32, crate::Error> {
+    if !(100..1000).contains(&year) {
+        return Ok(year);
+    }
+
+    options
+        .three_digit_years
+        .resolve(year)
+        .ok_or(crate::Error::invalid_date(format!(
+            "Three digit year: {year}"
+        )))
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RelativeSpecifier {
     This,
     Next,
@@ -321,8 +2416,17 @@ pub enum RelativeSpecifier {
 }
 
 impl RelativeSpecifier {
+    fn canonical(&self) -> &'static str {
+        match self {
+            RelativeSpecifier::This => "this",
+            RelativeSpecifier::Next => "next",
+            RelativeSpecifier::Last => "last",
+        }
+    }
+
     fn parse(l: &[Lexeme]) -> Option<(Self, usize)> {
-        let res = match l.get(0) {
+        stats::attempt();
+        let res = match l.first() {
             Some(Lexeme::This) => Some(Self::This),
             Some(Lexeme::Next) => Some(Self::Next),
             Some(Lexeme::Last) => Some(Self::Last),
@@ -333,7 +2437,8 @@ impl RelativeSpecifier {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Weekday {
     Monday,
     Tuesday,
@@ -344,9 +2449,29 @@ pub enum Weekday {
     Sunday,
 }
 
+impl From<ChronoWeekday> for Weekday {
+    fn from(weekday: ChronoWeekday) -> Self {
+        match weekday {
+            ChronoWeekday::Mon => Weekday::Monday,
+            ChronoWeekday::Tue => Weekday::Tuesday,
+            ChronoWeekday::Wed => Weekday::Wednesday,
+            ChronoWeekday::Thu => Weekday::Thursday,
+            ChronoWeekday::Fri => Weekday::Friday,
+            ChronoWeekday::Sat => Weekday::Saturday,
+            ChronoWeekday::Sun => Weekday::Sunday,
+        }
+    }
+}
+
 impl Weekday {
+    fn canonical(&self) -> String {
+        format!("{self:?}").to_lowercase()
+    }
+
     fn parse(l: &[Lexeme]) -> Option<(Self, usize)> {
-        let res = match l.get(0) {
+        stats::attempt();
+        memo::attempt(Rule::Weekday, l);
+        let res = match l.first() {
             Some(Lexeme::Sunday) => Some(Self::Sunday),
             Some(Lexeme::Monday) => Some(Self::Monday),
             Some(Lexeme::Tuesday) => Some(Self::Tuesday),
@@ -360,7 +2485,8 @@ impl Weekday {
         res.map(|e| (e, 1))
     }
 
-    fn to_chrono(&self) -> ChronoWeekday {
+    /// The equivalent chrono weekday
+    pub fn to_chrono(&self) -> ChronoWeekday {
         match *self {
             Weekday::Monday => ChronoWeekday::Mon,
             Weekday::Tuesday => ChronoWeekday::Tue,
@@ -374,6 +2500,7 @@ impl Weekday {
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Month {
     January = 1,
     February = 2,
@@ -391,7 +2518,9 @@ pub enum Month {
 
 impl Month {
     fn parse(l: &[Lexeme]) -> Option<(Self, usize)> {
-        let res = match l.get(0) {
+        stats::attempt();
+        memo::attempt(Rule::Month, l);
+        let res = match l.first() {
             Some(Lexeme::January) => Some(Self::January),
             Some(Lexeme::February) => Some(Self::February),
             Some(Lexeme::March) => Some(Self::March),
@@ -411,16 +2540,38 @@ impl Month {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+/// The time of a date, on a 24 hour clock unless stated otherwise
 pub enum Time {
     HourMin(u32, u32),
     HourMinAM(u32, u32),
     HourMinPM(u32, u32),
+    /// A part of the day, e.g. "evening"
+    DayPart(DayPart),
+    /// An hour and minute read on the clock of a part of the day, e.g.
+    /// "8 in the evening" or "morning at 8"
+    DayPartHourMin(DayPart, u32, u32),
+    /// A twelve hour time before noon given with a part of the day it must
+    /// fall in, e.g. "tonight at 2am"
+    DayPartHourMinAM(DayPart, u32, u32),
+    /// A twelve hour time after noon given with a part of the day it must
+    /// fall in, e.g. "tomorrow evening at 8pm"
+    DayPartHourMinPM(DayPart, u32, u32),
+    /// Half an hour next to an hour, e.g. "half eleven", which is before or
+    /// after it as set with [`crate::ParseOptions::half_hour`]
+    Half(u32),
     Empty,
 }
 
 impl Time {
     fn parse(l: &[Lexeme]) -> Option<(Self, usize)> {
+        memo::memoized(Rule::Time, l, Self::parse_uncached)
+    }
+
+    fn parse_uncached(l: &[Lexeme]) -> Option<(Self, usize)> {
+        stats::attempt();
         let mut tokens = 0;
 
         if let Some(&Lexeme::Midnight) = l.get(tokens) {
@@ -433,6 +2584,30 @@ impl Time {
             return Some((Time::HourMin(12, 0), tokens));
         }
 
+        if let Some(&Lexeme::Half) = l.get(tokens) {
+            if let Some((hour, t)) = Num::parse(&l[tokens + 1..]) {
+                tokens += t + 1;
+                return Some((Time::Half(hour), tokens));
+            }
+        }
+
+        // A part of the day may be introduced by "in the", e.g. "tomorrow in
+        // the evening"
+        if l.get(tokens) == Some(&Lexeme::In) && l.get(tokens + 1) == Some(&Lexeme::The) {
+            tokens += 2;
+        }
+
+        if let Some(part) = day_part(l.get(tokens)) {
+            tokens += 1;
+
+            let (time, t) = Time::parse_day_part(part, &l[tokens..]);
+            tokens += t;
+            return Some((time, tokens));
+        }
+
+        stats::backtrack(tokens);
+        tokens = 0;
+
         if let Some((hour, t)) = Num::parse(&l[tokens..]) {
             tokens += t;
             if l.get(tokens) == Some(&Lexeme::Colon) {
@@ -446,34 +2621,330 @@ impl Time {
                     } else if let Some(&Lexeme::PM) = l.get(tokens) {
                         tokens += 1;
                         return Some((Time::HourMinPM(hour, min), tokens));
+                    } else if let Some((part, t)) = Time::parse_in_day_part(&l[tokens..]) {
+                        tokens += t;
+                        return Some((Time::DayPartHourMin(part, hour, min), tokens));
                     } else {
                         return Some((Time::HourMin(hour, min), tokens));
                     }
                 }
+            } else if l.get(tokens) == Some(&Lexeme::OClock) {
+                tokens += 1;
+
+                if let Some((part, t)) = Time::parse_in_day_part(&l[tokens..]) {
+                    tokens += t;
+                    return Some((Time::DayPartHourMin(part, hour, 0), tokens));
+                }
+
+                return Some((Time::HourMin(hour, 0), tokens));
+            } else if let Some((part, t)) = Time::parse_in_day_part(&l[tokens..]) {
+                tokens += t;
+                return Some((Time::DayPartHourMin(part, hour, 0), tokens));
+            } else if l.get(tokens) == Some(&Lexeme::AM) {
+                tokens += 1;
+                return Some((Time::HourMinAM(hour, 0), tokens));
+            } else if l.get(tokens) == Some(&Lexeme::PM) {
+                tokens += 1;
+                return Some((Time::HourMinPM(hour, 0), tokens));
             }
         }
 
+        stats::backtrack(tokens);
         tokens = 0;
+
         Some((Self::Empty, tokens))
     }
 
-    fn to_chrono(&self, default: ChronoTime) -> Result<ChronoTime, crate::Error> {
+    /// Parse the part of the day following a time, e.g. "in the evening"
+    /// or "at night"
+    fn parse_in_day_part(l: &[Lexeme]) -> Option<(DayPart, usize)> {
+        stats::attempt();
+        let mut tokens = 0;
+
+        if l.get(tokens) == Some(&Lexeme::In) && l.get(tokens + 1) == Some(&Lexeme::The) {
+            tokens += 2;
+        } else if l.get(tokens) == Some(&Lexeme::At) && l.get(tokens + 1) == Some(&Lexeme::Night) {
+            tokens += 1;
+        }
+
+        let part = day_part(l.get(tokens))?;
+        tokens += 1;
+        Some((part, tokens))
+    }
+
+    /// Parse the clock time which may follow a part of the day, e.g. the
+    /// "at 8" of "morning at 8". Without one the time is the part itself.
+    fn parse_day_part(part: DayPart, l: &[Lexeme]) -> (Self, usize) {
+        stats::attempt();
+        let mut tokens = 0;
+
+        if l.get(tokens) == Some(&Lexeme::At) {
+            tokens += 1;
+
+            if let Some((hour, t)) = Num::parse(&l[tokens..]) {
+                tokens += t;
+
+                let mut min = 0;
+                if l.get(tokens) == Some(&Lexeme::Colon) {
+                    if let Some((m, t)) = Num::parse(&l[tokens + 1..]) {
+                        tokens += t + 1;
+                        min = m;
+                    }
+                }
+
+                match l.get(tokens) {
+                    Some(Lexeme::AM) => {
+                        return (Time::DayPartHourMinAM(part, hour, min), tokens + 1)
+                    }
+                    Some(Lexeme::PM) => {
+                        return (Time::DayPartHourMinPM(part, hour, min), tokens + 1)
+                    }
+                    Some(Lexeme::OClock) => tokens += 1,
+                    _ => (),
+                }
+
+                return (Time::DayPartHourMin(part, hour, min), tokens);
+            }
+        }
+
+        stats::backtrack(tokens);
+        (Time::DayPart(part), 0)
+    }
+
+    /// Parse a time which may be introduced by "at", e.g. "at midnight".
+    /// If "at" is present the time may not be empty, and may also be an
+    /// hour followed by its unit, e.g. the French "à 17h".
+    fn parse_at(l: &[Lexeme]) -> Option<(Self, usize)> {
+        stats::attempt();
+        if l.first() == Some(&Lexeme::At) {
+            if let Some((hour, t)) = Num::parse(&l[1..]) {
+                if l.get(t + 1) == Some(&Lexeme::Hour) {
+                    return Some((Time::HourMin(hour, 0), t + 2));
+                }
+            }
+
+            let (time, t) = Time::parse(&l[1..])?;
+            if time == Time::Empty {
+                return None;
+            }
+
+            return Some((time, t + 1));
+        }
+
+        Time::parse(l)
+    }
+
+    /// Describe the time in words, `None` if there is none
+    fn describe(&self) -> Option<String> {
+        let part = day_part_words;
+
+        Some(match *self {
+            Time::HourMin(hour, min) => format!("at {hour}:{min:02}"),
+            Time::HourMinAM(hour, min) => format!("at {hour}:{min:02} am"),
+            Time::HourMinPM(hour, min) => format!("at {hour}:{min:02} pm"),
+            Time::DayPart(p) => part(p).to_owned(),
+            Time::DayPartHourMin(p, hour, min) => format!("at {hour}:{min:02} {}", part(p)),
+            Time::DayPartHourMinAM(p, hour, min) => {
+                format!("at {hour}:{min:02} am {}", part(p))
+            }
+            Time::DayPartHourMinPM(p, hour, min) => {
+                format!("at {hour}:{min:02} pm {}", part(p))
+            }
+            Time::Half(hour) => format!("at half {hour}"),
+            Time::Empty => return None,
+        })
+    }
+
+    /// Write the time in English the way the parser reads it back, `None`
+    /// if there is none
+    fn canonical(&self) -> Option<String> {
+        let part = |part: DayPart| format!("{part:?}").to_lowercase();
+
+        Some(match *self {
+            Time::HourMin(hour, min) => format!("at {hour}:{min:02}"),
+            Time::HourMinAM(hour, min) => format!("at {hour}:{min:02} am"),
+            Time::HourMinPM(hour, min) => format!("at {hour}:{min:02} pm"),
+            Time::DayPart(p) => format!("in the {}", part(p)),
+            Time::DayPartHourMin(p, hour, min) => format!("in the {} at {hour}:{min:02}", part(p)),
+            Time::DayPartHourMinAM(p, hour, min) => {
+                format!("in the {} at {hour}:{min:02} am", part(p))
+            }
+            Time::DayPartHourMinPM(p, hour, min) => {
+                format!("in the {} at {hour}:{min:02} pm", part(p))
+            }
+            Time::Half(hour) => format!("at half {hour}"),
+            Time::Empty => return None,
+        })
+    }
+
+    /// Convert a Time to chrono's NaiveTime, using the default where no
+    /// time is given
+    pub fn to_chrono(
+        &self,
+        default: ChronoTime,
+        options: &ParseOptions,
+    ) -> Result<ChronoTime, crate::Error> {
         match *self {
             Time::Empty => Ok(default),
+            Time::DayPart(part) => Ok(options.day_part_time(part)),
+            Time::DayPartHourMin(part, hour, min) => {
+                // Hours on a twelve hour clock are moved into the part of
+                // the day, while those past noon are already on a 24 hour
+                // one. Midnight ends the evening and the night.
+                let clock = match (part, hour) {
+                    (DayPart::Morning, 12) | (DayPart::Evening | DayPart::Night, 0 | 12) => Some(0),
+                    (DayPart::Morning, 0..=11) | (DayPart::Night, 1..=5) => Some(hour),
+                    (DayPart::Afternoon | DayPart::Evening | DayPart::Night, 1..=11) => {
+                        Some(hour + 12)
+                    }
+                    (DayPart::Afternoon | DayPart::Evening | DayPart::Night, 13..=23) => Some(hour),
+                    (DayPart::Afternoon, 12) => Some(12),
+                    _ => None,
+                };
+
+                clock
+                    .and_then(|clock| ChronoTime::from_hms_opt(clock, min, 0))
+                    .ok_or(crate::Error::invalid_date(format!(
+                        "Invalid time: {hour}:{min:02} {}",
+                        day_part_words(part)
+                    )))
+            }
+            Time::DayPartHourMinAM(part, hour, min) | Time::DayPartHourMinPM(part, hour, min) => {
+                let pm = matches!(self, Time::DayPartHourMinPM(..));
+                let time = Time::twelve_hour(hour, min, pm)?;
+
+                // The time must agree with the part of the day, e.g. not
+                // "tomorrow morning at 8pm"
+                let agrees = match part {
+                    DayPart::Morning => !pm,
+                    DayPart::Afternoon => pm,
+                    DayPart::Evening => pm != (hour == 12),
+                    DayPart::Night if pm => (6..=11).contains(&hour),
+                    DayPart::Night => matches!(hour, 1..=5 | 12),
+                };
+                if !agrees {
+                    let suffix = if pm { "pm" } else { "am" };
+                    return Err(crate::Error::invalid_date(format!(
+                        "Invalid time: {hour}:{min:02} {suffix} isn't {}",
+                        day_part_words(part)
+                    )));
+                }
+
+                Ok(time)
+            }
             Time::HourMin(hour, min) => ChronoTime::from_hms_opt(hour, min, 0).ok_or(
-                crate::Error::InvalidDate(format!("Invalid time: {hour}:{min}")),
-            ),
-            Time::HourMinAM(hour, min) => ChronoTime::from_hms_opt(hour, min, 0).ok_or(
-                crate::Error::InvalidDate(format!("Invalid time: {hour}:{min} am")),
-            ),
-            Time::HourMinPM(hour, min) => ChronoTime::from_hms_opt(hour + 12, min, 0).ok_or(
-                crate::Error::InvalidDate(format!("Invalid time: {hour}:{min} pm")),
+                crate::Error::invalid_date(format!("Invalid time: {hour}:{min}")),
             ),
+            Time::Half(hour) => {
+                let clock = match options.half_hour_reading() {
+                    HalfHour::Past => Some(hour),
+                    HalfHour::To => hour.checked_sub(1),
+                };
+
+                clock
+                    .and_then(|clock| ChronoTime::from_hms_opt(clock, 30, 0))
+                    .ok_or(crate::Error::invalid_date(format!(
+                        "Invalid time: half {hour}"
+                    )))
+            }
+            Time::HourMinAM(hour, min) => Time::twelve_hour(hour, min, false),
+            Time::HourMinPM(hour, min) => Time::twelve_hour(hour, min, true),
+        }
+    }
+
+    /// The date the time falls on when given with `date`, the next day for
+    /// the small hours of its evening or night, e.g. "friday at 2 at night"
+    fn date_from(&self, date: ChronoDate) -> Result<ChronoDate, crate::Error> {
+        let next_day = match *self {
+            Time::DayPartHourMin(DayPart::Night, hour, _) => matches!(hour, 0..=5 | 12),
+            Time::DayPartHourMin(DayPart::Evening, hour, _) => matches!(hour, 0 | 12),
+            Time::DayPartHourMinAM(DayPart::Evening | DayPart::Night, ..) => true,
+            _ => false,
+        };
+        if !next_day {
+            return Ok(date);
+        }
+
+        date.succ_opt()
+            .ok_or(crate::Error::invalid_date(format!("No day after {date}")))
+    }
+
+    /// Convert an hour on a twelve hour clock, where 12am is midnight and
+    /// 12pm is noon
+    fn twelve_hour(hour: u32, min: u32, pm: bool) -> Result<ChronoTime, crate::Error> {
+        let suffix = if pm { "pm" } else { "am" };
+        if !(1..=12).contains(&hour) {
+            return Err(crate::Error::invalid_date(format!(
+                "Invalid time: {hour}:{min:02} {suffix}, hours on a 12 hour clock are 1 to 12"
+            )));
         }
+
+        let clock = hour % 12 + if pm { 12 } else { 0 };
+        ChronoTime::from_hms_opt(clock, min, 0).ok_or(crate::Error::invalid_date(format!(
+            "Invalid time: {hour}:{min:02} {suffix}"
+        )))
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+/// The English name of a month number, for descriptions
+fn month_name(month: u32) -> &'static str {
+    const NAMES: [&str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+
+    month
+        .checked_sub(1)
+        .and_then(|i| NAMES.get(i as usize))
+        .copied()
+        .unwrap_or("month")
+}
+
+/// An English ordinal number, e.g. "2nd", for descriptions
+fn ordinal(n: u32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{n}{suffix}")
+}
+
+/// The part of the day named by a lexeme
+/// A part of the day as it follows a time in words, e.g. "at night"
+fn day_part_words(part: DayPart) -> &'static str {
+    match part {
+        DayPart::Morning => "in the morning",
+        DayPart::Afternoon => "in the afternoon",
+        DayPart::Evening => "in the evening",
+        DayPart::Night => "at night",
+    }
+}
+
+fn day_part(l: Option<&Lexeme>) -> Option<DayPart> {
+    match l? {
+        Lexeme::Morning => Some(DayPart::Morning),
+        Lexeme::Afternoon => Some(DayPart::Afternoon),
+        Lexeme::Evening => Some(DayPart::Evening),
+        Lexeme::Night => Some(DayPart::Night),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Article {
     A,
     An,
@@ -482,7 +2953,8 @@ pub enum Article {
 
 impl Article {
     fn parse(l: &[Lexeme]) -> Option<(Self, usize)> {
-        match l.get(0) {
+        stats::attempt();
+        match l.first() {
             Some(Lexeme::A) => Some((Self::A, 1)),
             Some(Lexeme::An) => Some((Self::An, 1)),
             Some(Lexeme::The) => Some((Self::The, 1)),
@@ -491,15 +2963,37 @@ impl Article {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+/// A length of time in calendar units
 pub enum Duration {
     Article(Unit),
     Specific(u32, Unit),
+    /// A count given by a word, e.g. "a couple of days", which is set on
+    /// the options
+    Vague(Quantity, Unit),
     Concat(Box<Duration>, Box<Duration>),
 }
 
 impl Duration {
-    fn parse(l: &[Lexeme]) -> Option<(Self, usize)> {
+    /// A number of units, e.g. "3 days"
+    pub fn new(count: u32, unit: Unit) -> Self {
+        Duration::Specific(count, unit)
+    }
+
+    /// The duration followed by another, e.g. "3 days and 2 hours"
+    pub fn and(self, other: Duration) -> Self {
+        Duration::Concat(Box::new(self), Box::new(other))
+    }
+
+    /// Parse a duration from a slice of lexemes
+    pub fn parse(l: &[Lexeme]) -> Option<(Self, usize)> {
+        memo::scoped(Rule::Duration, l, Self::parse_scoped)
+    }
+
+    fn parse_scoped(l: &[Lexeme]) -> Option<(Self, usize)> {
+        stats::attempt();
         let mut tokens = 0;
         if let Some((d, t)) = Duration::parse_concrete(l) {
             tokens += t;
@@ -510,8 +3004,19 @@ impl Duration {
                 if let Some((dur2, t)) = Duration::parse(&l[tokens..]) {
                     tokens += t;
 
-                    return Some((Duration::Concat(Box::new(d), Box::new(dur2)), tokens));
+                    return Some((
+                        Duration::Concat(stats::boxed(d), stats::boxed(dur2)),
+                        tokens,
+                    ));
                 }
+            } else if let Some((dur2, t)) = Duration::parse(&l[tokens..]) {
+                // Durations may also be listed without "and", e.g. "1 year 2 months"
+                tokens += t;
+
+                return Some((
+                    Duration::Concat(stats::boxed(d), stats::boxed(dur2)),
+                    tokens,
+                ));
             }
 
             return Some((d, t));
@@ -521,8 +3026,22 @@ impl Duration {
     }
 
     fn parse_concrete(l: &[Lexeme]) -> Option<(Self, usize)> {
+        stats::attempt();
         let mut tokens = 0;
 
+        // A decimal number of units, e.g. "1.5 hours"
+        if let Some(&Lexeme::Decimal(whole, millionths)) = l.get(tokens) {
+            tokens += 1;
+            if let Some((u, t)) = Unit::parse(&l[tokens..]) {
+                tokens += t;
+                let numerator = u64::from(whole) * 1_000_000 + u64::from(millionths);
+                return Some((Self::fraction(u, numerator, 1_000_000)?, tokens));
+            }
+        }
+
+        stats::backtrack(tokens);
+        tokens = 0;
+
         if let Some((num, t)) = Num::parse(&l[tokens..]) {
             tokens += t;
             if let Some((u, t)) = Unit::parse(&l[tokens..]) {
@@ -531,7 +3050,63 @@ impl Duration {
             }
         }
 
+        // A fraction of a unit, e.g. "half an hour" or "a quarter of a year"
+        stats::backtrack(tokens);
+        tokens = 0;
+        if let Some((_, t)) = Article::parse(l) {
+            tokens += t;
+        }
+
+        let denominator = match l.get(tokens) {
+            Some(Lexeme::Half) => Some(2),
+            Some(Lexeme::Quarter) => Some(4),
+            _ => None,
+        };
+
+        if let Some(denominator) = denominator {
+            tokens += 1;
+            if l.get(tokens) == Some(&Lexeme::Of) {
+                tokens += 1;
+            }
+            if let Some((_, t)) = Article::parse(&l[tokens..]) {
+                tokens += t;
+            }
+
+            if let Some((u, t)) = Unit::parse(&l[tokens..]) {
+                tokens += t;
+                return Some((Self::fraction(u, 1, denominator)?, tokens));
+            }
+        }
+
+        // A vague quantity, e.g. "a couple of days" or "several weeks"
+        stats::backtrack(tokens);
+        tokens = 0;
+        if let Some((_, t)) = Article::parse(l) {
+            tokens += t;
+        }
+
+        let quantity = match l.get(tokens) {
+            Some(Lexeme::Couple) => Some(Quantity::Couple),
+            Some(Lexeme::Few) => Some(Quantity::Few),
+            Some(Lexeme::Several) => Some(Quantity::Several),
+            _ => None,
+        };
+
+        if let Some(quantity) = quantity {
+            tokens += 1;
+            if l.get(tokens) == Some(&Lexeme::Of) {
+                tokens += 1;
+            }
+
+            if let Some((u, t)) = Unit::parse(&l[tokens..]) {
+                tokens += t;
+                return Some((Self::Vague(quantity, u), tokens));
+            }
+        }
+
+        stats::backtrack(tokens);
         tokens = 0;
+
         if let Some((_, t)) = Article::parse(l) {
             tokens += t;
             if let Some((u, t)) = Unit::parse(&l[tokens..]) {
@@ -543,10 +3118,37 @@ impl Duration {
         None
     }
 
+    /// A fraction of a unit, e.g. half an hour as 30 minutes, in the
+    /// largest unit it is a whole number of, if any
+    fn fraction(unit: Unit, numerator: u64, denominator: u64) -> Option<Self> {
+        if numerator.is_multiple_of(denominator) {
+            let count = u32::try_from(numerator / denominator).ok()?;
+            return Some(Self::Specific(count, unit));
+        }
+
+        let (size, units): (u64, &[(u64, Unit)]) = match unit {
+            Unit::Year => (12, &[(1, Unit::Month)]),
+            Unit::Week => (7 * 24 * 60, MINUTE_UNITS),
+            Unit::Day => (24 * 60, MINUTE_UNITS),
+            Unit::Hour => (60, MINUTE_UNITS),
+            Unit::Minute | Unit::Month | Unit::BusinessDay => return None,
+        };
+
+        let count = size.checked_mul(numerator)?;
+        if !count.is_multiple_of(denominator) {
+            return None;
+        }
+        let count = count / denominator;
+
+        let &(size, unit) = units.iter().find(|(size, _)| count.is_multiple_of(*size))?;
+        Some(Self::Specific(u32::try_from(count / size).ok()?, unit))
+    }
+
     fn unit(&self) -> &Unit {
         match self {
             Duration::Article(u) => u,
             Duration::Specific(_, u) => u,
+            Duration::Vague(_, u) => u,
             _ => unimplemented!(),
         }
     }
@@ -555,76 +3157,320 @@ impl Duration {
         match *self {
             Duration::Article(_) => 1,
             Duration::Specific(num, _) => num,
+            // Without options the default count is used
+            Duration::Vague(quantity, _) => quantity.default_count(),
             _ => unimplemented!(),
         }
     }
 
+    /// Rebalance the duration into its largest whole units, from years down
+    /// to minutes, e.g. "90 minutes" into "1 hour and 30 minutes"
+    ///
+    /// Months and years are only rebalanced with each other, since their
+    /// length in days varies, and business days are kept as they are. A
+    /// unit whose total doesn't fit its count is an error.
+    pub fn normalize(&self) -> Result<Self, crate::Error> {
+        let mut months: u64 = 0;
+        let mut business_days: u64 = 0;
+        let mut minutes: u64 = 0;
+        self.for_each_part(&mut |num, unit| {
+            let num = u64::from(num);
+            match unit {
+                Unit::Year => months += num * 12,
+                Unit::Month => months += num,
+                Unit::BusinessDay => business_days += num,
+                Unit::Week => minutes += num * 7 * 24 * 60,
+                Unit::Day => minutes += num * 24 * 60,
+                Unit::Hour => minutes += num * 60,
+                Unit::Minute => minutes += num,
+            }
+        });
+
+        let parts = [
+            (months / 12, Unit::Year),
+            (months % 12, Unit::Month),
+            (business_days, Unit::BusinessDay),
+            (minutes / (7 * 24 * 60), Unit::Week),
+            (minutes / (24 * 60) % 7, Unit::Day),
+            (minutes / 60 % 24, Unit::Hour),
+            (minutes % 60, Unit::Minute),
+        ];
+
+        let parts = parts
+            .into_iter()
+            .filter(|&(num, _)| num > 0)
+            .map(|(num, unit)| match u32::try_from(num) {
+                Ok(num) => Ok(Self::Specific(num, unit)),
+                Err(_) => Err(crate::Error::invalid_date(format!(
+                    "Duration out of range: {num} {unit}s"
+                ))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(parts
+            .into_iter()
+            .reduce(|dur1, dur2| Self::Concat(Box::new(dur1), Box::new(dur2)))
+            .unwrap_or(Self::Specific(0, Unit::Minute)))
+    }
+
+    /// Write the duration in English the way the parser reads it back,
+    /// keeping articles and words for quantities
+    fn canonical(&self) -> String {
+        let plural = |unit: &Unit| format!("{unit}s");
+
+        match self {
+            Duration::Article(Unit::Hour) => "an hour".to_owned(),
+            Duration::Article(unit) => format!("a {unit}"),
+            Duration::Specific(1, unit) => format!("1 {unit}"),
+            Duration::Specific(n, unit) => format!("{n} {}", plural(unit)),
+            Duration::Vague(Quantity::Couple, unit) => format!("a couple of {}", plural(unit)),
+            Duration::Vague(Quantity::Few, unit) => format!("a few {}", plural(unit)),
+            Duration::Vague(Quantity::Several, unit) => format!("several {}", plural(unit)),
+            Duration::Concat(first, second) => {
+                format!("{} and {}", first.canonical(), second.canonical())
+            }
+        }
+    }
+
+    /// Call `f` with the number and unit of every part of the duration
+    fn for_each_part(&self, f: &mut impl FnMut(u32, Unit)) {
+        match self {
+            Duration::Concat(dur1, dur2) => {
+                dur1.for_each_part(f);
+                dur2.for_each_part(f);
+            }
+            _ => f(self.num(), *self.unit()),
+        }
+    }
+
     fn convertable(&self) -> bool {
         if let Duration::Concat(dur1, dur2) = self {
             return dur1.convertable() && dur2.convertable();
         }
 
         let unit = self.unit();
-        unit != &Unit::Month && unit != &Unit::Year
+        unit != &Unit::Month && unit != &Unit::Year && unit != &Unit::BusinessDay
     }
 
-    fn to_chrono(&self) -> ChronoDuration {
+    /// The fixed length of a convertable duration, or `None` if it is too
+    /// long to be represented
+    fn to_chrono(&self) -> Option<ChronoDuration> {
         if let Duration::Concat(dur1, dur2) = self {
-            return dur1.to_chrono() + dur2.to_chrono();
+            return dur1.to_chrono()?.checked_add(&dur2.to_chrono()?);
         }
 
         let unit = self.unit();
-        let num = self.num();
+        let num = i64::from(self.num());
 
         match unit {
-            Unit::Day => ChronoDuration::days(num as i64),
-            Unit::Week => ChronoDuration::weeks(num as i64),
-            Unit::Hour => ChronoDuration::hours(num as i64),
-            Unit::Minute => ChronoDuration::minutes(num as i64),
+            Unit::Day => ChronoDuration::try_days(num),
+            Unit::Week => ChronoDuration::try_weeks(num),
+            Unit::Hour => ChronoDuration::try_hours(num),
+            Unit::Minute => ChronoDuration::try_minutes(num),
             _ => unreachable!(),
         }
     }
 
-    fn after(&self, date: ChronoDateTime) -> ChronoDateTime {
+    /// The datetime a duration after the given one
+    pub fn after(
+        &self,
+        date: ChronoDateTime,
+        options: &ParseOptions,
+    ) -> Result<ChronoDateTime, crate::Error> {
         if let Duration::Concat(dur1, dur2) = self {
-            return dur2.after(dur1.after(date));
+            return dur2.after(dur1.after(date, options)?, options);
+        }
+
+        if let Duration::Vague(quantity, unit) = self {
+            return Duration::Specific(options.count(*quantity), *unit).after(date, options);
         }
 
         if self.convertable() {
-            date + self.to_chrono()
+            self.to_chrono()
+                .and_then(|duration| date.checked_add_signed(duration))
+                .ok_or(crate::Error::invalid_date(
+                    "Date out of representable date range.",
+                ))
         } else {
-            match self.unit() {
-                Unit::Month => date
-                    .checked_add_months(chrono::Months::new(self.num()))
-                    .expect("Date out of representable date range."),
-                Unit::Year => date.with_year(date.year() + self.num() as i32).unwrap(),
+            Ok(match self.unit() {
+                Unit::Month => add_months(date, i64::from(self.num()), options)?,
+                // Anniversaries of leap days fall on the 28th of February,
+                // unless the options say otherwise
+                Unit::Year => add_months(date, i64::from(self.num()) * 12, options)?,
+                // Weekends and holidays are skipped, e.g. 1 business day
+                // after a friday is the next monday
+                Unit::BusinessDay => add_business_days(date, self.num(), true, options)?,
                 _ => unreachable!(),
-            }
+            })
         }
     }
 
-    fn before(&self, date: ChronoDateTime) -> ChronoDateTime {
+    /// The datetime a duration before the given one
+    pub fn before(
+        &self,
+        date: ChronoDateTime,
+        options: &ParseOptions,
+    ) -> Result<ChronoDateTime, crate::Error> {
         if let Duration::Concat(dur1, dur2) = self {
-            return dur2.before(dur1.before(date));
+            return dur2.before(dur1.before(date, options)?, options);
+        }
+
+        if let Duration::Vague(quantity, unit) = self {
+            return Duration::Specific(options.count(*quantity), *unit).before(date, options);
         }
 
         if self.convertable() {
-            date - self.to_chrono()
+            self.to_chrono()
+                .and_then(|duration| date.checked_sub_signed(duration))
+                .ok_or(crate::Error::invalid_date(
+                    "Date out of representable date range.",
+                ))
         } else {
-            match self.unit() {
-                Unit::Month => date
-                    .checked_sub_months(chrono::Months::new(self.num()))
-                    .expect("Date out of representable date range."),
-                Unit::Year => date.with_year(date.year() - self.num() as i32).unwrap(),
+            Ok(match self.unit() {
+                Unit::Month => add_months(date, -i64::from(self.num()), options)?,
+                Unit::Year => add_months(date, -i64::from(self.num()) * 12, options)?,
+                Unit::BusinessDay => add_business_days(date, self.num(), false, options)?,
                 _ => unreachable!(),
+            })
+        }
+    }
+}
+
+/// The last instant of a day, outside of a leap second
+const END_OF_DAY: ChronoTime = match ChronoTime::from_hms_nano_opt(23, 59, 59, 999_999_999) {
+    Some(time) => time,
+    None => unreachable!(),
+};
+
+/// The number of days from a day on the weekday `from` to the `n`th day on
+/// the weekday `to` strictly after it, counting a zero `n` as the first
+fn weekdays_apart(from: chrono::Weekday, to: chrono::Weekday, n: u32) -> u64 {
+    let first = (to.num_days_from_monday() + 6 - from.num_days_from_monday()) % 7 + 1;
+    u64::from(first) + 7 * u64::from(n.saturating_sub(1))
+}
+
+/// Move a datetime by `n` business days, forward or backward, skipping
+/// weekends and holidays
+fn add_business_days(
+    date: ChronoDateTime,
+    n: u32,
+    forward: bool,
+    options: &ParseOptions,
+) -> Result<ChronoDateTime, crate::Error> {
+    let out_of_range = || crate::Error::invalid_date("Date out of representable date range.");
+    let step = |date: ChronoDateTime, days: u64| {
+        if forward {
+            date.checked_add_days(chrono::Days::new(days))
+        } else {
+            date.checked_sub_days(chrono::Days::new(days))
+        }
+        .ok_or_else(out_of_range)
+    };
+
+    // Every 7 days hold 5 weekdays, less the holidays among them. At least
+    // one day is left to walk so a start on a weekend lands on a business day
+    let mut date = date;
+    let mut remaining = n;
+    while remaining > 5 {
+        let weeks = (remaining - 1) / 5;
+        let next = step(date, u64::from(weeks) * 7)?;
+        let span = if forward {
+            (Bound::Excluded(date.date()), Bound::Included(next.date()))
+        } else {
+            (Bound::Included(next.date()), Bound::Excluded(date.date()))
+        };
+        let holidays = options
+            .holidays
+            .range(span)
+            .filter(|day| !matches!(day.weekday(), chrono::Weekday::Sat | chrono::Weekday::Sun))
+            .count();
+
+        remaining = remaining - weeks * 5 + holidays as u32;
+        date = next;
+    }
+
+    for _ in 0..remaining {
+        date = step(date, 1)?;
+        while !options.is_business_day(date.date()) {
+            date = step(date, 1)?;
+        }
+    }
+    Ok(date)
+}
+
+/// Move a datetime by whole months, resolving a day past the end of the
+/// target month, e.g. in "3 months before May 31", by the options' policy
+fn add_months(
+    date: ChronoDateTime,
+    months: i64,
+    options: &ParseOptions,
+) -> Result<ChronoDateTime, crate::Error> {
+    let out_of_range = || crate::Error::invalid_date("Date out of representable date range.");
+    let shift = |date: ChronoDateTime, months: i64| {
+        let n = chrono::Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+        if months < 0 {
+            date.checked_sub_months(n)
+        } else {
+            date.checked_add_months(n)
+        }
+    };
+
+    let first = shift(date.with_day(1).unwrap(), months).ok_or_else(out_of_range)?;
+    let next = shift(first, 1).ok_or_else(out_of_range)?;
+    let days = (next - first).num_days() as u32;
+
+    let day = date.day();
+    if day <= days {
+        return Ok(first.with_day(day).unwrap());
+    }
+
+    match options.month_overflow {
+        MonthOverflow::Clamp => Ok(first.with_day(days).unwrap()),
+        MonthOverflow::Roll => Ok(first + ChronoDuration::days(i64::from(day - 1))),
+        MonthOverflow::Error => Err(crate::Error::invalid_date(format!(
+            "{} has no day {day}",
+            first.format("%B %Y")
+        ))),
+    }
+}
+
+/// The units of a fixed number of minutes, from largest to smallest
+const MINUTE_UNITS: &[(u64, Unit)] = &[
+    (7 * 24 * 60, Unit::Week),
+    (24 * 60, Unit::Day),
+    (60, Unit::Hour),
+    (1, Unit::Minute),
+];
+
+impl fmt::Display for Duration {
+    /// Writes the parts of the duration as a list, e.g. "1 year, 2 months
+    /// and 3 days"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        self.for_each_part(&mut |num, unit| parts.push((num, unit)));
+
+        for (i, (num, unit)) in parts.iter().enumerate() {
+            if i > 0 {
+                let separator = if i + 1 == parts.len() { " and " } else { ", " };
+                f.write_str(separator)?;
+            }
+
+            write!(f, "{num} {unit}")?;
+            if *num != 1 {
+                f.write_str("s")?;
             }
         }
+
+        Ok(())
     }
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Unit {
     Day,
+    /// A day which is neither on a weekend nor a holiday
+    BusinessDay,
     Week,
     Hour,
     Minute,
@@ -634,8 +3480,11 @@ pub enum Unit {
 
 impl Unit {
     fn parse(l: &[Lexeme]) -> Option<(Self, usize)> {
-        match l.get(0) {
+        stats::attempt();
+        memo::attempt(Rule::Unit, l);
+        match l.first() {
             Some(Lexeme::Day) => Some((Unit::Day, 1)),
+            Some(Lexeme::BusinessDay) => Some((Unit::BusinessDay, 1)),
             Some(Lexeme::Week) => Some((Unit::Week, 1)),
             Some(Lexeme::Month) => Some((Unit::Month, 1)),
             Some(Lexeme::Year) => Some((Unit::Year, 1)),
@@ -646,11 +3495,26 @@ impl Unit {
     }
 }
 
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Unit::Day => "day",
+            Unit::BusinessDay => "business day",
+            Unit::Week => "week",
+            Unit::Hour => "hour",
+            Unit::Minute => "minute",
+            Unit::Month => "month",
+            Unit::Year => "year",
+        })
+    }
+}
+
 struct Ones;
 
 impl Ones {
     fn parse(l: &[Lexeme]) -> Option<(u32, usize)> {
-        let mut res = match l.get(0) {
+        stats::attempt();
+        let mut res = match l.first() {
             Some(Lexeme::One) => Some(1),
             Some(Lexeme::Two) => Some(2),
             Some(Lexeme::Three) => Some(3),
@@ -664,7 +3528,7 @@ impl Ones {
         };
 
         if res.is_none() {
-            if let Some(Lexeme::Num(n)) = l.get(0) {
+            if let Some(Lexeme::Num(n)) = l.first() {
                 if *n < 10 {
                     res = Some(*n);
                 }
@@ -678,7 +3542,8 @@ impl Ones {
 struct Teens;
 impl Teens {
     fn parse(l: &[Lexeme]) -> Option<(u32, usize)> {
-        let mut res = match l.get(0) {
+        stats::attempt();
+        let mut res = match l.first() {
             Some(Lexeme::Ten) => Some((10, 1)),
             Some(Lexeme::Eleven) => Some((11, 1)),
             Some(Lexeme::Twelve) => Some((12, 1)),
@@ -693,37 +3558,116 @@ impl Teens {
         };
 
         if res.is_none() {
-            if let Some(Lexeme::Num(n)) = l.get(0) {
+            if let Some(Lexeme::Num(n)) = l.first() {
                 if *n >= 10 && *n <= 19 {
                     res = Some((*n, 1));
                 }
             }
         }
 
-        res
-    }
-}
+        res
+    }
+}
+
+struct Tens;
+impl Tens {
+    fn parse(l: &[Lexeme]) -> Option<(u32, usize)> {
+        stats::attempt();
+        match l.first() {
+            Some(Lexeme::Twenty) => Some((20, 1)),
+            Some(Lexeme::Thirty) => Some((30, 1)),
+            Some(Lexeme::Fourty) => Some((40, 1)),
+            Some(Lexeme::Fifty) => Some((50, 1)),
+            Some(Lexeme::Sixty) => Some((60, 1)),
+            Some(Lexeme::Seventy) => Some((70, 1)),
+            Some(Lexeme::Eighty) => Some((80, 1)),
+            Some(Lexeme::Ninety) => Some((90, 1)),
+            _ => None,
+        }
+    }
+}
+
+struct OrdinalOnes;
+impl OrdinalOnes {
+    fn parse(l: &[Lexeme]) -> Option<(u32, usize)> {
+        stats::attempt();
+        match l.first() {
+            Some(Lexeme::First) => Some((1, 1)),
+            Some(Lexeme::Second) => Some((2, 1)),
+            Some(Lexeme::Third) => Some((3, 1)),
+            Some(Lexeme::Fourth) => Some((4, 1)),
+            Some(Lexeme::Fifth) => Some((5, 1)),
+            Some(Lexeme::Sixth) => Some((6, 1)),
+            Some(Lexeme::Seventh) => Some((7, 1)),
+            Some(Lexeme::Eighth) => Some((8, 1)),
+            Some(Lexeme::Ninth) => Some((9, 1)),
+            _ => None,
+        }
+    }
+}
+
+struct OrdinalTeens;
+impl OrdinalTeens {
+    fn parse(l: &[Lexeme]) -> Option<(u32, usize)> {
+        stats::attempt();
+        match l.first() {
+            Some(Lexeme::Tenth) => Some((10, 1)),
+            Some(Lexeme::Eleventh) => Some((11, 1)),
+            Some(Lexeme::Twelfth) => Some((12, 1)),
+            Some(Lexeme::Thirteenth) => Some((13, 1)),
+            Some(Lexeme::Fourteenth) => Some((14, 1)),
+            Some(Lexeme::Fifteenth) => Some((15, 1)),
+            Some(Lexeme::Sixteenth) => Some((16, 1)),
+            Some(Lexeme::Seventeenth) => Some((17, 1)),
+            Some(Lexeme::Eighteenth) => Some((18, 1)),
+            Some(Lexeme::Nineteenth) => Some((19, 1)),
+            _ => None,
+        }
+    }
+}
+
+/// An ordinal number word, e.g. "third" or "twenty-first"
+struct Ordinal;
+impl Ordinal {
+    fn parse(l: &[Lexeme]) -> Option<(u32, usize)> {
+        stats::attempt();
+        let mut tokens = 0;
+
+        if let Some((tens, t)) = Tens::parse(&l[tokens..]) {
+            tokens += t;
+
+            if Some(&Lexeme::Dash) == l.get(tokens) {
+                tokens += 1;
+            }
+
+            if let Some((ones, t)) = OrdinalOnes::parse(&l[tokens..]) {
+                tokens += t;
+                return Some((tens + ones, tokens));
+            }
+        }
+
+        stats::backtrack(tokens);
+        tokens = 0;
 
-struct Tens;
-impl Tens {
-    fn parse(l: &[Lexeme]) -> Option<(u32, usize)> {
-        match l.get(0) {
-            Some(Lexeme::Twenty) => Some((20, 1)),
-            Some(Lexeme::Thirty) => Some((30, 1)),
-            Some(Lexeme::Fourty) => Some((40, 1)),
-            Some(Lexeme::Fifty) => Some((50, 1)),
-            Some(Lexeme::Sixty) => Some((60, 1)),
-            Some(Lexeme::Seventy) => Some((70, 1)),
-            Some(Lexeme::Eighty) => Some((80, 1)),
-            Some(Lexeme::Ninety) => Some((90, 1)),
-            _ => None,
+        match l.get(tokens) {
+            Some(Lexeme::Twentieth) => return Some((20, 1)),
+            Some(Lexeme::Thirtieth) => return Some((30, 1)),
+            _ => {}
+        }
+
+        if let Some((teens, t)) = OrdinalTeens::parse(&l[tokens..]) {
+            tokens += t;
+            return Some((teens, tokens));
         }
+
+        OrdinalOnes::parse(&l[tokens..])
     }
 }
 
 struct NumDouble;
 impl NumDouble {
     fn parse(l: &[Lexeme]) -> Option<(u32, usize)> {
+        stats::attempt();
         let mut tokens = 0;
 
         if let Some((tens, t)) = Tens::parse(&l[tokens..]) {
@@ -738,19 +3682,25 @@ impl NumDouble {
             return Some((tens + ones, tokens));
         }
 
+        stats::backtrack(tokens);
         tokens = 0;
+
         if let Some((teens, t)) = Teens::parse(&l[tokens..]) {
             tokens += t;
             return Some((teens, tokens));
         }
 
+        stats::backtrack(tokens);
         tokens = 0;
+
         if let Some((ones, t)) = Ones::parse(&l[tokens..]) {
             tokens += t;
             return Some((ones, tokens));
         }
 
+        stats::backtrack(tokens);
         tokens = 0;
+
         if let Some(Lexeme::Num(n)) = l.get(tokens) {
             tokens += 1;
             if *n < 100 && *n > 19 {
@@ -765,6 +3715,7 @@ impl NumDouble {
 struct NumTriple;
 impl NumTriple {
     fn parse(l: &[Lexeme]) -> Option<(u32, usize)> {
+        stats::attempt();
         let mut tokens = 0;
 
         if let Some((ones, t)) = Ones::parse(&l[tokens..]) {
@@ -789,7 +3740,9 @@ impl NumTriple {
             }
         }
 
+        stats::backtrack(tokens);
         tokens = 0;
+
         if Some(&Lexeme::Hundred) == l.get(tokens) {
             tokens += 1;
 
@@ -807,13 +3760,17 @@ impl NumTriple {
             }
         }
 
+        stats::backtrack(tokens);
         tokens = 0;
+
         if let Some((num_double, t)) = NumDouble::parse(&l[tokens..]) {
             tokens += t;
             return Some((num_double, tokens));
         }
 
+        stats::backtrack(tokens);
         tokens = 0;
+
         if let Some(&Lexeme::Num(n)) = l.get(tokens) {
             tokens += 1;
             if n > 99 && n < 1000 {
@@ -828,7 +3785,8 @@ impl NumTriple {
 struct NumTripleUnit;
 impl NumTripleUnit {
     fn parse(l: &[Lexeme]) -> Option<(u32, usize)> {
-        match l.get(0) {
+        stats::attempt();
+        match l.first() {
             Some(Lexeme::Thousand) => Some((1000, 1)),
             Some(Lexeme::Million) => Some((1000000, 1)),
             Some(Lexeme::Billion) => Some((1000000000, 1)),
@@ -840,6 +3798,11 @@ impl NumTripleUnit {
 struct Num;
 impl Num {
     fn parse(l: &[Lexeme]) -> Option<(u32, usize)> {
+        memo::memoized(Rule::Num, l, Self::parse_uncached)
+    }
+
+    fn parse_uncached(l: &[Lexeme]) -> Option<(u32, usize)> {
+        stats::attempt();
         let mut tokens = 0;
 
         // <num_triple>
@@ -865,7 +3828,9 @@ impl Num {
             }
         }
 
+        stats::backtrack(tokens);
         tokens = 0;
+
         // <num_triple_unit>
         if let Some((unit, t)) = NumTripleUnit::parse(&l[tokens..]) {
             tokens += t;
@@ -885,13 +3850,16 @@ impl Num {
         }
 
         // <num_triple>
+        stats::backtrack(tokens);
         tokens = 0;
         if let Some((num, t)) = NumTriple::parse(&l[tokens..]) {
             tokens += t;
             return Some((num, tokens));
         }
 
+        stats::backtrack(tokens);
         tokens = 0;
+
         // NUM
         if let Some(&Lexeme::Num(n)) = l.get(tokens) {
             tokens += 1;
@@ -906,6 +3874,7 @@ impl Num {
 
 #[cfg(test)]
 mod tests {
+    use chrono::Local;
     use chrono::{NaiveDateTime as ChronoDateTime, TimeZone};
     use test_case::test_case;
 
@@ -985,7 +3954,10 @@ mod tests {
         ];
         let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
         let date = date
-            .to_chrono(Local::now().naive_local().time(), None)
+            .to_chrono(
+                Local::now().naive_local().time(),
+                Local::now().naive_local(),
+            )
             .unwrap();
 
         assert_eq!(t, 4);
@@ -1008,7 +3980,10 @@ mod tests {
         ];
         let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
         let date = date
-            .to_chrono(Local::now().naive_local().time(), None)
+            .to_chrono(
+                Local::now().naive_local().time(),
+                Local::now().naive_local(),
+            )
             .unwrap();
 
         assert_eq!(t, 4);
@@ -1019,6 +3994,53 @@ mod tests {
         assert_eq!(date.minute(), 0);
     }
 
+    #[test]
+    fn test_twelve_hour_clock() {
+        let options = crate::ParseOptions::default();
+        let time = |time: Time| time.to_chrono(ChronoTime::MIN, &options);
+
+        for (am_pm, expected) in [
+            (Time::HourMinAM(12, 0), "00:00:00"),
+            (Time::HourMinAM(12, 30), "00:30:00"),
+            (Time::HourMinAM(1, 0), "01:00:00"),
+            (Time::HourMinAM(11, 59), "11:59:00"),
+            (Time::HourMinPM(12, 0), "12:00:00"),
+            (Time::HourMinPM(12, 30), "12:30:00"),
+            (Time::HourMinPM(1, 0), "13:00:00"),
+            (Time::HourMinPM(11, 59), "23:59:00"),
+        ] {
+            assert_eq!(
+                time(am_pm.clone()).unwrap().to_string(),
+                expected,
+                "{am_pm:?}"
+            );
+        }
+
+        for am_pm in [
+            Time::HourMinAM(0, 0),
+            Time::HourMinPM(0, 30),
+            Time::HourMinAM(13, 0),
+            Time::HourMinPM(13, 0),
+            Time::HourMinPM(12, 60),
+        ] {
+            assert!(time(am_pm.clone()).is_err(), "{am_pm:?}");
+        }
+
+        let now = ChronoDate::from_ymd_opt(2024, 6, 12)
+            .unwrap()
+            .and_time(ChronoTime::MIN);
+        for (input, expected) in [
+            ("today at 12:30 pm", Some("2024-06-12 12:30:00")),
+            ("tomorrow at 12am", Some("2024-06-13 00:00:00")),
+            ("today at 13 pm", None),
+        ] {
+            let lexemes = Lexeme::lex_line(input).unwrap();
+            let (date, _) = DateTime::parse(&lexemes).unwrap();
+            let date = date.to_chrono(ChronoTime::MIN, now).ok();
+            assert_eq!(date.map(|d| d.to_string()).as_deref(), expected, "{input}");
+        }
+    }
+
     #[test]
     fn test_simple_date_time() {
         use chrono::Timelike;
@@ -1034,7 +4056,10 @@ mod tests {
         ];
         let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
         let date = date
-            .to_chrono(Local::now().naive_local().time(), None)
+            .to_chrono(
+                Local::now().naive_local().time(),
+                Local::now().naive_local(),
+            )
             .unwrap();
 
         assert_eq!(t, 7);
@@ -1045,9 +4070,9 @@ mod tests {
         assert_eq!(date.minute(), 27);
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_complex_relative_datetime(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_complex_relative_datetime(now: ChronoDateTime) {
         let lexemes = vec![
             Lexeme::A,
             Lexeme::Week,
@@ -1066,7 +4091,7 @@ mod tests {
         ];
 
         use chrono::naive::Days;
-        let today = now.map_or(Local::now().naive_local().date(), |now| now.date());
+        let today = now.date();
         let real_date = today + Days::new(7 - 2 + 1 + 1);
 
         let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
@@ -1080,9 +4105,9 @@ mod tests {
         assert_eq!(date.day(), real_date.day());
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_datetime_now(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_datetime_now(now: ChronoDateTime) {
         use chrono::Timelike;
 
         let lexemes = vec![Lexeme::Now];
@@ -1091,7 +4116,6 @@ mod tests {
             .to_chrono(Local::now().naive_local().time(), now)
             .unwrap();
 
-        let now = now.unwrap_or(Local::now().naive_local());
         assert_eq!(t, 1);
         assert_eq!(date.year(), now.year());
         assert_eq!(date.month(), now.month());
@@ -1112,22 +4136,393 @@ mod tests {
         assert!(DateTime::parse(lexemes.as_slice()).is_none());
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_datetime_ago(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_datetime_ago(now: ChronoDateTime) {
         let lexemes = vec![Lexeme::A, Lexeme::Day, Lexeme::Ago];
         let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
         let date = date
             .to_chrono(Local::now().naive_local().time(), now)
             .unwrap();
 
-        let today = now.map_or(Local::now().naive_local().date(), |now| now.date());
+        let today = now.date();
         assert_eq!(t, 3);
         assert_eq!(date.year(), today.year());
         assert_eq!(date.month(), today.month());
         assert_eq!(date.day(), today.day() - 1);
     }
 
+    #[test]
+    fn test_years_ago_today() {
+        use chrono::Timelike;
+
+        let now = ChronoDate::from_ymd_opt(2024, 3, 10)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap();
+        let lexemes = vec![Lexeme::Num(50), Lexeme::Year, Lexeme::Ago, Lexeme::Today];
+        let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
+        let date = date.to_chrono(now.time(), now).unwrap();
+
+        assert_eq!(t, 4);
+        assert_eq!(date.year(), 1974);
+        assert_eq!(date.month(), 3);
+        assert_eq!(date.day(), 10);
+        assert_eq!(date.hour(), 9);
+        assert_eq!(date.minute(), 30);
+    }
+
+    #[test]
+    fn test_year_from_today_at_midnight() {
+        use chrono::Timelike;
+
+        let now = ChronoDate::from_ymd_opt(2024, 2, 29)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap();
+        let lexemes = vec![
+            Lexeme::One,
+            Lexeme::Year,
+            Lexeme::From,
+            Lexeme::Today,
+            Lexeme::At,
+            Lexeme::Midnight,
+        ];
+        let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
+        let date = date.to_chrono(now.time(), now).unwrap();
+
+        assert_eq!(t, 6);
+        assert_eq!(date.year(), 2025);
+        assert_eq!(date.month(), 2);
+        assert_eq!(date.day(), 28);
+        assert_eq!(date.hour(), 0);
+        assert_eq!(date.minute(), 0);
+    }
+
+    #[test]
+    fn test_dangling_at() {
+        assert!(Time::parse_at(&[Lexeme::At]).is_none());
+        assert!(Time::parse_at(&[Lexeme::At, Lexeme::Today]).is_none());
+    }
+
+    #[test]
+    fn test_last_counted_months() {
+        let now = ChronoDate::from_ymd_opt(2024, 5, 31)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap();
+        let lexemes = vec![Lexeme::Last, Lexeme::Num(3), Lexeme::Month];
+        let (range, t) = Range::parse(lexemes.as_slice()).unwrap();
+        let (start, end) = range
+            .to_chrono(now.time(), now, &ParseOptions::default())
+            .unwrap();
+
+        assert_eq!(t, 3);
+        assert_eq!(
+            start,
+            now.checked_sub_months(chrono::Months::new(3)).unwrap()
+        );
+        assert_eq!(end, now);
+    }
+
+    #[test]
+    fn test_next_counted_weeks() {
+        let now = ChronoDate::from_ymd_opt(2024, 5, 31)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap();
+        let lexemes = vec![Lexeme::Next, Lexeme::Two, Lexeme::Week];
+        let (range, t) = Range::parse(lexemes.as_slice()).unwrap();
+        let (start, end) = range
+            .to_chrono(now.time(), now, &ParseOptions::default())
+            .unwrap();
+
+        assert_eq!(t, 3);
+        assert_eq!(start, now);
+        assert_eq!(end, now + ChronoDuration::weeks(2));
+    }
+
+    #[test]
+    fn test_uncounted_period_is_not_range() {
+        assert!(Range::parse(&[Lexeme::Last, Lexeme::Month]).is_none());
+        assert!(Range::parse(&[Lexeme::Last, Lexeme::A, Lexeme::Month]).is_none());
+        assert!(Range::parse(&[Lexeme::This, Lexeme::Num(3), Lexeme::Day]).is_none());
+    }
+
+    #[test]
+    fn test_three_digit_year() {
+        use crate::ThreeDigitYears;
+
+        let lexemes = vec![
+            Lexeme::Num(5),
+            Lexeme::Slash,
+            Lexeme::Num(12),
+            Lexeme::Slash,
+            Lexeme::Num(225),
+        ];
+        let (date, _) = DateTime::parse(lexemes.as_slice()).unwrap();
+        let default = Local::now().naive_local().time();
+
+        let literal = date.to_chrono(default, Local::now().naive_local()).unwrap();
+        assert_eq!(literal.year(), 225);
+
+        let options = ParseOptions::new().three_digit_years(ThreeDigitYears::Reject);
+        assert!(date
+            .to_chrono_with(default, Local::now().naive_local(), &options)
+            .is_err());
+
+        let options = ParseOptions::new().three_digit_years(ThreeDigitYears::Century(20));
+        let century = date
+            .to_chrono_with(default, Local::now().naive_local(), &options)
+            .unwrap();
+        assert_eq!(century.year(), 2025);
+
+        let options =
+            ParseOptions::new().three_digit_years(ThreeDigitYears::Map(|year| Some(year * 10)));
+        let mapped = date
+            .to_chrono_with(default, Local::now().naive_local(), &options)
+            .unwrap();
+        assert_eq!(mapped.year(), 2250);
+    }
+
+    #[test]
+    fn test_ordinal_date_with_offsets() {
+        use chrono::Timelike;
+
+        let lexemes = vec![
+            Lexeme::Num(2024),
+            Lexeme::Dash,
+            Lexeme::DayOfYear(150),
+            Lexeme::Plus,
+            Lexeme::Num(12),
+            Lexeme::Hour,
+            Lexeme::Dash,
+            Lexeme::Num(30),
+            Lexeme::Minute,
+        ];
+        let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
+        let date = date
+            .to_chrono(ChronoTime::MIN, Local::now().naive_local())
+            .unwrap();
+
+        assert_eq!(t, 9);
+        assert_eq!(date.year(), 2024);
+        assert_eq!(date.month(), 5);
+        assert_eq!(date.day(), 29);
+        assert_eq!(date.hour(), 11);
+        assert_eq!(date.minute(), 30);
+    }
+
+    #[test]
+    fn test_ordinal_date_out_of_range() {
+        let lexemes = vec![Lexeme::Num(2023), Lexeme::Dash, Lexeme::DayOfYear(366)];
+        let (date, _) = DateTime::parse(lexemes.as_slice()).unwrap();
+        assert!(date
+            .to_chrono(ChronoTime::MIN, Local::now().naive_local())
+            .is_err());
+    }
+
+    #[test]
+    fn test_year_out_of_range() {
+        let today = ChronoDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let options = ParseOptions::new();
+
+        for date in [
+            Date::Year(u32::MAX),
+            Date::YearDay(u32::MAX, 1),
+            Date::YearMonth(u32::MAX, 1),
+            Date::MonthYear(Month::June, u32::MAX),
+        ] {
+            assert!(
+                matches!(
+                    date.to_chrono(today, &options),
+                    Err(crate::Error::InvalidDate(_))
+                ),
+                "{date:?}"
+            );
+        }
+    }
+
+    #[test_case(None, DateSeparator::Slash, (5, 12, Some(2023)), (5, 12, Some(2023)); "slash defaults to mdy")]
+    #[test_case(None, DateSeparator::Dot, (5, 12, Some(2023)), (12, 5, Some(2023)); "dot defaults to dmy")]
+    #[test_case(Some(DateOrder::DayMonthYear), DateSeparator::Slash, (5, 12, Some(2023)), (12, 5, Some(2023)); "dmy slash")]
+    #[test_case(Some(DateOrder::DayMonthYear), DateSeparator::Dash, (5, 12, Some(2023)), (12, 5, Some(2023)); "dmy dash")]
+    #[test_case(Some(DateOrder::MonthDayYear), DateSeparator::Dot, (5, 12, Some(2023)), (5, 12, Some(2023)); "mdy dot")]
+    #[test_case(Some(DateOrder::YearMonthDay), DateSeparator::Slash, (24, 6, Some(15)), (6, 15, Some(24)); "ymd slash")]
+    #[test_case(None, DateSeparator::Slash, (2024, 6, Some(15)), (6, 15, Some(2024)); "four digit year slash")]
+    fn test_date_order(
+        order: Option<DateOrder>,
+        sep: DateSeparator,
+        (first, second, third): (u32, u32, Option<u32>),
+        expected: (u32, u32, Option<u32>),
+    ) {
+        let mut options = ParseOptions::new();
+        if let Some(order) = order {
+            options = options.date_order(order);
+        }
+
+        assert_eq!(sep.resolve(first, second, third, &options), expected);
+    }
+
+    #[test]
+    fn test_four_digit_year_first() {
+        let options = ParseOptions::new().date_order(DateOrder::DayMonthYear);
+        let lexemes = vec![
+            Lexeme::Num(2023),
+            Lexeme::Dash,
+            Lexeme::Num(5),
+            Lexeme::Dash,
+            Lexeme::Num(12),
+        ];
+        let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
+        let date = date
+            .to_chrono_with(ChronoTime::MIN, Local::now().naive_local(), &options)
+            .unwrap();
+
+        assert_eq!(t, 5);
+        assert_eq!(date.year(), 2023);
+        assert_eq!(date.month(), 5);
+        assert_eq!(date.day(), 12);
+    }
+
+    #[test]
+    fn test_month_day_without_year() {
+        let lexemes = vec![Lexeme::Num(5), Lexeme::Slash, Lexeme::Num(12)];
+        let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
+        let date = date
+            .to_chrono(ChronoTime::MIN, Local::now().naive_local())
+            .unwrap();
+
+        assert_eq!(t, 3);
+        assert_eq!(date.month(), 5);
+        assert_eq!(date.day(), 12);
+    }
+
+    #[test]
+    fn test_after_the_epoch() {
+        let lexemes = vec![
+            Lexeme::Num(5),
+            Lexeme::Minute,
+            Lexeme::After,
+            Lexeme::The,
+            Lexeme::Epoch,
+        ];
+        let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
+        let date = date
+            .to_chrono(ChronoTime::MIN, Local::now().naive_local())
+            .unwrap();
+
+        assert_eq!(t, 5);
+        assert_eq!(date.to_string(), "1970-01-01 00:05:00");
+    }
+
+    #[test]
+    fn test_iso_month_day() {
+        let options = ParseOptions::new().date_order(DateOrder::DayMonthYear);
+        let lexemes = vec![
+            Lexeme::Dash,
+            Lexeme::Dash,
+            Lexeme::Num(6),
+            Lexeme::Dash,
+            Lexeme::Num(15),
+        ];
+        let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
+        let date = date
+            .to_chrono_with(ChronoTime::MIN, Local::now().naive_local(), &options)
+            .unwrap();
+
+        assert_eq!(t, 5);
+        assert_eq!(date.month(), 6);
+        assert_eq!(date.day(), 15);
+    }
+
+    #[test]
+    fn test_iso_year_month() {
+        let lexemes = vec![Lexeme::Num(2024), Lexeme::Dash, Lexeme::Num(6)];
+        let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
+        let date = date
+            .to_chrono(ChronoTime::MIN, Local::now().naive_local())
+            .unwrap();
+
+        assert_eq!(t, 3);
+        assert_eq!(date.to_string(), "2024-06-01 00:00:00");
+
+        let lexemes = vec![Lexeme::Num(2024), Lexeme::Dash, Lexeme::Num(13)];
+        let (date, _) = DateTime::parse(lexemes.as_slice()).unwrap();
+        assert!(date
+            .to_chrono(ChronoTime::MIN, Local::now().naive_local())
+            .is_err());
+    }
+
+    #[test]
+    fn test_iso_interval() {
+        let lexemes = vec![
+            Lexeme::Num(2024),
+            Lexeme::Dash,
+            Lexeme::Num(1),
+            Lexeme::Dash,
+            Lexeme::Num(1),
+            Lexeme::Slash,
+            Lexeme::Num(2024),
+            Lexeme::Dash,
+            Lexeme::Num(2),
+            Lexeme::Dash,
+            Lexeme::Num(1),
+        ];
+        let (range, t) = Range::parse(lexemes.as_slice()).unwrap();
+        let (start, end) = range
+            .to_chrono(
+                ChronoTime::MIN,
+                Local::now().naive_local(),
+                &ParseOptions::default(),
+            )
+            .unwrap();
+
+        assert_eq!(t, 11);
+        assert_eq!(start.to_string(), "2024-01-01 00:00:00");
+        assert_eq!(end.to_string(), "2024-02-01 00:00:00");
+    }
+
+    #[test]
+    fn test_iso_interval_with_duration() {
+        let start = vec![
+            Lexeme::Num(2024),
+            Lexeme::Dash,
+            Lexeme::Num(1),
+            Lexeme::Dash,
+            Lexeme::Num(31),
+        ];
+        let dur = vec![Lexeme::Num(1), Lexeme::Month, Lexeme::Num(2), Lexeme::Day];
+
+        let after = [start.as_slice(), &[Lexeme::Slash], dur.as_slice()].concat();
+        let (range, t) = Range::parse(after.as_slice()).unwrap();
+        let (from, to) = range
+            .to_chrono(
+                ChronoTime::MIN,
+                Local::now().naive_local(),
+                &ParseOptions::default(),
+            )
+            .unwrap();
+
+        assert_eq!(t, 10);
+        assert_eq!(from.to_string(), "2024-01-31 00:00:00");
+        assert_eq!(to.to_string(), "2024-03-02 00:00:00");
+
+        let before = [dur.as_slice(), &[Lexeme::Slash], start.as_slice()].concat();
+        let (range, t) = Range::parse(before.as_slice()).unwrap();
+        let (from, to) = range
+            .to_chrono(
+                ChronoTime::MIN,
+                Local::now().naive_local(),
+                &ParseOptions::default(),
+            )
+            .unwrap();
+
+        assert_eq!(t, 10);
+        assert_eq!(from.to_string(), "2023-12-29 00:00:00");
+        assert_eq!(to.to_string(), "2024-01-31 00:00:00");
+    }
+
     #[test]
     fn test_teens() {
         assert_eq!((10, 1), Teens::parse(&[Lexeme::Ten]).unwrap());
@@ -1142,25 +4537,25 @@ mod tests {
         assert_eq!((19, 1), Teens::parse(&[Lexeme::Nineteen]).unwrap());
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_article_before(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_article_before(now: ChronoDateTime) {
         let (date, t) =
             DateTime::parse(&[Lexeme::A, Lexeme::Day, Lexeme::Before, Lexeme::Today]).unwrap();
         let date = date
             .to_chrono(Local::now().naive_local().time(), now)
             .unwrap();
 
-        let today = now.map_or(Local::now().naive_local().date(), |now| now.date());
+        let today = now.date();
         assert_eq!(t, 4);
         assert_eq!(date.year(), today.year());
         assert_eq!(date.month(), today.month());
         assert_eq!(date.day(), today.day() - 1);
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_after_december(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_after_december(now: ChronoDateTime) {
         let l = vec![
             Lexeme::A,
             Lexeme::Month,
@@ -1169,7 +4564,7 @@ mod tests {
             Lexeme::Num(5),
         ];
 
-        let today = now.map_or(Local::now().naive_local().date(), |now| now.date());
+        let today = now.date();
         let (date, t) = DateTime::parse(l.as_slice()).unwrap();
         let date = date
             .to_chrono(Local::now().naive_local().time(), now)
@@ -1181,9 +4576,9 @@ mod tests {
         assert_eq!(date.day(), 5);
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_month_before_january(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_month_before_january(now: ChronoDateTime) {
         let l = vec![
             Lexeme::A,
             Lexeme::Month,
@@ -1192,7 +4587,7 @@ mod tests {
             Lexeme::Num(5),
         ];
 
-        let today = now.map_or(Local::now().naive_local().date(), |now| now.date());
+        let today = now.date();
         let (date, t) = DateTime::parse(l.as_slice()).unwrap();
         let date = date
             .to_chrono(Local::now().naive_local().time(), now)
@@ -1204,9 +4599,9 @@ mod tests {
         assert_eq!(date.day(), 5);
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_week_after(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_week_after(now: ChronoDateTime) {
         let l = vec![
             Lexeme::A,
             Lexeme::Week,
@@ -1215,7 +4610,7 @@ mod tests {
             Lexeme::Num(5),
         ];
 
-        let today = now.map_or(Local::now().naive_local().date(), |now| now.date());
+        let today = now.date();
         let (date, t) = DateTime::parse(l.as_slice()).unwrap();
         let date = date
             .to_chrono(Local::now().naive_local().time(), now)
@@ -1227,9 +4622,9 @@ mod tests {
         assert_eq!(date.day(), 12);
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_month_after(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_month_after(now: ChronoDateTime) {
         let l = vec![
             Lexeme::A,
             Lexeme::Month,
@@ -1238,7 +4633,7 @@ mod tests {
             Lexeme::Num(5),
         ];
 
-        let today = now.map_or(Local::now().naive_local().date(), |now| now.date());
+        let today = now.date();
         let (date, t) = DateTime::parse(l.as_slice()).unwrap();
         let date = date
             .to_chrono(Local::now().naive_local().time(), now)
@@ -1250,9 +4645,9 @@ mod tests {
         assert_eq!(date.day(), 5);
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_year_after(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_year_after(now: ChronoDateTime) {
         let l = vec![
             Lexeme::A,
             Lexeme::Year,
@@ -1261,7 +4656,7 @@ mod tests {
             Lexeme::Num(5),
         ];
 
-        let today = now.map_or(Local::now().naive_local().date(), |now| now.date());
+        let today = now.date();
         let (date, t) = DateTime::parse(l.as_slice()).unwrap();
         let date = date
             .to_chrono(Local::now().naive_local().time(), now)
@@ -1273,9 +4668,9 @@ mod tests {
         assert_eq!(date.day(), 5);
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_week_before(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_week_before(now: ChronoDateTime) {
         let l = vec![
             Lexeme::A,
             Lexeme::Week,
@@ -1284,7 +4679,7 @@ mod tests {
             Lexeme::Num(15),
         ];
 
-        let today = now.map_or(Local::now().naive_local().date(), |now| now.date());
+        let today = now.date();
         let (date, t) = DateTime::parse(l.as_slice()).unwrap();
         let date = date
             .to_chrono(Local::now().naive_local().time(), now)
@@ -1296,9 +4691,9 @@ mod tests {
         assert_eq!(date.day(), 8);
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_month_before(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_month_before(now: ChronoDateTime) {
         let l = vec![
             Lexeme::A,
             Lexeme::Month,
@@ -1307,7 +4702,7 @@ mod tests {
             Lexeme::Num(5),
         ];
 
-        let today = now.map_or(Local::now().naive_local().date(), |now| now.date());
+        let today = now.date();
         let (date, t) = DateTime::parse(l.as_slice()).unwrap();
         let date = date
             .to_chrono(Local::now().naive_local().time(), now)
@@ -1319,9 +4714,9 @@ mod tests {
         assert_eq!(date.day(), 5);
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_year_before(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_year_before(now: ChronoDateTime) {
         let l = vec![
             Lexeme::A,
             Lexeme::Year,
@@ -1330,7 +4725,7 @@ mod tests {
             Lexeme::Num(5),
         ];
 
-        let today = now.map_or(Local::now().naive_local().date(), |now| now.date());
+        let today = now.date();
         let (date, t) = DateTime::parse(l.as_slice()).unwrap();
         let date = date
             .to_chrono(Local::now().naive_local().time(), now)
@@ -1355,7 +4750,10 @@ mod tests {
 
         let (date, t) = DateTime::parse(l.as_slice()).unwrap();
         let date = date
-            .to_chrono(Local::now().naive_local().time(), None)
+            .to_chrono(
+                Local::now().naive_local().time(),
+                Local::now().naive_local(),
+            )
             .unwrap();
 
         assert_eq!(t, 6);
@@ -1378,7 +4776,10 @@ mod tests {
 
         let (date, t) = DateTime::parse(l.as_slice()).unwrap();
         let date = date
-            .to_chrono(Local::now().naive_local().time(), None)
+            .to_chrono(
+                Local::now().naive_local().time(),
+                Local::now().naive_local(),
+            )
             .unwrap();
 
         assert_eq!(t, 6);
@@ -1388,24 +4789,24 @@ mod tests {
         assert_eq!(date.day(), 28);
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_next_week(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_next_week(now: ChronoDateTime) {
         let l = vec![Lexeme::Next, Lexeme::Week];
 
-        let today = now.map_or(Local::now().naive_local(), |now| now);
+        let today = now;
         let (date, _) = DateTime::parse(l.as_slice()).unwrap();
         let date = date.to_chrono(today.time(), now).unwrap();
 
         assert_eq!(date, today + ChronoDuration::weeks(1));
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_next_month(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_next_month(now: ChronoDateTime) {
         let l = vec![Lexeme::Next, Lexeme::Month];
 
-        let today = now.map_or(Local::now().naive_local(), |now| now);
+        let today = now;
 
         let (date, _) = DateTime::parse(l.as_slice()).unwrap();
         let date = date.to_chrono(today.time(), now).unwrap();
@@ -1418,12 +4819,12 @@ mod tests {
         );
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_next_year(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_next_year(now: ChronoDateTime) {
         let l = vec![Lexeme::Next, Lexeme::Year];
 
-        let today = now.map_or(Local::now().naive_local(), |now| now);
+        let today = now;
         let (date, _) = DateTime::parse(l.as_slice()).unwrap();
         let date = date.to_chrono(today.time(), now).unwrap();
 
@@ -1435,24 +4836,24 @@ mod tests {
         );
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_last_week(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_last_week(now: ChronoDateTime) {
         let l = vec![Lexeme::Last, Lexeme::Week];
 
-        let today = now.map_or(Local::now().naive_local(), |now| now);
+        let today = now;
         let (date, _) = DateTime::parse(l.as_slice()).unwrap();
         let date = date.to_chrono(today.time(), now).unwrap();
 
         assert_eq!(date, today - ChronoDuration::weeks(1));
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_last_month(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_last_month(now: ChronoDateTime) {
         let l = vec![Lexeme::Last, Lexeme::Month];
 
-        let today = now.map_or(Local::now().naive_local(), |now| now);
+        let today = now;
         let (date, _) = DateTime::parse(l.as_slice()).unwrap();
         let date = date.to_chrono(today.time(), now).unwrap();
 
@@ -1464,12 +4865,12 @@ mod tests {
         );
     }
 
-    #[test_case(None; "default reference time")]
-    #[test_case(Some(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local()); "past reference time")]
-    fn test_last_year(now: Option<ChronoDateTime>) {
+    #[test_case(Local::now().naive_local(); "default reference time")]
+    #[test_case(Local.with_ymd_and_hms(2021, 4, 30, 7, 15, 17).single().expect("literal date for test case").naive_local(); "past reference time")]
+    fn test_last_year(now: ChronoDateTime) {
         let l = vec![Lexeme::Last, Lexeme::Year];
 
-        let today = now.map_or(Local::now().naive_local(), |now| now);
+        let today = now;
         let (date, _) = DateTime::parse(l.as_slice()).unwrap();
         let date = date.to_chrono(today.time(), now).unwrap();
 
@@ -1498,7 +4899,10 @@ mod tests {
 
         let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
         let date = date
-            .to_chrono(Local::now().naive_local().time(), None)
+            .to_chrono(
+                Local::now().naive_local().time(),
+                Local::now().naive_local(),
+            )
             .unwrap();
 
         assert_eq!(t, 8);
@@ -1521,7 +4925,10 @@ mod tests {
 
         let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
         let date = date
-            .to_chrono(Local::now().naive_local().time(), None)
+            .to_chrono(
+                Local::now().naive_local().time(),
+                Local::now().naive_local(),
+            )
             .unwrap();
 
         assert_eq!(t, 5);
@@ -1545,7 +4952,10 @@ mod tests {
         ];
         let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
         let date = date
-            .to_chrono(Local::now().naive_local().time(), None)
+            .to_chrono(
+                Local::now().naive_local().time(),
+                Local::now().naive_local(),
+            )
             .unwrap();
         let current_year = Local::now().naive_local().year();
 
@@ -1567,7 +4977,10 @@ mod tests {
             Lexeme::Num(2023),
         ];
         let (date, _) = DateTime::parse(lexemes.as_slice()).unwrap();
-        let date = date.to_chrono(Local::now().naive_local().time(), None);
+        let date = date.to_chrono(
+            Local::now().naive_local().time(),
+            Local::now().naive_local(),
+        );
 
         assert!(date.is_err());
     }
@@ -1583,7 +4996,10 @@ mod tests {
         ];
         let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
         let date = date
-            .to_chrono(Local::now().naive_local().time(), None)
+            .to_chrono(
+                Local::now().naive_local().time(),
+                Local::now().naive_local(),
+            )
             .unwrap();
 
         assert_eq!(t, 5);
@@ -1602,7 +5018,10 @@ mod tests {
             Lexeme::Num(2023),
         ];
         let (date, _) = DateTime::parse(lexemes.as_slice()).unwrap();
-        let date = date.to_chrono(Local::now().naive_local().time(), None);
+        let date = date.to_chrono(
+            Local::now().naive_local().time(),
+            Local::now().naive_local(),
+        );
 
         assert!(date.is_err());
     }
@@ -1618,7 +5037,10 @@ mod tests {
         ];
         let (date, t) = DateTime::parse(lexemes.as_slice()).unwrap();
         let date = date
-            .to_chrono(Local::now().naive_local().time(), None)
+            .to_chrono(
+                Local::now().naive_local().time(),
+                Local::now().naive_local(),
+            )
             .unwrap();
 
         assert_eq!(t, 5);
@@ -1637,8 +5059,138 @@ mod tests {
             Lexeme::Num(2023),
         ];
         let (date, _) = DateTime::parse(lexemes.as_slice()).unwrap();
-        let date = date.to_chrono(Local::now().naive_local().time(), None);
+        let date = date.to_chrono(
+            Local::now().naive_local().time(),
+            Local::now().naive_local(),
+        );
 
         assert!(date.is_err());
     }
+
+    #[test]
+    fn test_constructed_tree() {
+        let now = ChronoDate::from_ymd_opt(2024, 6, 3)
+            .unwrap()
+            .and_time(ChronoTime::MIN);
+        let options = ParseOptions::default();
+
+        // A tree built by hand is the same as the parsed one
+        let lexemes = Lexeme::lex_line("3 days and 2 hours after next friday at 5:00 pm").unwrap();
+        let (parsed, _) = DateTime::parse(lexemes.as_slice()).unwrap();
+        let built = DateTime::after(
+            Duration::new(3, Unit::Day).and(Duration::new(2, Unit::Hour)),
+            DateTime::at(
+                Date::Relative(RelativeSpecifier::Next, Weekday::Friday),
+                Time::HourMinPM(5, 0),
+            ),
+        );
+        assert_eq!(built, parsed);
+
+        let date = built.to_chrono(ChronoTime::MIN, now).unwrap();
+        assert_eq!(date.to_string(), "2024-06-17 19:00:00");
+
+        let fixed = ChronoDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let fixed_date = Date::try_from(fixed).unwrap();
+        let tree = DateTime::before(Duration::new(1, Unit::Week), fixed_date.clone().into())
+            .with_time(Time::HourMin(9, 30));
+        let date = tree.to_chrono(ChronoTime::MIN, now).unwrap();
+        assert_eq!(date.to_string(), "2024-02-22 09:30:00");
+
+        // Nodes evaluate on their own as well
+        assert_eq!(fixed_date.to_chrono(now.date(), &options), Ok(fixed));
+        let bc = ChronoDate::from_ymd_opt(-44, 3, 15).unwrap();
+        assert!(matches!(
+            Date::try_from(bc),
+            Err(crate::Error::InvalidDate(_))
+        ));
+        assert_eq!(
+            Date::Tomorrow.to_chrono(now.date(), &options),
+            Ok(ChronoDate::from_ymd_opt(2024, 6, 4).unwrap())
+        );
+        assert_eq!(
+            Time::Empty.to_chrono(ChronoTime::MIN, &options),
+            Ok(ChronoTime::MIN)
+        );
+        assert_eq!(
+            Duration::new(1, Unit::Month).after(now, &options).unwrap(),
+            ChronoDate::from_ymd_opt(2024, 7, 3)
+                .unwrap()
+                .and_time(ChronoTime::MIN)
+        );
+        assert_eq!(
+            Weekday::from(chrono::Weekday::Wed).to_chrono(),
+            chrono::Weekday::Wed
+        );
+    }
+
+    #[test]
+    fn test_canonical_round_trip() {
+        use crate::named::Anchor;
+
+        let release = ChronoDate::from_ymd_opt(2024, 7, 1).unwrap();
+        let options =
+            ParseOptions::new().named_date(Anchor::fixed(["release day", "launch"], release));
+
+        for (input, expected) in [
+            ("Tomorrow at 5pm", "tomorrow at 5:00 pm"),
+            ("an hour ago", "an hour ago"),
+            ("1h30m ago", "1 hour and 30 minutes ago"),
+            (
+                "a couple of days after launch",
+                "a couple of days after this release day",
+            ),
+            ("tonight at 11", "today in the night at 11:00"),
+            ("tonight at 2am", "today in the night at 2:00 am"),
+            (
+                "3 sundays before the end of next month",
+                "3 sundays before the end of next month",
+            ),
+            ("the friday after next week", "the friday after next week"),
+            (
+                "(3 hours after today at noon) at 5pm",
+                "3 hours after today at 12:00, at 5:00 pm",
+            ),
+            (
+                "3 hours after (tomorrow, at 5pm)",
+                "3 hours after (tomorrow, at 5:00 pm)",
+            ),
+            (
+                "Tuesday, June 4 2024 at 9am",
+                "tuesday, june 4 2024 at 9:00 am",
+            ),
+            ("19.12.2023", "19.12.2023"),
+            ("2024-150 + 12h", "12 hours after 2024-150"),
+            ("--06-15", "--06-15"),
+            ("in 2030", "in 2030"),
+            ("friday week", "friday week"),
+            ("last leap day", "last leap day"),
+        ] {
+            let lexemes = Lexeme::lex_line_with(input, &options).unwrap();
+            let (tree, _) = DateTime::parse(lexemes.as_slice()).unwrap();
+            let canonical = tree.to_canonical_string(&options);
+            assert_eq!(canonical, expected, "{input}");
+
+            let lexemes = Lexeme::lex_line_with(&canonical, &options).unwrap();
+            let (reparsed, tokens) = DateTime::parse(lexemes.as_slice()).unwrap();
+            assert_eq!(tokens, lexemes.len(), "{canonical}");
+            assert_eq!(reparsed, tree, "{canonical}");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let lexemes = Lexeme::lex_line("3 days after next friday at 5:00 pm").unwrap();
+        let (tree, _) = DateTime::parse(lexemes.as_slice()).unwrap();
+
+        let json = serde_json::to_string(&tree).unwrap();
+        let restored: DateTime = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, tree);
+
+        let now = ChronoDate::from_ymd_opt(2024, 6, 3)
+            .unwrap()
+            .and_time(ChronoTime::MIN);
+        let date = restored.to_chrono(ChronoTime::MIN, now).unwrap();
+        assert_eq!(date.to_string(), "2024-06-17 17:00:00");
+    }
 }
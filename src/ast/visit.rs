@@ -0,0 +1,328 @@
+//! Traversal of syntax trees, for inspecting or rewriting expressions
+//! without matching every kind of node
+//!
+//! Every method of [`Visitor`] and [`VisitorMut`] walks the children of its
+//! node by default, so implementations only override the nodes they are
+//! interested in, calling the matching `walk_` function to keep descending.
+//!
+//! ```rust
+//! use fuzzydate::ast::visit::{self, Visitor};
+//! use fuzzydate::ast::{DateTime, Weekday};
+//! use fuzzydate::lexer::Lexeme;
+//!
+//! struct Weekdays(Vec<Weekday>);
+//!
+//! impl Visitor for Weekdays {
+//!     fn visit_weekday(&mut self, weekday: &Weekday) {
+//!         self.0.push(weekday.clone());
+//!     }
+//! }
+//!
+//! let lexemes = Lexeme::lex_line("the monday after next friday").unwrap();
+//! let (tree, _) = DateTime::parse(&lexemes).unwrap();
+//!
+//! let mut weekdays = Weekdays(Vec::new());
+//! visit::walk(&mut weekdays, &tree);
+//! assert_eq!(weekdays.0, [Weekday::Monday, Weekday::Friday]);
+//! ```
+
+use super::{Date, DateTime, Duration, Month, RelativeSpecifier, Time, Unit, Weekday};
+
+/// Inspects the nodes of a tree, from the root down
+pub trait Visitor {
+    fn visit_datetime(&mut self, datetime: &DateTime) {
+        walk_datetime(self, datetime);
+    }
+
+    fn visit_date(&mut self, date: &Date) {
+        walk_date(self, date);
+    }
+
+    fn visit_time(&mut self, _time: &Time) {}
+
+    fn visit_duration(&mut self, duration: &Duration) {
+        walk_duration(self, duration);
+    }
+
+    fn visit_relative_specifier(&mut self, _relspec: &RelativeSpecifier) {}
+
+    fn visit_weekday(&mut self, _weekday: &Weekday) {}
+
+    fn visit_month(&mut self, _month: &Month) {}
+
+    fn visit_unit(&mut self, _unit: &Unit) {}
+}
+
+/// Visit a whole tree
+pub fn walk<V: Visitor + ?Sized>(visitor: &mut V, datetime: &DateTime) {
+    visitor.visit_datetime(datetime);
+}
+
+/// Visit the children of a datetime
+pub fn walk_datetime<V: Visitor + ?Sized>(visitor: &mut V, datetime: &DateTime) {
+    match datetime {
+        DateTime::DateTime(date, time) | DateTime::TimeDate(time, date) => {
+            visitor.visit_date(date);
+            visitor.visit_time(time);
+        }
+        DateTime::After(duration, anchor) | DateTime::Before(duration, anchor) => {
+            visitor.visit_duration(duration);
+            visitor.visit_datetime(anchor);
+        }
+        DateTime::Ago(duration) => visitor.visit_duration(duration),
+        DateTime::WeekdayAfter(_, weekday, anchor)
+        | DateTime::WeekdayBefore(_, weekday, anchor) => {
+            visitor.visit_weekday(weekday);
+            visitor.visit_datetime(anchor);
+        }
+        DateTime::WithTime(datetime, time) => {
+            visitor.visit_datetime(datetime);
+            visitor.visit_time(time);
+        }
+        DateTime::Now | DateTime::Epoch | DateTime::PastHour(_) => {}
+    }
+}
+
+/// Visit the children of a date
+pub fn walk_date<V: Visitor + ?Sized>(visitor: &mut V, date: &Date) {
+    match date {
+        Date::MonthDayYear(month, _, _) | Date::MonthDay(month, _) | Date::MonthYear(month, _) => {
+            visitor.visit_month(month)
+        }
+        Date::UnitRelative(relspec, unit)
+        | Date::StartOf(relspec, unit)
+        | Date::EndOf(relspec, unit) => {
+            visitor.visit_relative_specifier(relspec);
+            visitor.visit_unit(unit);
+        }
+        Date::Named(_, month, relspec) => {
+            if let Some((month, _)) = month {
+                visitor.visit_month(month);
+            }
+            visitor.visit_relative_specifier(relspec);
+        }
+        Date::EndOfNamed(_, relspec) | Date::LeapDay(relspec) | Date::LeapYear(relspec) => {
+            visitor.visit_relative_specifier(relspec);
+        }
+        Date::Relative(relspec, weekday) => {
+            visitor.visit_relative_specifier(relspec);
+            visitor.visit_weekday(weekday);
+        }
+        Date::RelativeMonth(relspec, month) => {
+            visitor.visit_relative_specifier(relspec);
+            visitor.visit_month(month);
+        }
+        Date::WeekOn(weekday) | Date::Weekday(weekday) => visitor.visit_weekday(weekday),
+        Date::WeekdayDate(weekday, date) | Date::LastWeekdayOf(weekday, date) => {
+            visitor.visit_weekday(weekday);
+            visitor.visit_date(date);
+        }
+        Date::Numeric(..)
+        | Date::YearDay(..)
+        | Date::YearMonth(..)
+        | Date::Year(_)
+        | Date::MonthNumDay(..)
+        | Date::Day(_)
+        | Date::Today
+        | Date::Tomorrow
+        | Date::Yesterday
+        | Date::DayAfterTomorrow
+        | Date::DayBeforeYesterday => {}
+    }
+}
+
+/// Visit the children of a duration
+pub fn walk_duration<V: Visitor + ?Sized>(visitor: &mut V, duration: &Duration) {
+    match duration {
+        Duration::Article(unit) | Duration::Specific(_, unit) | Duration::Vague(_, unit) => {
+            visitor.visit_unit(unit);
+        }
+        Duration::Concat(first, second) => {
+            visitor.visit_duration(first);
+            visitor.visit_duration(second);
+        }
+    }
+}
+
+/// Rewrites the nodes of a tree in place, from the root down
+pub trait VisitorMut {
+    fn visit_datetime_mut(&mut self, datetime: &mut DateTime) {
+        walk_datetime_mut(self, datetime);
+    }
+
+    fn visit_date_mut(&mut self, date: &mut Date) {
+        walk_date_mut(self, date);
+    }
+
+    fn visit_time_mut(&mut self, _time: &mut Time) {}
+
+    fn visit_duration_mut(&mut self, duration: &mut Duration) {
+        walk_duration_mut(self, duration);
+    }
+
+    fn visit_relative_specifier_mut(&mut self, _relspec: &mut RelativeSpecifier) {}
+
+    fn visit_weekday_mut(&mut self, _weekday: &mut Weekday) {}
+
+    fn visit_month_mut(&mut self, _month: &mut Month) {}
+
+    fn visit_unit_mut(&mut self, _unit: &mut Unit) {}
+}
+
+/// Rewrite a whole tree
+pub fn walk_mut<V: VisitorMut + ?Sized>(visitor: &mut V, datetime: &mut DateTime) {
+    visitor.visit_datetime_mut(datetime);
+}
+
+/// Rewrite the children of a datetime
+pub fn walk_datetime_mut<V: VisitorMut + ?Sized>(visitor: &mut V, datetime: &mut DateTime) {
+    match datetime {
+        DateTime::DateTime(date, time) | DateTime::TimeDate(time, date) => {
+            visitor.visit_date_mut(date);
+            visitor.visit_time_mut(time);
+        }
+        DateTime::After(duration, anchor) | DateTime::Before(duration, anchor) => {
+            visitor.visit_duration_mut(duration);
+            visitor.visit_datetime_mut(anchor);
+        }
+        DateTime::Ago(duration) => visitor.visit_duration_mut(duration),
+        DateTime::WeekdayAfter(_, weekday, anchor)
+        | DateTime::WeekdayBefore(_, weekday, anchor) => {
+            visitor.visit_weekday_mut(weekday);
+            visitor.visit_datetime_mut(anchor);
+        }
+        DateTime::WithTime(datetime, time) => {
+            visitor.visit_datetime_mut(datetime);
+            visitor.visit_time_mut(time);
+        }
+        DateTime::Now | DateTime::Epoch | DateTime::PastHour(_) => {}
+    }
+}
+
+/// Rewrite the children of a date
+pub fn walk_date_mut<V: VisitorMut + ?Sized>(visitor: &mut V, date: &mut Date) {
+    match date {
+        Date::MonthDayYear(month, _, _) | Date::MonthDay(month, _) | Date::MonthYear(month, _) => {
+            visitor.visit_month_mut(month)
+        }
+        Date::UnitRelative(relspec, unit)
+        | Date::StartOf(relspec, unit)
+        | Date::EndOf(relspec, unit) => {
+            visitor.visit_relative_specifier_mut(relspec);
+            visitor.visit_unit_mut(unit);
+        }
+        Date::Named(_, month, relspec) => {
+            if let Some((month, _)) = month {
+                visitor.visit_month_mut(month);
+            }
+            visitor.visit_relative_specifier_mut(relspec);
+        }
+        Date::EndOfNamed(_, relspec) | Date::LeapDay(relspec) | Date::LeapYear(relspec) => {
+            visitor.visit_relative_specifier_mut(relspec);
+        }
+        Date::Relative(relspec, weekday) => {
+            visitor.visit_relative_specifier_mut(relspec);
+            visitor.visit_weekday_mut(weekday);
+        }
+        Date::RelativeMonth(relspec, month) => {
+            visitor.visit_relative_specifier_mut(relspec);
+            visitor.visit_month_mut(month);
+        }
+        Date::WeekOn(weekday) | Date::Weekday(weekday) => visitor.visit_weekday_mut(weekday),
+        Date::WeekdayDate(weekday, date) | Date::LastWeekdayOf(weekday, date) => {
+            visitor.visit_weekday_mut(weekday);
+            visitor.visit_date_mut(date);
+        }
+        Date::Numeric(..)
+        | Date::YearDay(..)
+        | Date::YearMonth(..)
+        | Date::Year(_)
+        | Date::MonthNumDay(..)
+        | Date::Day(_)
+        | Date::Today
+        | Date::Tomorrow
+        | Date::Yesterday
+        | Date::DayAfterTomorrow
+        | Date::DayBeforeYesterday => {}
+    }
+}
+
+/// Rewrite the children of a duration
+pub fn walk_duration_mut<V: VisitorMut + ?Sized>(visitor: &mut V, duration: &mut Duration) {
+    match duration {
+        Duration::Article(unit) | Duration::Specific(_, unit) | Duration::Vague(_, unit) => {
+            visitor.visit_unit_mut(unit);
+        }
+        Duration::Concat(first, second) => {
+            visitor.visit_duration_mut(first);
+            visitor.visit_duration_mut(second);
+        }
+    }
+}
+
+#[test]
+fn test_visit() {
+    use crate::lexer::Lexeme;
+    use alloc::vec::Vec;
+    use chrono::{NaiveDate, NaiveTime};
+
+    let parse = |input| {
+        let lexemes = Lexeme::lex_line(input).unwrap();
+        DateTime::parse(&lexemes).unwrap().0
+    };
+
+    #[derive(Default)]
+    struct Collect {
+        weekdays: Vec<Weekday>,
+        units: Vec<Unit>,
+        dates: usize,
+    }
+
+    impl Visitor for Collect {
+        fn visit_date(&mut self, date: &Date) {
+            self.dates += 1;
+            walk_date(self, date);
+        }
+
+        fn visit_weekday(&mut self, weekday: &Weekday) {
+            self.weekdays.push(weekday.clone());
+        }
+
+        fn visit_unit(&mut self, unit: &Unit) {
+            self.units.push(*unit);
+        }
+    }
+
+    let mut collect = Collect::default();
+    walk(
+        &mut collect,
+        &parse("3 days and 2 hours after friday, june 14 2024"),
+    );
+    assert_eq!(collect.weekdays, [Weekday::Friday]);
+    assert_eq!(collect.units, [Unit::Day, Unit::Hour]);
+    assert_eq!(collect.dates, 2);
+
+    // Every relative specifier is moved a period back
+    struct Earlier;
+
+    impl VisitorMut for Earlier {
+        fn visit_relative_specifier_mut(&mut self, relspec: &mut RelativeSpecifier) {
+            *relspec = match relspec {
+                RelativeSpecifier::Next => RelativeSpecifier::This,
+                _ => RelativeSpecifier::Last,
+            };
+        }
+    }
+
+    let mut tree = parse("2 days after next month");
+    walk_mut(&mut Earlier, &mut tree);
+    assert_eq!(tree, parse("2 days after this month"));
+
+    let now = NaiveDate::from_ymd_opt(2024, 6, 12)
+        .unwrap()
+        .and_time(NaiveTime::MIN);
+    let mut tree = parse("the tuesday after next friday");
+    walk_mut(&mut Earlier, &mut tree);
+    let date = tree.to_chrono(NaiveTime::MIN, now).unwrap();
+    assert_eq!(date.to_string(), "2024-06-18 00:00:00");
+}
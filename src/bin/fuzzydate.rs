@@ -0,0 +1,604 @@
+//! A command line interface to the parser, built with the `cli` feature,
+//! e.g. `cargo run --features cli -- "next friday at 5pm"`
+//!
+//! - `parse` (the default): parse expressions, one result per line
+//! - `range`: parse periods, e.g. "from monday to friday", writing their
+//!   start and end on two lines
+//! - `diff`: the time between two expressions
+//! - `schedule`: the next occurrences of a recurrence, e.g. "every tuesday"
+//! - `explain`: how expressions were interpreted
+//!
+//! Expressions are read from stdin, one per line, when none are given, e.g.
+//! `cut -d, -f3 log.csv | fuzzydate parse --keep-going`.
+//! Errors are explained on stderr, pointing at the word which couldn't be
+//! read where it is known.
+//!
+//! `--locale de`, `fr` or `es` reads input in that language and writes the
+//! month and weekday names of `--format` in it. Other output is numeric or
+//! a standard format, and humanized phrases are in English.
+
+extern crate fuzzydate;
+use chrono::{DateTime, Local, NaiveDateTime, SubsecRound, TimeZone};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use fuzzydate::lang::{localize_format, Language};
+use fuzzydate::{
+    parse_detailed, parse_recurrence_with_options, parse_with_options, CalendarDuration, DateOrder,
+    Error, Granularity, ParseOptions, Parsed, TimeBinding, WeekdayCheck,
+};
+use serde_json::json;
+use std::io::{stderr, stdin, stdout, BufRead, Write};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(
+    name = "fuzzydate",
+    about = "Parse fuzzy dates such as \"next friday at 5pm\""
+)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[command(flatten)]
+    parse: ParseArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Parse expressions into datetimes
+    Parse(ParseArgs),
+    /// Parse expressions into periods, writing their start and end
+    Range(BatchArgs),
+    /// The time from the second expression to the first, negative if the
+    /// first is the earlier
+    Diff {
+        first: String,
+        second: String,
+        /// Write a whole number of the unit instead of calendar units,
+        /// e.g. "6 days and 3 hours"
+        #[arg(long)]
+        unit: Option<Unit>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// The next occurrences of a recurrence, e.g. "every tuesday at 9am"
+    Schedule {
+        recurrence: String,
+        /// How many occurrences to write
+        #[arg(long, short = 'n', default_value_t = 5)]
+        count: usize,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// How expressions were interpreted
+    Explain {
+        /// Expressions to explain, read from stdin if there are none
+        exprs: Vec<String>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+#[derive(Args)]
+struct BatchArgs {
+    /// Expressions to parse, read from stdin one per line if there are none
+    exprs: Vec<String>,
+    /// Write errors in place of results instead of stopping at the first
+    /// expression which can't be parsed
+    #[arg(long)]
+    keep_going: bool,
+    /// Only validate the input: nothing is written to stdout, errors are
+    /// written to stderr, and the exit code is 1 if any expression
+    /// couldn't be parsed
+    #[arg(long)]
+    check: bool,
+    #[command(flatten)]
+    input: InputArgs,
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+/// Periods have no single interpretation to explain, so only parsing
+/// datetimes takes --explain
+#[derive(Args)]
+struct ParseArgs {
+    #[command(flatten)]
+    batch: BatchArgs,
+    /// Explain on stderr how every expression was interpreted
+    #[arg(long)]
+    explain: bool,
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Read expressions relative to an RFC 3339 timestamp or an expression,
+    /// e.g. "last monday at 9am", instead of now
+    #[arg(long)]
+    relative_to: Option<String>,
+    /// The language of the input and of the month and weekday names of
+    /// --format: en, de, fr, or es
+    #[arg(long)]
+    locale: Option<String>,
+    /// The order of numeric dates
+    #[arg(long)]
+    date_order: Option<Order>,
+    /// Fail on input with more than one reading instead of guessing, and
+    /// point at clock times past the end of a 24 hour clock
+    #[arg(long)]
+    strict: bool,
+}
+
+#[derive(Args)]
+struct OutputArgs {
+    /// The strftime format of results, which may include the local offset,
+    /// with month and weekday names in the --locale
+    #[arg(long)]
+    format: Option<String>,
+    /// Write results in ISO 8601
+    #[arg(long)]
+    iso: bool,
+    /// Write results in RFC 2822
+    #[arg(long)]
+    rfc2822: bool,
+    /// Write only the date of results
+    #[arg(long)]
+    date_only: bool,
+    /// Write only the time of results
+    #[arg(long)]
+    time_only: bool,
+    /// Write the seconds since the unix epoch, reading results in the local
+    /// timezone
+    #[arg(long)]
+    unix: bool,
+    /// Write the milliseconds since the unix epoch
+    #[arg(long)]
+    unix_ms: bool,
+    /// Write results as phrases relative to now, e.g. "2 hours ago"
+    #[arg(long)]
+    humanize: bool,
+    /// Write each result as a JSON object, and with --keep-going each error
+    /// as an object with the input and the error
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Order {
+    Mdy,
+    Dmy,
+    Ymd,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Unit {
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+}
+
+impl InputArgs {
+    /// The language of the input and output, English by default
+    fn language(&self) -> Result<&'static dyn Language, String> {
+        let Some(code) = &self.locale else {
+            return Ok(&fuzzydate::lang::English);
+        };
+
+        fuzzydate::lang::from_code(code).ok_or(format!("unsupported locale {code}"))
+    }
+
+    /// The options to parse with, and the time every expression is read
+    /// relative to
+    fn options(&self) -> Result<(ParseOptions, NaiveDateTime), String> {
+        let mut options = ParseOptions::new()
+            .language(self.language()?)
+            .strict(self.strict)
+            .strict_time(self.strict);
+        if let Some(order) = self.date_order {
+            options = options.date_order(match order {
+                Order::Mdy => DateOrder::MonthDayYear,
+                Order::Dmy => DateOrder::DayMonthYear,
+                Order::Ymd => DateOrder::YearMonthDay,
+            });
+        }
+
+        // RFC 3339 timestamps are read exactly, anything else as an
+        // expression
+        let mut now = Local::now().naive_local().trunc_subsecs(0);
+        if let Some(relative_to) = &self.relative_to {
+            let rfc3339 = DateTime::parse_from_rfc3339(relative_to)
+                .map(|date| date.with_timezone(&Local).naive_local());
+            now = rfc3339
+                .or_else(|_| parse_with_options(relative_to, &options))
+                .map_err(|e| format!("--relative-to {}", diagnose(relative_to, &e)))?;
+        }
+
+        Ok((options.relative_to(now), now))
+    }
+}
+
+impl OutputArgs {
+    /// Write a result, relative to `now` when humanized, with the names in
+    /// a format in `language`
+    fn render(
+        &self,
+        date: NaiveDateTime,
+        now: NaiveDateTime,
+        language: &dyn Language,
+    ) -> Result<String, String> {
+        if self.humanize {
+            return Ok(fuzzydate::humanize_relative(date, now, Granularity::Minute));
+        }
+
+        // Results are in the local timezone, the earlier instant where
+        // clocks go back, so formats may include the offset
+        if self.unix || self.unix_ms {
+            let Some(local) = Local.from_local_datetime(&date).earliest() else {
+                return Err(format!("{date} doesn't exist in the local timezone"));
+            };
+
+            return Ok(match self.unix_ms {
+                true => local.timestamp_millis().to_string(),
+                false => local.timestamp().to_string(),
+            });
+        }
+
+        let localized;
+        let format = if let Some(format) = &self.format {
+            localized = localize_format(format, date.date(), language);
+            &localized
+        } else if self.iso {
+            "%Y-%m-%dT%H:%M:%S"
+        } else if self.rfc2822 {
+            "%a, %d %b %Y %H:%M:%S %z"
+        } else if self.date_only {
+            "%Y-%m-%d"
+        } else if self.time_only {
+            "%H:%M:%S"
+        } else {
+            "%Y-%m-%d %H:%M:%S"
+        };
+
+        fuzzydate::format_result_in(&Ok(date), &Local, Some(format))
+            .map_err(|_| format!("{date} can't be written with the format {format}"))
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        None => batch(&cli.parse.batch, false, cli.parse.explain),
+        Some(Command::Parse(args)) => batch(&args.batch, false, args.explain),
+        Some(Command::Range(args)) => batch(&args, true, false),
+        Some(Command::Diff {
+            first,
+            second,
+            unit,
+            input,
+        }) => diff(&first, &second, unit, &input),
+        Some(Command::Schedule {
+            recurrence,
+            count,
+            input,
+            output,
+        }) => schedule(&recurrence, count, &input, &output),
+        Some(Command::Explain { exprs, input }) => explain(exprs, &input),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The given expressions, or the lines of stdin if there are none
+fn inputs(exprs: Vec<String>) -> Box<dyn Iterator<Item = std::io::Result<String>>> {
+    match exprs.is_empty() {
+        true => Box::new(stdin().lock().lines()),
+        false => Box::new(exprs.into_iter().map(Ok)),
+    }
+}
+
+/// Parse every input as a datetime or a period, returning whether all of
+/// them could be parsed, and explaining datetimes if `explain` is set
+fn batch(args: &BatchArgs, range: bool, explain: bool) -> Result<bool, String> {
+    // Batches may be long, so the options are compiled once for every line
+    let (options, now) = args.input.options()?;
+    let language = args.input.language()?;
+    let parser = fuzzydate::Parser::new(options);
+    let output = &args.output;
+    let mut out = stdout().lock();
+    let mut failed = false;
+
+    for line in inputs(args.exprs.clone()) {
+        let line = line.map_err(|e| e.to_string())?;
+        let input = line.trim();
+
+        let result = if range {
+            parser
+                .parse_range(input, now)
+                .map_err(|e| diagnose(input, &e))
+                .and_then(|range| {
+                    let start = output.render(range.start, now, language)?;
+                    let end = output.render(range.end, now, language)?;
+                    Ok(match output.json {
+                        true => json!({ "start": start, "end": end }).to_string(),
+                        false => format!("{start}\n{end}"),
+                    })
+                })
+        } else {
+            parser
+                .parse_detailed(input, now)
+                .map_err(|e| diagnose(input, &e))
+                .and_then(|parsed| {
+                    if explain {
+                        eprintln!("{}", explanation(input, &parsed, parser.options(), now));
+                    }
+                    output.render(parsed.datetime, now, language)
+                })
+                .map(|date| match output.json {
+                    true => json!({ "date": date }).to_string(),
+                    false => date,
+                })
+        };
+
+        // Errors are explained on stderr, and with --keep-going stdout gets
+        // a line in their place so results stay in line with the input
+        let written = match &result {
+            Ok(_) if args.check => Ok(()),
+            Ok(output) => writeln!(out, "{output}"),
+            Err(e) => writeln!(stderr(), "error: {e}").and_then(|_| {
+                match args.keep_going && !args.check {
+                    true => writeln!(out, "{}", failure(input, e, output.json)),
+                    false => Ok(()),
+                }
+            }),
+        };
+        written.map_err(|e| e.to_string())?;
+
+        if result.is_err() {
+            failed = true;
+            if !args.keep_going {
+                break;
+            }
+        }
+    }
+
+    Ok(!failed)
+}
+
+/// The line written in place of the result of an input which couldn't be
+/// parsed, a JSON object with the input and the error under --json
+fn failure(input: &str, error: &str, json: bool) -> String {
+    let error = error.lines().next().unwrap_or_default();
+    match json {
+        true => json!({ "input": input, "error": error }).to_string(),
+        false => format!("error: {error}"),
+    }
+}
+
+/// Write the time from `second` to `first`
+fn diff(first: &str, second: &str, unit: Option<Unit>, input: &InputArgs) -> Result<bool, String> {
+    let (options, _) = input.options()?;
+    let a = parse_with_options(first, &options).map_err(|e| diagnose(first, &e))?;
+    let b = parse_with_options(second, &options).map_err(|e| diagnose(second, &e))?;
+
+    let Some(unit) = unit else {
+        println!("{}", CalendarDuration::between(b, a));
+        return Ok(true);
+    };
+
+    let seconds = match unit {
+        Unit::Weeks => 7 * 24 * 60 * 60,
+        Unit::Days => 24 * 60 * 60,
+        Unit::Hours => 60 * 60,
+        Unit::Minutes => 60,
+        Unit::Seconds => 1,
+    };
+
+    println!("{}", (a - b).num_seconds() / seconds);
+    Ok(true)
+}
+
+/// Write the next occurrences of a recurrence
+fn schedule(
+    recurrence: &str,
+    count: usize,
+    input: &InputArgs,
+    output: &OutputArgs,
+) -> Result<bool, String> {
+    let (options, now) = input.options()?;
+    let language = input.language()?;
+    let parsed = parse_recurrence_with_options(recurrence, &options)
+        .map_err(|e| diagnose(recurrence, &e))?;
+
+    for date in parsed.occurrences().take(count) {
+        let date = output.render(date, now, language)?;
+        match output.json {
+            true => println!("{}", json!({ "date": date })),
+            false => println!("{date}"),
+        }
+    }
+
+    Ok(true)
+}
+
+/// Write how every input was interpreted, returning whether all of them
+/// could be parsed
+fn explain(exprs: Vec<String>, input: &InputArgs) -> Result<bool, String> {
+    let (options, now) = input.options()?;
+    // Misspellings and mismatched weekdays are explained rather than
+    // failing the parse
+    let options = options
+        .weekday_check(WeekdayCheck::Warn)
+        .typo_tolerant(true);
+    let mut failed = false;
+
+    for line in inputs(exprs) {
+        let line = line.map_err(|e| e.to_string())?;
+        let input = line.trim();
+
+        match parse_detailed(input, &options) {
+            Ok(parsed) => {
+                println!("{}", explanation(input, &parsed, &options, now));
+                println!("  result: {}", parsed.datetime);
+                for correction in &parsed.corrections {
+                    println!(
+                        "  read {:?} as {:?}",
+                        correction.word, correction.replacement
+                    );
+                }
+                if let Some(mismatch) = parsed.weekday_mismatch {
+                    println!(
+                        "  {} is a {}, not a {}",
+                        mismatch.date,
+                        chrono::Datelike::weekday(&mismatch.date),
+                        mismatch.weekday
+                    );
+                }
+            }
+            Err(e) => {
+                eprintln!("error: {}", diagnose(input, &e));
+                failed = true;
+            }
+        }
+    }
+
+    Ok(!failed)
+}
+
+/// Describe how an input was interpreted, e.g. "'next friday' is the first
+/// Friday on or after a week from today, relative to 2025-01-02 09:00:00;
+/// the time defaulted to 09:00:00"
+fn explanation(input: &str, parsed: &Parsed, options: &ParseOptions, now: NaiveDateTime) -> String {
+    let time = match parsed.time_binding {
+        TimeBinding::Default => "defaulted to",
+        TimeBinding::Expression => "was given as",
+        TimeBinding::Anchor => "was given before the offset, ending up at",
+    };
+
+    format!(
+        "'{input}' is {}, relative to {now}; the time {time} {}",
+        parsed.expression.describe(options),
+        parsed.datetime.time()
+    )
+}
+
+/// Describe an error, pointing at the part of the input it is about where
+/// it is known, e.g.
+///
+/// ```text
+/// Unrecognized Token while lexing: "tomorow" at 0..7, did you mean "tomorrow"?
+///   tomorow at 5pm
+///   ^^^^^^^ did you mean "tomorrow"?
+/// ```
+fn diagnose(input: &str, e: &Error) -> String {
+    let (span, hint) = match e {
+        Error::UnrecognizedToken(token) => (
+            Some(token.span.clone()),
+            token
+                .suggestion
+                .as_ref()
+                .map(|s| format!("did you mean \"{s}\"?")),
+        ),
+        Error::ParseError(error) => (
+            error.span.clone(),
+            (!error.expected.is_empty()).then(|| format!("expected {}", error.expected.join(", "))),
+        ),
+        Error::InvalidDate(error) => (error.span.clone(), None),
+        _ => (None, None),
+    };
+
+    let mut message = e.to_string();
+    if let Some(span) = span.filter(|span| input.get(span.clone()).is_some()) {
+        let offset = input[..span.start].chars().count();
+        let width = input[span.clone()].chars().count().max(1);
+        message += &format!("\n  {input}\n  {}{}", " ".repeat(offset), "^".repeat(width));
+        if let Some(hint) = hint {
+            message += &format!(" {hint}");
+        }
+    }
+
+    message
+}
+
+#[test]
+fn test_humanize() {
+    let args = |expr: &str| {
+        let args = [
+            "fuzzydate",
+            "--humanize",
+            "--relative-to",
+            "2024-06-03T09:00:00Z",
+            expr,
+        ];
+        Cli::try_parse_from(args).unwrap().parse.batch
+    };
+
+    for (expr, expected) in [
+        ("in 3 days", "in 3 days"),
+        ("2 hours ago", "2 hours ago"),
+        ("in 2 weeks and 1 day", "in 2 weeks and 1 day"),
+        ("a month ago", "1 month ago"),
+    ] {
+        let args = args(expr);
+        let (options, now) = args.input.options().unwrap();
+        let date = parse_with_options(expr, &options).unwrap();
+        let rendered = args.output.render(date, now, &fuzzydate::lang::English);
+        assert_eq!(rendered.unwrap(), expected, "{expr}");
+    }
+}
+
+#[test]
+fn test_locale() {
+    for (locale, expr, expected) in [
+        ("de", "freitag", "Freitag 7 Juni"),
+        ("fr", "vendredi", "vendredi 7 juin"),
+        ("es", "viernes", "viernes 7 junio"),
+    ] {
+        let args = [
+            "fuzzydate",
+            "--locale",
+            locale,
+            "--format",
+            "%A %-d %B",
+            "--relative-to",
+            "2024-06-03T09:00:00Z",
+            expr,
+        ];
+        let args = Cli::try_parse_from(args).unwrap().parse.batch;
+        let (options, now) = args.input.options().unwrap();
+        let language = args.input.language().unwrap();
+        let date = parse_with_options(expr, &options).unwrap();
+        let rendered = args.output.render(date, now, language).unwrap();
+        assert_eq!(rendered, expected, "{expr}");
+    }
+}
+
+#[test]
+fn test_failure() {
+    let input = "next whenever";
+    let error = parse_with_options(input, &ParseOptions::new()).unwrap_err();
+    let error = diagnose(input, &error);
+    let first = error.lines().next().unwrap();
+    assert_eq!(failure(input, &error, false), format!("error: {first}"));
+
+    let line = failure(input, &error, true);
+    let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+    assert_eq!(value, json!({ "input": input, "error": first }));
+}
+
+#[test]
+fn test_explain_flag() {
+    assert!(Cli::try_parse_from(["fuzzydate", "--explain", "tomorrow"]).is_ok());
+    assert!(Cli::try_parse_from(["fuzzydate", "parse", "--explain", "tomorrow"]).is_ok());
+
+    let range = ["fuzzydate", "range", "--explain", "from monday to friday"];
+    assert!(Cli::try_parse_from(range).is_err());
+}
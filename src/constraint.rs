@@ -0,0 +1,224 @@
+//! Open-ended bounds on a datetime, e.g. "after 5pm tomorrow" or "no later
+//! than friday"
+
+use chrono::NaiveDateTime;
+
+use crate::{ast, lexer, Error, ParseOptions};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Which side of its datetime a [`Constraint`] allows
+pub enum Bound {
+    /// The datetime is the earliest allowed, e.g. "after 5pm"
+    Lower,
+    /// The datetime is the latest allowed, e.g. "before noon"
+    Upper,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// An open-ended bound on a datetime, as produced by `parse_constraint`
+///
+/// ```rust
+/// use chrono::NaiveDateTime;
+/// use fuzzydate::Bound;
+///
+/// let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+/// let constraint = fuzzydate::parse_constraint_relative_to("after 5pm tomorrow", now).unwrap();
+/// assert_eq!(constraint.bound, Bound::Lower);
+/// assert_eq!(constraint.datetime.to_string(), "2024-06-02 17:00:00");
+///
+/// let later = NaiveDateTime::parse_from_str("2024-06-02 18:00", "%Y-%m-%d %H:%M").unwrap();
+/// assert!(constraint.contains(later));
+/// assert!(!constraint.contains(now));
+/// ```
+pub struct Constraint {
+    /// Whether the datetime is the earliest or the latest allowed
+    pub bound: Bound,
+    /// The datetime bounding the allowed ones
+    pub datetime: NaiveDateTime,
+    /// Whether the datetime itself is allowed, as for "no later than friday"
+    /// but not for "before friday"
+    pub inclusive: bool,
+}
+
+impl Constraint {
+    /// Whether the constraint allows the datetime
+    pub fn contains(&self, datetime: NaiveDateTime) -> bool {
+        match (self.bound, self.inclusive) {
+            (Bound::Lower, false) => datetime > self.datetime,
+            (Bound::Lower, true) => datetime >= self.datetime,
+            (Bound::Upper, false) => datetime < self.datetime,
+            (Bound::Upper, true) => datetime <= self.datetime,
+        }
+    }
+}
+
+/// Parse an input string describing an open-ended bound on a datetime,
+/// interpreting it according to the given options
+pub fn parse_constraint_with_options(
+    input: impl AsRef<str>,
+    options: &ParseOptions,
+) -> Result<Constraint, Error> {
+    let vocabulary = lexer::Vocabulary::new(options);
+    parse_constraint_compiled(input.as_ref(), options, &vocabulary, None)
+}
+
+/// Parse an input string describing an open-ended bound on a datetime with
+/// a vocabulary gathered from the options beforehand, relative to the given
+/// datetime or else the current time of the options
+pub(crate) fn parse_constraint_compiled(
+    input: &str,
+    options: &ParseOptions,
+    vocabulary: &lexer::Vocabulary,
+    now: Option<NaiveDateTime>,
+) -> Result<Constraint, Error> {
+    let (lexemes, spans, _) = lexer::Lexeme::lex_compiled(input, options, vocabulary)?;
+    let (tree, tokens) = ast::Constraint::parse(lexemes.as_slice())
+        .ok_or_else(|| Error::parse_error_at(&spans, input.len()))?;
+    if options.minimal_grammar && tokens < lexemes.len() {
+        return Err(Error::trailing_tokens(&spans, tokens));
+    }
+    if options.minimal_grammar && !tree.is_documented() {
+        return Err(Error::parse_error());
+    }
+
+    let now = now.map_or_else(|| options.now(), Ok)?;
+    tree.to_chrono(options.time(now), now, options)
+}
+
+/// Parse an input string describing an open-ended bound on a datetime,
+/// treating the default as if it was the current time
+pub fn parse_constraint_relative_to(
+    input: impl AsRef<str>,
+    default: NaiveDateTime,
+) -> Result<Constraint, Error> {
+    parse_constraint_with_options(input, &ParseOptions::new().relative_to(default))
+}
+
+/// Parse an input string describing an open-ended bound on a datetime
+/// relative to now
+#[cfg(feature = "clock")]
+pub fn parse_constraint(input: impl AsRef<str>) -> Result<Constraint, Error> {
+    parse_constraint_with_options(input, &ParseOptions::new())
+}
+
+#[test]
+fn test_parse_constraint() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+
+    for (input, bound, datetime, inclusive) in [
+        ("after 5pm", Bound::Lower, "2024-06-01 17:00:00", false),
+        (
+            "after 5pm tomorrow",
+            Bound::Lower,
+            "2024-06-02 17:00:00",
+            false,
+        ),
+        ("before noon", Bound::Upper, "2024-06-01 12:00:00", false),
+        (
+            "before friday at 9am",
+            Bound::Upper,
+            "2024-06-07 09:00:00",
+            false,
+        ),
+        (
+            "no later than June 3 2024 at 8:30",
+            Bound::Upper,
+            "2024-06-03 08:30:00",
+            true,
+        ),
+        (
+            "no earlier than 3 days from now",
+            Bound::Lower,
+            "2024-06-04 12:00:00",
+            true,
+        ),
+        (
+            "not before monday at 10am",
+            Bound::Lower,
+            "2024-06-03 10:00:00",
+            true,
+        ),
+    ] {
+        let constraint = parse_constraint_relative_to(input, now).unwrap();
+        assert_eq!(constraint.bound, bound, "{input}");
+        assert_eq!(constraint.datetime.to_string(), datetime, "{input}");
+        assert_eq!(constraint.inclusive, inclusive, "{input}");
+        assert_eq!(
+            constraint.contains(constraint.datetime),
+            inclusive,
+            "{input}"
+        );
+    }
+
+    // A date without a time bounds the whole day rather than the current
+    // time on it
+    for (input, bound, datetime, inclusive) in [
+        ("after friday", Bound::Lower, "2024-06-08 00:00:00", true),
+        ("before friday", Bound::Upper, "2024-06-07 00:00:00", false),
+        (
+            "no earlier than monday",
+            Bound::Lower,
+            "2024-06-03 00:00:00",
+            true,
+        ),
+        (
+            "after 2 days after monday",
+            Bound::Lower,
+            "2024-06-06 00:00:00",
+            true,
+        ),
+    ] {
+        let constraint = parse_constraint_relative_to(input, now).unwrap();
+        assert_eq!(constraint.bound, bound, "{input}");
+        assert_eq!(constraint.datetime.to_string(), datetime, "{input}");
+        assert_eq!(constraint.inclusive, inclusive, "{input}");
+    }
+
+    let constraint = parse_constraint_relative_to("after friday", now).unwrap();
+    let late_friday =
+        NaiveDateTime::parse_from_str("2024-06-07 23:59:59.5", "%Y-%m-%d %H:%M:%S%.f");
+    assert!(!constraint.contains(late_friday.unwrap()));
+
+    // A datetime on its own isn't a bound
+    for input in ["tomorrow at 5pm", "after", "3 days after tomorrow"] {
+        assert!(parse_constraint_relative_to(input, now).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn test_deadline() {
+    let now = NaiveDateTime::parse_from_str("2024-06-20 12:00", "%Y-%m-%d %H:%M").unwrap();
+
+    // A deadline without a time allows its whole day, up to the start of
+    // the next
+    for (input, datetime, inclusive) in [
+        ("by friday", "2024-06-22 00:00:00", false),
+        ("by friday at 5pm", "2024-06-21 17:00:00", true),
+        ("until the 15th", "2024-07-16 00:00:00", false),
+        ("by the 20th", "2024-06-21 00:00:00", false),
+        ("no later than the 31st", "2024-08-01 00:00:00", false),
+        ("until noon", "2024-06-20 12:00:00", true),
+        ("by 3rd of July 2024", "2024-07-04 00:00:00", false),
+    ] {
+        let constraint = parse_constraint_relative_to(input, now).unwrap();
+        assert_eq!(constraint.bound, Bound::Upper, "{input}");
+        assert_eq!(constraint.inclusive, inclusive, "{input}");
+        assert_eq!(constraint.datetime.to_string(), datetime, "{input}");
+    }
+
+    let constraint = parse_constraint_relative_to("by friday", now).unwrap();
+    let late_friday =
+        NaiveDateTime::parse_from_str("2024-06-21 23:59:59.5", "%Y-%m-%d %H:%M:%S%.f");
+    assert!(constraint.contains(late_friday.unwrap()));
+
+    let options = ParseOptions::new()
+        .relative_to(now)
+        .deadline_time(chrono::NaiveTime::from_hms_opt(17, 0, 0).unwrap());
+    let constraint = parse_constraint_with_options("by tomorrow", &options).unwrap();
+    assert_eq!(constraint.datetime.to_string(), "2024-06-21 17:00:00");
+    assert!(constraint.inclusive);
+
+    // Other bounds take in the whole day
+    let constraint = parse_constraint_with_options("after tomorrow", &options).unwrap();
+    assert_eq!(constraint.datetime.to_string(), "2024-06-22 00:00:00");
+}
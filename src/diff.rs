@@ -0,0 +1,172 @@
+//! Differences between datetimes, in calendar units or until a deadline
+
+use alloc::boxed::Box;
+use core::fmt;
+
+use chrono::{Datelike, Duration as ChronoDuration, Months, NaiveDateTime};
+
+use crate::ast::{Duration, Unit};
+use crate::{Error, ParseOptions};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The difference between two datetimes in calendar units, e.g. "1 month
+/// and 3 days", as produced by [`crate::between`]
+pub struct CalendarDuration {
+    /// Whether the second datetime is before the first
+    pub negative: bool,
+    /// The length of the difference in its largest whole units, down to
+    /// minutes
+    pub duration: Duration,
+}
+
+impl CalendarDuration {
+    /// The difference from `start` to `end`. Whole months are counted on
+    /// the calendar from the earlier datetime, so from January 31st to
+    /// March 1st is 1 month and 1 day.
+    pub fn between(start: NaiveDateTime, end: NaiveDateTime) -> Self {
+        let negative = end < start;
+        let (start, end) = if negative { (end, start) } else { (start, end) };
+
+        let mut months =
+            (end.year() - start.year()) * 12 + end.month() as i32 - start.month() as i32;
+        let add_months = |months: i32| start.checked_add_months(Months::new(months as u32));
+        while months > 0 && add_months(months).is_none_or(|date| date > end) {
+            months -= 1;
+        }
+
+        let rest = end - add_months(months).unwrap_or(start);
+        let minutes = u32::try_from(rest.num_minutes()).unwrap_or(u32::MAX);
+        let duration = Duration::Concat(
+            Box::new(Duration::Specific(months as u32, Unit::Month)),
+            Box::new(Duration::Specific(minutes, Unit::Minute)),
+        );
+
+        Self {
+            negative,
+            // Less than a month of minutes is left over after whole months
+            duration: duration
+                .normalize()
+                .expect("a difference between datetimes is in range"),
+        }
+    }
+}
+
+impl fmt::Display for CalendarDuration {
+    /// Writes the length of the difference, preceded by a minus sign if it
+    /// is negative, e.g. "-1 month and 3 days"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        write!(f, "{}", self.duration)
+    }
+}
+
+/// The difference between two inputs, interpreting both according to the
+/// given options and relative to the same current time
+pub fn between_with_options(
+    a: impl AsRef<str>,
+    b: impl AsRef<str>,
+    options: &ParseOptions,
+) -> Result<CalendarDuration, Error> {
+    let options = options.clone().relative_to(options.now()?);
+    let a = crate::parse_with_options(a, &options)?;
+    let b = crate::parse_with_options(b, &options)?;
+
+    Ok(CalendarDuration::between(a, b))
+}
+
+/// The difference between two inputs, both relative to `reference`
+///
+/// ```rust
+/// use chrono::NaiveDateTime;
+///
+/// let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+/// let diff = fuzzydate::between("today", "1 month and 3 days after today", now).unwrap();
+/// assert_eq!(diff.to_string(), "1 month and 3 days");
+/// ```
+pub fn between(
+    a: impl AsRef<str>,
+    b: impl AsRef<str>,
+    reference: NaiveDateTime,
+) -> Result<CalendarDuration, Error> {
+    between_with_options(a, b, &ParseOptions::new().relative_to(reference))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The time left until a datetime, as produced by [`crate::time_until`]
+pub struct Countdown {
+    /// The time from the reference to the datetime, negative if the
+    /// datetime has passed
+    pub duration: ChronoDuration,
+    /// Whether the datetime is before the reference
+    pub is_past: bool,
+}
+
+/// The time from the current time to an input, interpreting it according
+/// to the given options
+pub fn time_until_with_options(
+    input: impl AsRef<str>,
+    options: &ParseOptions,
+) -> Result<Countdown, Error> {
+    let now = options.now()?;
+    let options = options.clone().relative_to(now);
+    let duration = crate::parse_with_options(input, &options)? - now;
+
+    Ok(Countdown {
+        duration,
+        is_past: duration < ChronoDuration::zero(),
+    })
+}
+
+/// The time from `reference` to an input
+///
+/// ```rust
+/// use chrono::NaiveDateTime;
+///
+/// let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+/// let countdown = fuzzydate::time_until("tomorrow at 5pm", now).unwrap();
+/// assert_eq!(countdown.duration.num_hours(), 29);
+/// assert!(!countdown.is_past);
+/// ```
+pub fn time_until(input: impl AsRef<str>, reference: NaiveDateTime) -> Result<Countdown, Error> {
+    time_until_with_options(input, &ParseOptions::new().relative_to(reference))
+}
+
+#[test]
+fn test_between() {
+    let date = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+
+    for (start, end, expected) in [
+        ("2024-01-31 00:00", "2024-03-01 00:00", "1 month and 1 day"),
+        ("2024-01-31 00:00", "2024-02-29 00:00", "1 month"),
+        (
+            "2024-06-01 12:00",
+            "2025-07-15 13:30",
+            "1 year, 1 month, 2 weeks, 1 hour and 30 minutes",
+        ),
+        ("2024-06-01 12:00", "2024-06-01 12:00", "0 minutes"),
+        ("2024-06-01 12:00", "2024-05-31 11:00", "-1 day and 1 hour"),
+    ] {
+        let diff = CalendarDuration::between(date(start), date(end));
+        assert_eq!(diff.to_string(), expected, "{start} to {end}");
+    }
+
+    let now = date("2024-06-01 12:00");
+    let diff = between("3 days after today", "yesterday", now).unwrap();
+    assert!(diff.negative);
+    assert_eq!(diff.duration.to_string(), "4 days");
+}
+
+#[test]
+fn test_time_until() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+
+    let countdown = time_until("2 hours ago", now).unwrap();
+    assert_eq!(countdown.duration, ChronoDuration::hours(-2));
+    assert!(countdown.is_past);
+
+    let countdown = time_until("now", now).unwrap();
+    assert_eq!(countdown.duration, ChronoDuration::zero());
+    assert!(!countdown.is_past);
+}
@@ -0,0 +1,185 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+/// The ways parsing can fail
+///
+/// New variants, and new fields of the payloads, may be added without a
+/// breaking change, so match with a wildcard arm.
+pub enum Error {
+    #[error("Invalid date: {0}")]
+    /// The date is invalid,
+    /// e.g. `"31st of February"`, `"December 32nd"`, `"32/13/2019"`
+    InvalidDate(InvalidDate),
+    #[error("Unrecognized Token while lexing: {0}")]
+    /// The lexer found a token that it doesn't recognize
+    UnrecognizedToken(UnrecognizedToken),
+    #[error("Unable to parse date")]
+    /// The date _may_ be valid, but the parser was unable to parse it,
+    /// e.g. `"tomorrow at at 5pm"`
+    ParseError(ParseError),
+    #[error("Ambiguous input: {0}")]
+    /// The input has more than one reading and strict parsing is enabled,
+    /// see [`crate::ParseOptions::strict`]
+    Ambiguous(Ambiguous),
+    #[error("No reference datetime")]
+    /// The input is relative to the current time, which is unavailable
+    /// without the `clock` feature, and no reference datetime was given
+    MissingReference,
+    #[cfg(feature = "testing")]
+    #[error("Invalid snapshot: {0}")]
+    /// A snapshot read by [`crate::testing::Corpus::from_snapshot`] has a
+    /// malformed line
+    InvalidSnapshot(InvalidSnapshot),
+}
+
+impl Error {
+    pub(crate) fn invalid_date(reason: impl Into<String>) -> Self {
+        Self::InvalidDate(InvalidDate {
+            reason: reason.into(),
+            span: None,
+        })
+    }
+
+    pub(crate) fn invalid_date_at(reason: impl Into<String>, span: Range<usize>) -> Self {
+        Self::InvalidDate(InvalidDate {
+            reason: reason.into(),
+            span: Some(span),
+        })
+    }
+
+    pub(crate) fn ambiguous(reason: impl Into<String>) -> Self {
+        Self::Ambiguous(Ambiguous {
+            reason: reason.into(),
+        })
+    }
+
+    #[cfg(feature = "testing")]
+    pub(crate) fn invalid_snapshot(line: usize, reason: impl Into<String>) -> Self {
+        Self::InvalidSnapshot(InvalidSnapshot {
+            line,
+            reason: reason.into(),
+        })
+    }
+
+    pub(crate) fn parse_error() -> Self {
+        Self::ParseError(ParseError::default())
+    }
+
+    /// A parse error at the furthest lexeme any rule was tried at, with the
+    /// rules tried there as what was expected, where that is known. The
+    /// span is empty at `end` when the parser ran out of input.
+    pub(crate) fn parse_error_at(spans: &[Range<usize>], end: usize) -> Self {
+        let Some((left, rules)) = crate::memo::furthest() else {
+            return Self::parse_error();
+        };
+        let Some(index) = spans.len().checked_sub(left) else {
+            return Self::parse_error();
+        };
+
+        Self::ParseError(ParseError {
+            span: Some(spans.get(index).cloned().unwrap_or(end..end)),
+            expected: rules
+                .into_iter()
+                .map(crate::memo::Rule::description)
+                .collect(),
+        })
+    }
+
+    /// A parse error at the first lexeme left over after a complete
+    /// expression of `tokens` lexemes, which the minimal grammar rejects
+    pub(crate) fn trailing_tokens(spans: &[Range<usize>], tokens: usize) -> Self {
+        Self::ParseError(ParseError {
+            span: spans.get(tokens).cloned(),
+            expected: vec!["the end of the input"],
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// Details of an [`Error::InvalidDate`]
+pub struct InvalidDate {
+    /// Why the date is invalid
+    pub reason: String,
+    /// The byte range of the input which is invalid, where it is known
+    pub span: Option<Range<usize>>,
+}
+
+impl fmt::Display for InvalidDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.reason)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// Details of an [`Error::Ambiguous`]
+pub struct Ambiguous {
+    /// Which part of the input has more than one reading
+    pub reason: String,
+}
+
+impl fmt::Display for Ambiguous {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.reason)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// Details of an [`Error::UnrecognizedToken`]
+pub struct UnrecognizedToken {
+    /// The token as it was written in the input
+    pub token: String,
+    /// The byte range of the token in the input
+    pub span: Range<usize>,
+    /// A known word the token may be a misspelling of
+    pub suggestion: Option<String>,
+}
+
+impl fmt::Display for UnrecognizedToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\"{}\" at {}..{}",
+            self.token, self.span.start, self.span.end
+        )?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, ", did you mean \"{suggestion}\"?")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+/// Details of an [`Error::ParseError`], where they are known
+pub struct ParseError {
+    /// The byte range of the input where parsing failed
+    pub span: Option<Range<usize>>,
+    /// Descriptions of what could have been parsed instead
+    pub expected: Vec<&'static str>,
+}
+
+#[cfg(feature = "testing")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// Details of an [`Error::InvalidSnapshot`]
+pub struct InvalidSnapshot {
+    /// The number of the malformed line, starting at 1
+    pub line: usize,
+    /// What is wrong with the line
+    pub reason: String,
+}
+
+#[cfg(feature = "testing")]
+impl fmt::Display for InvalidSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
@@ -0,0 +1,291 @@
+//! Formatting datetimes as phrases the parser accepts
+//!
+//! The phrases are English and are read back by [`crate::parse`] with the
+//! default options as the same datetime, up to the chosen granularity.
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use chrono::{Duration as ChronoDuration, NaiveDateTime, Timelike};
+
+use crate::ast::{Duration, Unit};
+use crate::CalendarDuration;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// The smallest unit a datetime is described in, finer details are dropped
+pub enum Granularity {
+    /// Only the day, e.g. "3 days ago"
+    Day,
+    /// The day and hour, e.g. "tomorrow at 5 pm"
+    Hour,
+    /// The day, hour and minute, e.g. "tomorrow at 5:30 pm"
+    #[default]
+    Minute,
+}
+
+/// Describe a datetime relative to `now` as a phrase which parses back to
+/// it, e.g. "tomorrow at 5 pm" or "2 hours and 5 minutes ago"
+///
+/// ```rust
+/// use chrono::NaiveDateTime;
+/// use fuzzydate::{humanize, Granularity};
+///
+/// let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+/// let date = NaiveDateTime::parse_from_str("2024-06-02 17:30", "%Y-%m-%d %H:%M").unwrap();
+///
+/// let phrase = humanize(date, now, Granularity::Minute);
+/// assert_eq!(phrase, "tomorrow at 5:30 pm");
+/// assert_eq!(fuzzydate::parse_relative_to(&phrase, now).unwrap(), date);
+/// ```
+pub fn humanize(date: NaiveDateTime, now: NaiveDateTime, granularity: Granularity) -> String {
+    let days = (date.date() - now.date()).num_days();
+
+    if granularity == Granularity::Day {
+        return match days {
+            0 => "today".to_string(),
+            1 => "tomorrow".to_string(),
+            -1 => "yesterday".to_string(),
+            2..=6 => format!("in {}", Duration::Specific(days as u32, Unit::Day)),
+            -6..=-2 => format!("{} ago", Duration::Specific(-days as u32, Unit::Day)),
+            _ => date.format("%B %-d %Y").to_string(),
+        };
+    }
+
+    // Within a day of now the offset is given instead of the time
+    let offset = date - now;
+    if offset.abs() < ChronoDuration::days(1) {
+        let hours = offset.num_hours().unsigned_abs() as u32;
+        let minutes = match granularity {
+            Granularity::Minute => (offset.num_minutes().unsigned_abs() % 60) as u32,
+            _ => 0,
+        };
+
+        let duration = match (hours, minutes) {
+            (0, 0) => return "now".to_string(),
+            (_, 0) => Duration::Specific(hours, Unit::Hour),
+            (0, _) => Duration::Specific(minutes, Unit::Minute),
+            _ => Duration::Concat(
+                Box::new(Duration::Specific(hours, Unit::Hour)),
+                Box::new(Duration::Specific(minutes, Unit::Minute)),
+            ),
+        };
+
+        return if offset < ChronoDuration::zero() {
+            format!("{duration} ago")
+        } else {
+            format!("in {duration}")
+        };
+    }
+
+    let day = match days {
+        0 => "today".to_string(),
+        1 => "tomorrow".to_string(),
+        -1 => "yesterday".to_string(),
+        _ => date.format("%B %-d %Y").to_string(),
+    };
+
+    let minute = match granularity {
+        Granularity::Minute => date.minute(),
+        _ => 0,
+    };
+
+    // Twelve o'clock has no reading with am or pm
+    let time = match (date.hour(), minute) {
+        (0, 0) => "midnight".to_string(),
+        (12, 0) => "noon".to_string(),
+        (0 | 12, _) => format!("{}:{minute:02}", date.hour()),
+        (hour, 0) => format!("{} {}", hour % 12, if hour < 12 { "am" } else { "pm" }),
+        (hour, _) => format!(
+            "{}:{minute:02} {}",
+            hour % 12,
+            if hour < 12 { "am" } else { "pm" }
+        ),
+    };
+
+    format!("{day} at {time}")
+}
+
+/// Describe a datetime only by its offset from `now`, however far away it
+/// is, e.g. "in 3 days and 5 hours" or "2 months and 1 week ago". The
+/// offset is given in its two largest units down to the granularity, so it
+/// reads back to the datetime rounded towards `now`.
+///
+/// ```rust
+/// use chrono::NaiveDateTime;
+/// use fuzzydate::{humanize_relative, Granularity};
+///
+/// let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+/// let date = NaiveDateTime::parse_from_str("2024-06-04 17:30", "%Y-%m-%d %H:%M").unwrap();
+///
+/// assert_eq!(humanize_relative(date, now, Granularity::Minute), "in 3 days and 5 hours");
+/// assert_eq!(humanize_relative(date, now, Granularity::Day), "in 3 days");
+/// ```
+pub fn humanize_relative(
+    date: NaiveDateTime,
+    now: NaiveDateTime,
+    granularity: Granularity,
+) -> String {
+    fn flatten(duration: &Duration, parts: &mut Vec<(u32, Unit)>) {
+        match duration {
+            Duration::Concat(first, second) => {
+                flatten(first, parts);
+                flatten(second, parts);
+            }
+            Duration::Specific(num, unit) => parts.push((*num, *unit)),
+            _ => {}
+        }
+    }
+
+    let diff = CalendarDuration::between(now, date);
+    let mut parts = Vec::new();
+    flatten(&diff.duration, &mut parts);
+
+    let duration = parts
+        .into_iter()
+        .filter(|&(num, unit)| {
+            num > 0
+                && match unit {
+                    Unit::Hour => granularity >= Granularity::Hour,
+                    Unit::Minute => granularity >= Granularity::Minute,
+                    _ => true,
+                }
+        })
+        .take(2)
+        .map(|(num, unit)| Duration::Specific(num, unit))
+        .reduce(|first, second| Duration::Concat(Box::new(first), Box::new(second)));
+
+    match (duration, diff.negative) {
+        (None, _) if granularity == Granularity::Day => "today".to_string(),
+        (None, _) => "now".to_string(),
+        (Some(duration), false) => format!("in {duration}"),
+        (Some(duration), true) => format!("{duration} ago"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn datetime(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    #[test]
+    fn test_humanize() {
+        let now = datetime("2024-06-01 12:00");
+
+        for (date, granularity, phrase) in [
+            ("2024-06-01 12:00", Granularity::Minute, "now"),
+            (
+                "2024-06-01 14:05",
+                Granularity::Minute,
+                "in 2 hours and 5 minutes",
+            ),
+            ("2024-06-01 11:15", Granularity::Minute, "45 minutes ago"),
+            ("2024-06-01 09:59", Granularity::Hour, "2 hours ago"),
+            (
+                "2024-06-02 17:30",
+                Granularity::Minute,
+                "tomorrow at 5:30 pm",
+            ),
+            ("2024-06-02 17:30", Granularity::Hour, "tomorrow at 5 pm"),
+            (
+                "2024-05-31 00:00",
+                Granularity::Minute,
+                "yesterday at midnight",
+            ),
+            ("2024-06-02 12:00", Granularity::Minute, "tomorrow at noon"),
+            ("2024-06-02 12:30", Granularity::Minute, "tomorrow at 12:30"),
+            (
+                "2024-07-04 09:00",
+                Granularity::Minute,
+                "July 4 2024 at 9 am",
+            ),
+            ("2024-05-29 09:00", Granularity::Day, "3 days ago"),
+            ("2024-06-03 09:00", Granularity::Day, "in 2 days"),
+            ("2024-06-01 23:00", Granularity::Day, "today"),
+            ("2023-12-25 09:00", Granularity::Day, "December 25 2023"),
+        ] {
+            assert_eq!(humanize(datetime(date), now, granularity), phrase);
+        }
+    }
+
+    #[test]
+    fn test_humanize_round_trip() {
+        let now = datetime("2024-06-01 12:00");
+
+        let start = NaiveDate::from_ymd_opt(2024, 5, 20)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let dates = (0..24 * 4 * 25).map(|i| start + ChronoDuration::minutes(i * 15 + 7));
+
+        for date in dates {
+            for granularity in [Granularity::Day, Granularity::Hour, Granularity::Minute] {
+                let phrase = humanize(date, now, granularity);
+                let parsed = crate::parse_relative_to(&phrase, now).unwrap();
+
+                match granularity {
+                    Granularity::Day => assert_eq!(parsed.date(), date.date(), "{phrase}"),
+                    // Minutes are dropped
+                    Granularity::Hour => {
+                        let error = (parsed - date).num_minutes().abs();
+                        assert!(error < 60, "{phrase}: {parsed} for {date}");
+                    }
+                    Granularity::Minute => assert_eq!(parsed, date, "{phrase}"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_humanize_relative() {
+        let now = datetime("2024-06-01 12:00");
+
+        for (date, granularity, phrase) in [
+            ("2024-06-01 12:00", Granularity::Minute, "now"),
+            ("2024-06-01 23:00", Granularity::Day, "today"),
+            (
+                "2024-06-01 14:05",
+                Granularity::Minute,
+                "in 2 hours and 5 minutes",
+            ),
+            ("2024-06-01 09:59", Granularity::Hour, "2 hours ago"),
+            ("2024-06-04 12:00", Granularity::Minute, "in 3 days"),
+            (
+                "2024-06-04 17:30",
+                Granularity::Minute,
+                "in 3 days and 5 hours",
+            ),
+            ("2024-05-29 09:00", Granularity::Day, "3 days ago"),
+            (
+                "2024-06-12 12:00",
+                Granularity::Minute,
+                "in 1 week and 4 days",
+            ),
+            (
+                "2024-08-15 08:00",
+                Granularity::Hour,
+                "in 2 months and 1 week",
+            ),
+            (
+                "2023-03-01 12:00",
+                Granularity::Minute,
+                "1 year and 3 months ago",
+            ),
+        ] {
+            let date = datetime(date);
+            let relative = humanize_relative(date, now, granularity);
+            assert_eq!(relative, phrase);
+
+            // The phrase is read back as a time between now and the date
+            let parsed = crate::parse_relative_to(&relative, now).unwrap();
+            assert!(
+                now.min(date) <= parsed && parsed <= now.max(date),
+                "{relative}"
+            );
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Parsing into the types of the `jiff` crate, enabled by the `jiff` feature
+//!
+//! Input is lexed, parsed, and evaluated the same way as for chrono, and
+//! only the reference and the result are converted. Unlike [`crate::aware_parse`],
+//! local times which don't exist in the time zone are not an error: they
+//! are resolved the way `jiff` resolves them, by moving forward across the
+//! gap of a DST transition.
+
+use ::jiff::civil;
+use ::jiff::{Timestamp, Zoned};
+use alloc::format;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+
+use crate::{Error, ParseOptions};
+
+/// Convert a chrono datetime to a civil `jiff` datetime
+fn from_chrono(date: NaiveDateTime) -> Result<civil::DateTime, Error> {
+    let invalid = || Error::invalid_date(format!("Out of range: {date}"));
+
+    civil::DateTime::new(
+        i16::try_from(date.year()).map_err(|_| invalid())?,
+        date.month() as i8,
+        date.day() as i8,
+        date.hour() as i8,
+        date.minute() as i8,
+        date.second() as i8,
+        date.nanosecond() as i32,
+    )
+    .map_err(|_| invalid())
+}
+
+/// Convert a civil `jiff` datetime to a chrono datetime
+fn to_chrono(date: civil::DateTime) -> Result<NaiveDateTime, Error> {
+    NaiveDate::from_ymd_opt(date.year().into(), date.month() as u32, date.day() as u32)
+        .and_then(|day| {
+            day.and_hms_nano_opt(
+                date.hour() as u32,
+                date.minute() as u32,
+                date.second() as u32,
+                date.subsec_nanosecond() as u32,
+            )
+        })
+        .ok_or(Error::invalid_date(format!("Out of range: {date}")))
+}
+
+/// Parse an input string into a civil `jiff` datetime, interpreting it
+/// according to the given options
+pub fn parse_jiff_with_options(
+    input: impl AsRef<str>,
+    options: &ParseOptions,
+) -> Result<civil::DateTime, Error> {
+    from_chrono(crate::parse_with_options(input, options)?)
+}
+
+/// Parse an input string into a civil `jiff` datetime relative to now
+#[cfg(feature = "clock")]
+pub fn parse_jiff(input: impl AsRef<str>) -> Result<civil::DateTime, Error> {
+    parse_jiff_with_options(input, &ParseOptions::new())
+}
+
+/// Parse an input string into a zoned `jiff` datetime, relative to `now`
+/// and in its time zone, interpreting it according to the given options.
+/// The `relative_to` option is ignored in favour of `now`.
+pub fn aware_parse_jiff_with_options(
+    input: impl AsRef<str>,
+    now: &Zoned,
+    options: &ParseOptions,
+) -> Result<Zoned, Error> {
+    let tz = now.time_zone();
+    let mut options = options.clone().relative_to(to_chrono(now.datetime())?);
+    options.epoch = Some(to_chrono(
+        Timestamp::UNIX_EPOCH.to_zoned(tz.clone()).datetime(),
+    )?);
+
+    let date = from_chrono(crate::parse_with_options(input, &options)?)?;
+    date.to_zoned(tz.clone())
+        .map_err(|_| Error::invalid_date(format!("Out of range: {date}")))
+}
+
+/// Parse an input string into a zoned `jiff` datetime, relative to `now`
+/// and in its time zone
+///
+/// ```rust
+/// use jiff::civil::date;
+/// use jiff::tz::TimeZone;
+///
+/// let now = date(2024, 6, 1).at(12, 0, 0, 0).to_zoned(TimeZone::UTC).unwrap();
+/// let date = fuzzydate::aware_parse_jiff("tomorrow at 5pm", &now).unwrap();
+/// assert_eq!(date.datetime(), jiff::civil::date(2024, 6, 2).at(17, 0, 0, 0));
+/// ```
+pub fn aware_parse_jiff(input: impl AsRef<str>, now: &Zoned) -> Result<Zoned, Error> {
+    aware_parse_jiff_with_options(input, now, &ParseOptions::new())
+}
+
+#[test]
+fn test_conversion_round_trip() {
+    let date = NaiveDate::from_ymd_opt(2024, 2, 29)
+        .unwrap()
+        .and_hms_nano_opt(23, 59, 58, 123_456_789)
+        .unwrap();
+    let converted = from_chrono(date).unwrap();
+
+    assert_eq!(converted.subsec_nanosecond(), 123_456_789);
+    assert_eq!(to_chrono(converted).unwrap(), date);
+}
+
+#[test]
+fn test_aware_parse_jiff_across_dst() {
+    let now: Zoned = "2024-03-09T12:00[America/New_York]".parse().unwrap();
+
+    // 2:30am doesn't exist on the 10th, clocks jump from 2am to 3am
+    let date = aware_parse_jiff("tomorrow at 2:30am", &now).unwrap();
+    assert_eq!(
+        date.to_string(),
+        "2024-03-10T03:30:00-04:00[America/New_York]"
+    );
+
+    let date = aware_parse_jiff("3 days after tomorrow at 5pm", &now).unwrap();
+    assert_eq!(
+        date.to_string(),
+        "2024-03-13T17:00:00-04:00[America/New_York]"
+    );
+}
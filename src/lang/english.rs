@@ -0,0 +1,299 @@
+use alloc::collections::BTreeMap;
+use lazy_static::lazy_static;
+
+use super::Language;
+use crate::lexer::Lexeme;
+
+lazy_static! {
+    /// Words which aren't month, weekday, or number names
+    static ref KEYWORDS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("an", Lexeme::An);
+        map.insert("after", Lexeme::After);
+        map.insert("last", Lexeme::Last);
+        map.insert("previous", Lexeme::Last);
+        map.insert("preceding", Lexeme::Last);
+        map.insert("this", Lexeme::This);
+        map.insert("next", Lexeme::Next);
+        map.insert("following", Lexeme::Next);
+        map.insert("am", Lexeme::AM);
+        map.insert("pm", Lexeme::PM);
+        map.insert("day", Lexeme::Day);
+        map.insert("days", Lexeme::Day);
+        map.insert("week", Lexeme::Week);
+        map.insert("weeks", Lexeme::Week);
+        map.insert("month", Lexeme::Month);
+        map.insert("months", Lexeme::Month);
+        map.insert("year", Lexeme::Year);
+        map.insert("years", Lexeme::Year);
+        map.insert("hour", Lexeme::Hour);
+        map.insert("hours", Lexeme::Hour);
+        map.insert("min", Lexeme::Minute);
+        map.insert("mins", Lexeme::Minute);
+        map.insert("minute", Lexeme::Minute);
+        map.insert("minutes", Lexeme::Minute);
+        map.insert("and", Lexeme::And);
+        map.insert("couple", Lexeme::Couple);
+        map.insert("few", Lexeme::Few);
+        map.insert("several", Lexeme::Several);
+        map.insert("half", Lexeme::Half);
+        map.insert("quarter", Lexeme::Quarter);
+        map.insert("past", Lexeme::Past);
+        map.insert("today", Lexeme::Today);
+        map.insert("tomorrow", Lexeme::Tomorrow);
+        map.insert("yesterday", Lexeme::Yesterday);
+        map.insert("now", Lexeme::Now);
+        map.insert("asap", Lexeme::Now);
+        map.insert("immediately", Lexeme::Now);
+        map.insert("from", Lexeme::From);
+        map.insert("before", Lexeme::Before);
+        map.insert("ago", Lexeme::Ago);
+        map.insert("midnight", Lexeme::Midnight);
+        map.insert("noon", Lexeme::Noon);
+        map.insert("morning", Lexeme::Morning);
+        map.insert("afternoon", Lexeme::Afternoon);
+        map.insert("evening", Lexeme::Evening);
+        map.insert("night", Lexeme::Night);
+        map.insert("tonight", Lexeme::Tonight);
+        map.insert("a", Lexeme::A);
+        map.insert("the", Lexeme::The);
+        map.insert("at", Lexeme::At);
+        map.insert("in", Lexeme::In);
+        map.insert("o'clock", Lexeme::OClock);
+        map.insert("epoch", Lexeme::Epoch);
+        map.insert("start", Lexeme::Start);
+        map.insert("beginning", Lexeme::Start);
+        map.insert("end", Lexeme::End);
+        map.insert("of", Lexeme::Of);
+        map.insert("on", Lexeme::On);
+        map.insert("leap", Lexeme::Leap);
+        map.insert("to", Lexeme::To);
+        map.insert("through", Lexeme::Through);
+        map.insert("thru", Lexeme::Through);
+        map.insert("by", Lexeme::By);
+        map.insert("since", Lexeme::Since);
+        map.insert("until", Lexeme::Until);
+        map.insert("till", Lexeme::Until);
+        map.insert("between", Lexeme::Between);
+        map.insert("random", Lexeme::Random);
+        map.insert("every", Lexeme::Every);
+        map.insert("daily", Lexeme::Daily);
+        map.insert("weekly", Lexeme::Weekly);
+        map.insert("monthly", Lexeme::Monthly);
+        map.insert("yearly", Lexeme::Yearly);
+        map.insert("annually", Lexeme::Yearly);
+
+        map
+    };
+
+    /// Month names
+    static ref MONTHS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("january", Lexeme::January);
+        map.insert("february", Lexeme::February);
+        map.insert("march", Lexeme::March);
+        map.insert("april", Lexeme::April);
+        map.insert("may", Lexeme::May);
+        map.insert("june", Lexeme::June);
+        map.insert("july", Lexeme::July);
+        map.insert("august", Lexeme::August);
+        map.insert("september", Lexeme::September);
+        map.insert("october", Lexeme::October);
+        map.insert("november", Lexeme::November);
+        map.insert("december", Lexeme::December);
+
+        map
+    };
+
+    /// Weekday names
+    static ref WEEKDAYS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("monday", Lexeme::Monday);
+        map.insert("tuesday", Lexeme::Tuesday);
+        map.insert("wednesday", Lexeme::Wednesday);
+        map.insert("thursday", Lexeme::Thursday);
+        map.insert("friday", Lexeme::Friday);
+        map.insert("saturday", Lexeme::Saturday);
+        map.insert("sunday", Lexeme::Sunday);
+        map.insert("mondays", Lexeme::Monday);
+        map.insert("tuesdays", Lexeme::Tuesday);
+        map.insert("wednesdays", Lexeme::Wednesday);
+        map.insert("thursdays", Lexeme::Thursday);
+        map.insert("fridays", Lexeme::Friday);
+        map.insert("saturdays", Lexeme::Saturday);
+        map.insert("sundays", Lexeme::Sunday);
+
+        map
+    };
+
+    /// Abbreviated month and weekday names, which may be followed by a
+    /// period, e.g. "oct."
+    static ref ABBREVIATIONS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("jan", Lexeme::January);
+        map.insert("feb", Lexeme::February);
+        map.insert("mar", Lexeme::March);
+        map.insert("apr", Lexeme::April);
+        map.insert("jun", Lexeme::June);
+        map.insert("jul", Lexeme::July);
+        map.insert("aug", Lexeme::August);
+        map.insert("sep", Lexeme::September);
+        map.insert("sept", Lexeme::September);
+        map.insert("oct", Lexeme::October);
+        map.insert("nov", Lexeme::November);
+        map.insert("dec", Lexeme::December);
+
+        map.insert("mon", Lexeme::Monday);
+        map.insert("tue", Lexeme::Tuesday);
+        map.insert("tues", Lexeme::Tuesday);
+        map.insert("wed", Lexeme::Wednesday);
+        map.insert("weds", Lexeme::Wednesday);
+        map.insert("thu", Lexeme::Thursday);
+        map.insert("thur", Lexeme::Thursday);
+        map.insert("thurs", Lexeme::Thursday);
+        map.insert("fri", Lexeme::Friday);
+        // "sat" and "sun" are always weekdays, as no other word of the
+        // grammar reads the same
+        map.insert("sat", Lexeme::Saturday);
+        map.insert("sun", Lexeme::Sunday);
+
+        map
+    };
+
+    /// Number words
+    static ref NUMBERS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("zero", Lexeme::Zero);
+        map.insert("one", Lexeme::One);
+        map.insert("two", Lexeme::Two);
+        map.insert("three", Lexeme::Three);
+        map.insert("four", Lexeme::Four);
+        map.insert("five", Lexeme::Five);
+        map.insert("six", Lexeme::Six);
+        map.insert("seven", Lexeme::Seven);
+        map.insert("eight", Lexeme::Eight);
+        map.insert("nine", Lexeme::Nine);
+        map.insert("ten", Lexeme::Ten);
+        map.insert("eleven", Lexeme::Eleven);
+        map.insert("twelve", Lexeme::Twelve);
+        map.insert("thirteen", Lexeme::Thirteen);
+        map.insert("fourteen", Lexeme::Fourteen);
+        map.insert("fifteen", Lexeme::Fifteen);
+        map.insert("sixteen", Lexeme::Sixteen);
+        map.insert("seventeen", Lexeme::Seventeen);
+        map.insert("eighteen", Lexeme::Eighteen);
+        map.insert("nineteen", Lexeme::Nineteen);
+        map.insert("twenty", Lexeme::Twenty);
+        map.insert("thirty", Lexeme::Thirty);
+        map.insert("fourty", Lexeme::Fourty);
+        map.insert("fifty", Lexeme::Fifty);
+        map.insert("sixty", Lexeme::Sixty);
+        map.insert("seventy", Lexeme::Seventy);
+        map.insert("eighty", Lexeme::Eighty);
+        map.insert("ninety", Lexeme::Ninety);
+        map.insert("hundred", Lexeme::Hundred);
+        map.insert("thousand", Lexeme::Thousand);
+        map.insert("million", Lexeme::Million);
+        map.insert("billion", Lexeme::Billion);
+        map.insert("first", Lexeme::First);
+        map.insert("second", Lexeme::Second);
+        map.insert("third", Lexeme::Third);
+        map.insert("fourth", Lexeme::Fourth);
+        map.insert("fifth", Lexeme::Fifth);
+        map.insert("sixth", Lexeme::Sixth);
+        map.insert("seventh", Lexeme::Seventh);
+        map.insert("eighth", Lexeme::Eighth);
+        map.insert("ninth", Lexeme::Ninth);
+        map.insert("tenth", Lexeme::Tenth);
+        map.insert("eleventh", Lexeme::Eleventh);
+        map.insert("twelfth", Lexeme::Twelfth);
+        map.insert("thirteenth", Lexeme::Thirteenth);
+        map.insert("fourteenth", Lexeme::Fourteenth);
+        map.insert("fifteenth", Lexeme::Fifteenth);
+        map.insert("sixteenth", Lexeme::Sixteenth);
+        map.insert("seventeenth", Lexeme::Seventeenth);
+        map.insert("eighteenth", Lexeme::Eighteenth);
+        map.insert("nineteenth", Lexeme::Nineteenth);
+        map.insert("twentieth", Lexeme::Twentieth);
+        map.insert("thirtieth", Lexeme::Thirtieth);
+
+        map
+    };
+
+    /// Units which may directly follow a number, e.g. the "h" in "12h"
+    static ref UNIT_SUFFIXES: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("h", Lexeme::Hour);
+        map.insert("m", Lexeme::Minute);
+        map.insert("d", Lexeme::Day);
+        map.insert("w", Lexeme::Week);
+        map.insert("y", Lexeme::Year);
+
+        map
+    };
+}
+
+/// Entries spanning several words
+const PHRASES: &[(&str, Lexeme)] = &[
+    ("unix epoch", Lexeme::Epoch),
+    ("which is", Lexeme::WhichIs),
+    ("right now", Lexeme::Now),
+    ("business day", Lexeme::BusinessDay),
+    ("business days", Lexeme::BusinessDay),
+    ("working day", Lexeme::BusinessDay),
+    ("working days", Lexeme::BusinessDay),
+    ("no later than", Lexeme::NoLaterThan),
+    ("not later than", Lexeme::NoLaterThan),
+    ("not after", Lexeme::NoLaterThan),
+    ("no earlier than", Lexeme::NoEarlierThan),
+    ("not earlier than", Lexeme::NoEarlierThan),
+    ("not before", Lexeme::NoEarlierThan),
+];
+
+#[derive(Debug, Clone, Copy, Default)]
+/// The English language, used by default
+pub struct English;
+
+impl Language for English {
+    fn code(&self) -> &'static str {
+        "en"
+    }
+
+    fn keywords(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &KEYWORDS
+    }
+
+    fn months(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &MONTHS
+    }
+
+    fn weekdays(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &WEEKDAYS
+    }
+
+    fn abbreviations(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &ABBREVIATIONS
+    }
+
+    fn numbers(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &NUMBERS
+    }
+
+    fn unit_suffixes(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &UNIT_SUFFIXES
+    }
+
+    fn ordinal_suffixes(&self) -> &[&'static str] {
+        &["st", "nd", "rd", "th"]
+    }
+
+    fn phrases(&self) -> &[(&'static str, Lexeme)] {
+        PHRASES
+    }
+}
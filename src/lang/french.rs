@@ -0,0 +1,278 @@
+use alloc::collections::BTreeMap;
+use lazy_static::lazy_static;
+
+use super::Language;
+use crate::lexer::Lexeme;
+
+lazy_static! {
+    /// Words which aren't month, weekday, or number names
+    static ref KEYWORDS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("le", Lexeme::The);
+        map.insert("la", Lexeme::The);
+        map.insert("les", Lexeme::The);
+        map.insert("un", Lexeme::A);
+        map.insert("une", Lexeme::A);
+        map.insert("après", Lexeme::After);
+        map.insert("apres", Lexeme::After);
+        map.insert("avant", Lexeme::Before);
+        map.insert("dernier", Lexeme::Last);
+        map.insert("dernière", Lexeme::Last);
+        map.insert("derniere", Lexeme::Last);
+        map.insert("ce", Lexeme::This);
+        map.insert("cet", Lexeme::This);
+        map.insert("cette", Lexeme::This);
+        map.insert("prochain", Lexeme::Next);
+        map.insert("prochaine", Lexeme::Next);
+        map.insert("jour", Lexeme::Day);
+        map.insert("jours", Lexeme::Day);
+        map.insert("semaine", Lexeme::Week);
+        map.insert("semaines", Lexeme::Week);
+        map.insert("mois", Lexeme::Month);
+        map.insert("an", Lexeme::Year);
+        map.insert("ans", Lexeme::Year);
+        map.insert("année", Lexeme::Year);
+        map.insert("années", Lexeme::Year);
+        map.insert("annee", Lexeme::Year);
+        map.insert("annees", Lexeme::Year);
+        map.insert("heure", Lexeme::Hour);
+        map.insert("heures", Lexeme::Hour);
+        map.insert("minute", Lexeme::Minute);
+        map.insert("minutes", Lexeme::Minute);
+        map.insert("min", Lexeme::Minute);
+        map.insert("et", Lexeme::And);
+        map.insert("quelques", Lexeme::Few);
+        map.insert("plusieurs", Lexeme::Several);
+        map.insert("aujourd'hui", Lexeme::Today);
+        map.insert("demain", Lexeme::Tomorrow);
+        map.insert("hier", Lexeme::Yesterday);
+        map.insert("maintenant", Lexeme::Now);
+        map.insert("minuit", Lexeme::Midnight);
+        map.insert("midi", Lexeme::Noon);
+        map.insert("matin", Lexeme::Morning);
+        map.insert("soir", Lexeme::Evening);
+        map.insert("nuit", Lexeme::Night);
+        map.insert("à", Lexeme::At);
+        map.insert("dans", Lexeme::In);
+        map.insert("h", Lexeme::Hour);
+        map.insert("chaque", Lexeme::Every);
+        map.insert("quotidien", Lexeme::Daily);
+        map.insert("hebdomadaire", Lexeme::Weekly);
+        map.insert("mensuel", Lexeme::Monthly);
+        map.insert("annuel", Lexeme::Yearly);
+
+        map
+    };
+
+    /// Month names
+    static ref MONTHS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("janvier", Lexeme::January);
+        map.insert("février", Lexeme::February);
+        map.insert("fevrier", Lexeme::February);
+        map.insert("mars", Lexeme::March);
+        map.insert("avril", Lexeme::April);
+        map.insert("mai", Lexeme::May);
+        map.insert("juin", Lexeme::June);
+        map.insert("juillet", Lexeme::July);
+        map.insert("août", Lexeme::August);
+        map.insert("aout", Lexeme::August);
+        map.insert("septembre", Lexeme::September);
+        map.insert("octobre", Lexeme::October);
+        map.insert("novembre", Lexeme::November);
+        map.insert("décembre", Lexeme::December);
+        map.insert("decembre", Lexeme::December);
+
+        map
+    };
+
+    /// Weekday names
+    static ref WEEKDAYS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("lundi", Lexeme::Monday);
+        map.insert("mardi", Lexeme::Tuesday);
+        map.insert("mercredi", Lexeme::Wednesday);
+        map.insert("jeudi", Lexeme::Thursday);
+        map.insert("vendredi", Lexeme::Friday);
+        map.insert("samedi", Lexeme::Saturday);
+        map.insert("dimanche", Lexeme::Sunday);
+        map.insert("lundis", Lexeme::Monday);
+        map.insert("mardis", Lexeme::Tuesday);
+        map.insert("mercredis", Lexeme::Wednesday);
+        map.insert("jeudis", Lexeme::Thursday);
+        map.insert("vendredis", Lexeme::Friday);
+        map.insert("samedis", Lexeme::Saturday);
+        map.insert("dimanches", Lexeme::Sunday);
+
+        map
+    };
+
+    /// Abbreviated month and weekday names, which may be followed by a
+    /// period, e.g. "oct."
+    static ref ABBREVIATIONS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("janv", Lexeme::January);
+        map.insert("févr", Lexeme::February);
+        map.insert("fevr", Lexeme::February);
+        map.insert("avr", Lexeme::April);
+        map.insert("juil", Lexeme::July);
+        map.insert("sept", Lexeme::September);
+        map.insert("oct", Lexeme::October);
+        map.insert("nov", Lexeme::November);
+        map.insert("déc", Lexeme::December);
+        map.insert("dec", Lexeme::December);
+
+        map
+    };
+
+    /// Number words
+    static ref NUMBERS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("zéro", Lexeme::Zero);
+        map.insert("deux", Lexeme::Two);
+        map.insert("trois", Lexeme::Three);
+        map.insert("quatre", Lexeme::Four);
+        map.insert("cinq", Lexeme::Five);
+        map.insert("six", Lexeme::Six);
+        map.insert("sept", Lexeme::Seven);
+        map.insert("huit", Lexeme::Eight);
+        map.insert("neuf", Lexeme::Nine);
+        map.insert("dix", Lexeme::Ten);
+        map.insert("onze", Lexeme::Eleven);
+        map.insert("douze", Lexeme::Twelve);
+        map.insert("treize", Lexeme::Thirteen);
+        map.insert("quatorze", Lexeme::Fourteen);
+        map.insert("quinze", Lexeme::Fifteen);
+        map.insert("seize", Lexeme::Sixteen);
+        map.insert("zero", Lexeme::Zero);
+        map.insert("vingt", Lexeme::Twenty);
+        map.insert("trente", Lexeme::Thirty);
+        map.insert("quarante", Lexeme::Fourty);
+        map.insert("cinquante", Lexeme::Fifty);
+        map.insert("soixante", Lexeme::Sixty);
+        map.insert("cent", Lexeme::Hundred);
+        map.insert("cents", Lexeme::Hundred);
+        map.insert("mille", Lexeme::Thousand);
+        map.insert("million", Lexeme::Million);
+        map.insert("millions", Lexeme::Million);
+        map.insert("milliard", Lexeme::Billion);
+        map.insert("milliards", Lexeme::Billion);
+
+        map
+    };
+
+    /// Units which may directly follow a number, e.g. the "h" in "17h"
+    static ref UNIT_SUFFIXES: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("h", Lexeme::Hour);
+        map.insert("min", Lexeme::Minute);
+
+        map
+    };
+}
+
+/// Entries spanning several words or containing dashes
+const PHRASES: &[(&str, Lexeme)] = &[
+    ("après-demain", Lexeme::DayAfterTomorrow),
+    ("apres-demain", Lexeme::DayAfterTomorrow),
+    ("avant-hier", Lexeme::DayBeforeYesterday),
+    ("il y a", Lexeme::Ago),
+    ("dix-sept", Lexeme::Seventeen),
+    ("dix-huit", Lexeme::Eighteen),
+    ("dix-neuf", Lexeme::Nineteen),
+    ("soixante-dix", Lexeme::Seventy),
+    ("quatre-vingt", Lexeme::Eighty),
+    ("quatre-vingts", Lexeme::Eighty),
+    ("quatre-vingt-dix", Lexeme::Ninety),
+];
+
+#[derive(Debug, Clone, Copy, Default)]
+/// The French language, enabled by the `lang-fr` feature
+pub struct French;
+
+impl Language for French {
+    fn code(&self) -> &'static str {
+        "fr"
+    }
+
+    fn keywords(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &KEYWORDS
+    }
+
+    fn months(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &MONTHS
+    }
+
+    fn weekdays(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &WEEKDAYS
+    }
+
+    fn abbreviations(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &ABBREVIATIONS
+    }
+
+    fn numbers(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &NUMBERS
+    }
+
+    fn unit_suffixes(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &UNIT_SUFFIXES
+    }
+
+    fn phrases(&self) -> &[(&'static str, Lexeme)] {
+        PHRASES
+    }
+
+    fn month_names(&self) -> [&'static str; 12] {
+        [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ]
+    }
+
+    fn weekday_names(&self) -> [&'static str; 7] {
+        [
+            "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+        ]
+    }
+}
+
+#[test]
+fn test_in_three_days() {
+    use crate::{parse_with_options, ParseOptions};
+    use chrono::NaiveDateTime;
+
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().language(&French).relative_to(now);
+
+    let date = parse_with_options("dans trois jours", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-06-04 12:00:00");
+
+    let date = parse_with_options("après-demain à 17h", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-06-03 17:00:00");
+
+    let date = parse_with_options("il y a dix-huit jours", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-05-14 12:00:00");
+
+    let date = parse_with_options("avant-hier à minuit", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-05-30 00:00:00");
+
+    let date = parse_with_options("sept. 5 2024", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-09-05 12:00:00");
+}
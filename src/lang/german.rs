@@ -0,0 +1,337 @@
+use alloc::collections::BTreeMap;
+use lazy_static::lazy_static;
+
+use super::Language;
+use crate::lexer::Lexeme;
+use crate::HalfHour;
+
+lazy_static! {
+    /// Words which aren't month, weekday, or number names
+    static ref KEYWORDS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("der", Lexeme::The);
+        map.insert("die", Lexeme::The);
+        map.insert("das", Lexeme::The);
+        map.insert("den", Lexeme::The);
+        map.insert("dem", Lexeme::The);
+        map.insert("ein", Lexeme::A);
+        map.insert("eine", Lexeme::A);
+        map.insert("einen", Lexeme::A);
+        map.insert("einem", Lexeme::A);
+        map.insert("einer", Lexeme::A);
+        map.insert("nach", Lexeme::After);
+        map.insert("vor", Lexeme::Ago);
+        map.insert("letzte", Lexeme::Last);
+        map.insert("letzten", Lexeme::Last);
+        map.insert("letzter", Lexeme::Last);
+        map.insert("letztes", Lexeme::Last);
+        map.insert("diese", Lexeme::This);
+        map.insert("diesen", Lexeme::This);
+        map.insert("dieser", Lexeme::This);
+        map.insert("dieses", Lexeme::This);
+        map.insert("nächste", Lexeme::Next);
+        map.insert("nächsten", Lexeme::Next);
+        map.insert("nächster", Lexeme::Next);
+        map.insert("nächstes", Lexeme::Next);
+        map.insert("naechste", Lexeme::Next);
+        map.insert("naechsten", Lexeme::Next);
+        map.insert("tag", Lexeme::Day);
+        map.insert("tage", Lexeme::Day);
+        map.insert("tagen", Lexeme::Day);
+        map.insert("werktag", Lexeme::BusinessDay);
+        map.insert("werktage", Lexeme::BusinessDay);
+        map.insert("werktagen", Lexeme::BusinessDay);
+        map.insert("woche", Lexeme::Week);
+        map.insert("wochen", Lexeme::Week);
+        map.insert("monat", Lexeme::Month);
+        map.insert("monate", Lexeme::Month);
+        map.insert("monaten", Lexeme::Month);
+        map.insert("jahr", Lexeme::Year);
+        map.insert("jahre", Lexeme::Year);
+        map.insert("jahren", Lexeme::Year);
+        map.insert("stunde", Lexeme::Hour);
+        map.insert("stunden", Lexeme::Hour);
+        map.insert("minute", Lexeme::Minute);
+        map.insert("minuten", Lexeme::Minute);
+        map.insert("min", Lexeme::Minute);
+        map.insert("und", Lexeme::And);
+        map.insert("paar", Lexeme::Couple);
+        map.insert("einige", Lexeme::Few);
+        map.insert("mehrere", Lexeme::Several);
+        map.insert("halb", Lexeme::Half);
+        map.insert("halbe", Lexeme::Half);
+        map.insert("halben", Lexeme::Half);
+        map.insert("viertel", Lexeme::Quarter);
+        map.insert("heute", Lexeme::Today);
+        map.insert("morgen", Lexeme::Tomorrow);
+        map.insert("gestern", Lexeme::Yesterday);
+        map.insert("übermorgen", Lexeme::DayAfterTomorrow);
+        map.insert("uebermorgen", Lexeme::DayAfterTomorrow);
+        map.insert("vorgestern", Lexeme::DayBeforeYesterday);
+        map.insert("jetzt", Lexeme::Now);
+        map.insert("mitternacht", Lexeme::Midnight);
+        map.insert("mittag", Lexeme::Noon);
+        map.insert("früh", Lexeme::Morning);
+        map.insert("frueh", Lexeme::Morning);
+        map.insert("vormittag", Lexeme::Morning);
+        map.insert("nachmittag", Lexeme::Afternoon);
+        map.insert("abend", Lexeme::Evening);
+        map.insert("nacht", Lexeme::Night);
+        map.insert("um", Lexeme::At);
+        map.insert("in", Lexeme::In);
+        map.insert("uhr", Lexeme::OClock);
+        map.insert("h", Lexeme::Hour);
+        map.insert("jeden", Lexeme::Every);
+        map.insert("jede", Lexeme::Every);
+        map.insert("jedes", Lexeme::Every);
+        map.insert("täglich", Lexeme::Daily);
+        map.insert("wöchentlich", Lexeme::Weekly);
+        map.insert("monatlich", Lexeme::Monthly);
+        map.insert("jährlich", Lexeme::Yearly);
+
+        map
+    };
+
+    /// Month names
+    static ref MONTHS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("januar", Lexeme::January);
+        map.insert("jänner", Lexeme::January);
+        map.insert("februar", Lexeme::February);
+        map.insert("märz", Lexeme::March);
+        map.insert("maerz", Lexeme::March);
+        map.insert("april", Lexeme::April);
+        map.insert("mai", Lexeme::May);
+        map.insert("juni", Lexeme::June);
+        map.insert("juli", Lexeme::July);
+        map.insert("august", Lexeme::August);
+        map.insert("september", Lexeme::September);
+        map.insert("oktober", Lexeme::October);
+        map.insert("november", Lexeme::November);
+        map.insert("dezember", Lexeme::December);
+
+        map
+    };
+
+    /// Weekday names
+    static ref WEEKDAYS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("montag", Lexeme::Monday);
+        map.insert("dienstag", Lexeme::Tuesday);
+        map.insert("mittwoch", Lexeme::Wednesday);
+        map.insert("donnerstag", Lexeme::Thursday);
+        map.insert("freitag", Lexeme::Friday);
+        map.insert("samstag", Lexeme::Saturday);
+        map.insert("sonnabend", Lexeme::Saturday);
+        map.insert("sonntag", Lexeme::Sunday);
+        map.insert("montage", Lexeme::Monday);
+        map.insert("dienstage", Lexeme::Tuesday);
+        map.insert("mittwoche", Lexeme::Wednesday);
+        map.insert("donnerstage", Lexeme::Thursday);
+        map.insert("freitage", Lexeme::Friday);
+        map.insert("samstage", Lexeme::Saturday);
+        map.insert("sonntage", Lexeme::Sunday);
+
+        map
+    };
+
+    /// Abbreviated month and weekday names, which may be followed by a
+    /// period, e.g. "oct."
+    static ref ABBREVIATIONS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("jan", Lexeme::January);
+        map.insert("feb", Lexeme::February);
+        map.insert("mär", Lexeme::March);
+        map.insert("apr", Lexeme::April);
+        map.insert("jun", Lexeme::June);
+        map.insert("jul", Lexeme::July);
+        map.insert("aug", Lexeme::August);
+        map.insert("sep", Lexeme::September);
+        map.insert("sept", Lexeme::September);
+        map.insert("okt", Lexeme::October);
+        map.insert("nov", Lexeme::November);
+        map.insert("dez", Lexeme::December);
+
+        map
+    };
+
+    /// Number words
+    static ref NUMBERS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("null", Lexeme::Zero);
+        map.insert("eins", Lexeme::One);
+        map.insert("zwei", Lexeme::Two);
+        map.insert("drei", Lexeme::Three);
+        map.insert("vier", Lexeme::Four);
+        map.insert("fünf", Lexeme::Five);
+        map.insert("sechs", Lexeme::Six);
+        map.insert("sieben", Lexeme::Seven);
+        map.insert("acht", Lexeme::Eight);
+        map.insert("neun", Lexeme::Nine);
+        map.insert("zehn", Lexeme::Ten);
+        map.insert("elf", Lexeme::Eleven);
+        map.insert("zwölf", Lexeme::Twelve);
+        map.insert("dreizehn", Lexeme::Thirteen);
+        map.insert("vierzehn", Lexeme::Fourteen);
+        map.insert("fünfzehn", Lexeme::Fifteen);
+        map.insert("sechzehn", Lexeme::Sixteen);
+        map.insert("siebzehn", Lexeme::Seventeen);
+        map.insert("achtzehn", Lexeme::Eighteen);
+        map.insert("neunzehn", Lexeme::Nineteen);
+        map.insert("zwanzig", Lexeme::Twenty);
+        map.insert("dreißig", Lexeme::Thirty);
+        map.insert("vierzig", Lexeme::Fourty);
+        map.insert("fünfzig", Lexeme::Fifty);
+        map.insert("sechzig", Lexeme::Sixty);
+        map.insert("siebzig", Lexeme::Seventy);
+        map.insert("achtzig", Lexeme::Eighty);
+        map.insert("neunzig", Lexeme::Ninety);
+        map.insert("hundert", Lexeme::Hundred);
+        map.insert("tausend", Lexeme::Thousand);
+        map.insert("million", Lexeme::Million);
+        map.insert("millionen", Lexeme::Million);
+        map.insert("milliarde", Lexeme::Billion);
+        map.insert("milliarden", Lexeme::Billion);
+
+        map
+    };
+
+    /// Units which may directly follow a number, e.g. the "h" in "17h"
+    static ref UNIT_SUFFIXES: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("h", Lexeme::Hour);
+        map.insert("min", Lexeme::Minute);
+
+        map
+    };
+}
+
+/// "morgen" is tomorrow on its own, but the morning after a day, e.g.
+/// "heute morgen" or "montag morgen"
+const DAY_PARTS_AFTER_DAY: &[(&str, Lexeme)] = &[("morgen", Lexeme::Morning)];
+
+#[derive(Debug, Clone, Copy, Default)]
+/// The German language, enabled by the `lang-de` feature
+pub struct German;
+
+impl Language for German {
+    fn code(&self) -> &'static str {
+        "de"
+    }
+
+    fn keywords(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &KEYWORDS
+    }
+
+    fn months(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &MONTHS
+    }
+
+    fn weekdays(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &WEEKDAYS
+    }
+
+    fn abbreviations(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &ABBREVIATIONS
+    }
+
+    fn numbers(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &NUMBERS
+    }
+
+    fn unit_suffixes(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &UNIT_SUFFIXES
+    }
+
+    fn half_hour(&self) -> HalfHour {
+        HalfHour::To
+    }
+
+    fn day_parts_after_day(&self) -> &[(&'static str, Lexeme)] {
+        DAY_PARTS_AFTER_DAY
+    }
+
+    fn month_names(&self) -> [&'static str; 12] {
+        [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ]
+    }
+
+    fn weekday_names(&self) -> [&'static str; 7] {
+        [
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+            "Sonntag",
+        ]
+    }
+}
+
+#[test]
+fn test_day_after_tomorrow_at() {
+    use crate::{parse_with_options, ParseOptions};
+    use chrono::NaiveDateTime;
+
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().language(&German).relative_to(now);
+
+    let date = parse_with_options("übermorgen um 17 Uhr", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-06-03 17:00:00");
+
+    let date = parse_with_options("Vor drei Tagen", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-05-29 12:00:00");
+
+    let date = parse_with_options("in zwei Wochen", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-06-15 12:00:00");
+
+    let date = parse_with_options("nächsten Freitag um 9:30", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-06-14 09:30:00");
+
+    let date = parse_with_options("morgen Abend", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-06-02 19:00:00");
+
+    let date = parse_with_options("vor einer halben Stunde", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-06-01 11:30:00");
+}
+
+#[test]
+fn test_morgen() {
+    use crate::{parse_with_options, ParseOptions};
+    use chrono::NaiveDateTime;
+
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().language(&German).relative_to(now);
+
+    for (input, expected) in [
+        ("morgen", "2024-06-02 12:00:00"),
+        ("morgen früh", "2024-06-02 09:00:00"),
+        ("heute Morgen", "2024-06-01 09:00:00"),
+        ("gestern Morgen", "2024-05-31 09:00:00"),
+        ("morgen Morgen", "2024-06-02 09:00:00"),
+        ("Montag Morgen", "2024-06-03 09:00:00"),
+        ("morgen Abend", "2024-06-02 19:00:00"),
+    ] {
+        let date = parse_with_options(input, &options).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+}
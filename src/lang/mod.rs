@@ -0,0 +1,291 @@
+//! Vocabulary for the languages understood by the lexer
+//!
+//! Each [`Language`] provides tables mapping its words onto [`Lexeme`]s,
+//! which are consumed by a shared tokenizer, so the grammar is the same for
+//! every language. English is used unless another language is selected
+//! with [`crate::ParseOptions::language`].
+//!
+//! German, French, and Spanish are available behind the `lang-de`,
+//! `lang-fr`, and `lang-es` cargo features.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use core::fmt;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::lexer::Lexeme;
+use crate::HalfHour;
+
+mod english;
+#[cfg(feature = "lang-fr")]
+mod french;
+#[cfg(feature = "lang-de")]
+mod german;
+#[cfg(feature = "lang-es")]
+mod spanish;
+
+pub use english::English;
+#[cfg(feature = "lang-fr")]
+pub use french::French;
+#[cfg(feature = "lang-de")]
+pub use german::German;
+#[cfg(feature = "lang-es")]
+pub use spanish::Spanish;
+
+/// The abbreviations of a language without any
+static NO_ABBREVIATIONS: BTreeMap<&str, Lexeme> = BTreeMap::new();
+
+/// Tables of words for a language, all in lowercase
+pub trait Language: Send + Sync {
+    /// The ISO 639-1 code of the language, e.g. `"en"`
+    fn code(&self) -> &'static str;
+
+    /// Words which aren't month, weekday, or number names, e.g. `"tomorrow"`
+    fn keywords(&self) -> &BTreeMap<&'static str, Lexeme>;
+
+    /// Month names
+    fn months(&self) -> &BTreeMap<&'static str, Lexeme>;
+
+    /// Weekday names
+    fn weekdays(&self) -> &BTreeMap<&'static str, Lexeme>;
+
+    /// Abbreviated month and weekday names, e.g. `"sept"`, which may be
+    /// followed by a period, e.g. "Sept. 5"
+    fn abbreviations(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &NO_ABBREVIATIONS
+    }
+
+    /// Number words, e.g. `"twenty"`
+    fn numbers(&self) -> &BTreeMap<&'static str, Lexeme>;
+
+    /// Units which may directly follow a number, e.g. the `"h"` in `"12h"`
+    fn unit_suffixes(&self) -> &BTreeMap<&'static str, Lexeme>;
+
+    /// Endings which make a number ordinal, e.g. the `"rd"` in `"3rd"`
+    fn ordinal_suffixes(&self) -> &[&'static str] {
+        &[]
+    }
+
+    /// Which half hour "half" and an hour refer to, half past by default
+    fn half_hour(&self) -> HalfHour {
+        HalfHour::Past
+    }
+
+    /// Words naming a part of the day when they directly follow a day, but
+    /// something else on their own, e.g. the German "morgen" is tomorrow,
+    /// but the morning in "heute morgen"
+    fn day_parts_after_day(&self) -> &[(&'static str, Lexeme)] {
+        &[]
+    }
+
+    /// Entries spanning several words or containing separators, such as
+    /// "pasado mañana" or "après-demain", which are matched before the
+    /// input is split into tokens
+    fn phrases(&self) -> &[(&'static str, Lexeme)] {
+        &[]
+    }
+
+    /// The names of the months when writing dates, starting with January
+    fn month_names(&self) -> [&'static str; 12] {
+        [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ]
+    }
+
+    /// The names of the weekdays when writing dates, starting with Monday
+    fn weekday_names(&self) -> [&'static str; 7] {
+        [
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+            "Sunday",
+        ]
+    }
+
+    /// Look up a single lowercase word in every table
+    fn lookup(&self, word: &str) -> Option<Lexeme> {
+        self.keywords()
+            .get(word)
+            .or_else(|| self.months().get(word))
+            .or_else(|| self.weekdays().get(word))
+            .or_else(|| self.abbreviations().get(word))
+            .or_else(|| self.numbers().get(word))
+            .copied()
+    }
+}
+
+impl fmt::Debug for dyn Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Language").field(&self.code()).finish()
+    }
+}
+
+/// The enabled language with the given ISO 639-1 code, e.g. `"de"` with
+/// the `lang-de` feature
+///
+/// ```rust
+/// assert_eq!(fuzzydate::lang::from_code("en").unwrap().code(), "en");
+/// assert!(fuzzydate::lang::from_code("xx").is_none());
+/// ```
+pub fn from_code(code: &str) -> Option<&'static dyn Language> {
+    let languages: &[&'static dyn Language] = &[
+        &English,
+        #[cfg(feature = "lang-de")]
+        &German,
+        #[cfg(feature = "lang-fr")]
+        &French,
+        #[cfg(feature = "lang-es")]
+        &Spanish,
+    ];
+
+    languages
+        .iter()
+        .copied()
+        .find(|language| language.code().eq_ignore_ascii_case(code))
+}
+
+/// Write the month and weekday names of `date` into a strftime format in
+/// `language`, since chrono only writes English names. `%B` and `%A` are
+/// replaced with the full names, and `%b`, `%h` and `%a` with their first
+/// three letters.
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use fuzzydate::lang::{localize_format, English};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 6, 7).unwrap();
+/// assert_eq!(localize_format("%A %-d %B %Y", date, &English), "Friday %-d June %Y");
+/// assert_eq!(localize_format("%a %b 100%%", date, &English), "Fri Jun 100%%");
+/// ```
+pub fn localize_format(format: &str, date: NaiveDate, language: &dyn Language) -> String {
+    let month = language.month_names()[date.month0() as usize];
+    let weekday = language.weekday_names()[date.weekday().num_days_from_monday() as usize];
+    let short = |name: &str| -> String { name.chars().take(3).collect() };
+
+    let mut localized = String::with_capacity(format.len());
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            localized.push(c);
+            continue;
+        }
+
+        // Any other specifier, including an escaped "%%", is left to chrono
+        match chars.next() {
+            Some('B') => localized += month,
+            Some('b' | 'h') => localized += &short(month),
+            Some('A') => localized += weekday,
+            Some('a') => localized += &short(weekday),
+            Some(other) => {
+                localized.push('%');
+                localized.push(other);
+            }
+            None => localized.push('%'),
+        }
+    }
+
+    localized
+}
+
+#[test]
+fn test_extended_abbreviations() {
+    use chrono::NaiveDateTime;
+
+    use crate::{parse_with_options, ParseOptions};
+
+    lazy_static::lazy_static! {
+        static ref ABBREVIATIONS: BTreeMap<&'static str, Lexeme> = {
+            let mut map = English.abbreviations().clone();
+            map.insert("sptmbr", Lexeme::September);
+            map
+        };
+    }
+
+    /// English with an abbreviation of its own
+    struct Extended;
+
+    impl Language for Extended {
+        fn code(&self) -> &'static str {
+            "en"
+        }
+
+        fn keywords(&self) -> &BTreeMap<&'static str, Lexeme> {
+            English.keywords()
+        }
+
+        fn months(&self) -> &BTreeMap<&'static str, Lexeme> {
+            English.months()
+        }
+
+        fn weekdays(&self) -> &BTreeMap<&'static str, Lexeme> {
+            English.weekdays()
+        }
+
+        fn numbers(&self) -> &BTreeMap<&'static str, Lexeme> {
+            English.numbers()
+        }
+
+        fn unit_suffixes(&self) -> &BTreeMap<&'static str, Lexeme> {
+            English.unit_suffixes()
+        }
+
+        fn abbreviations(&self) -> &BTreeMap<&'static str, Lexeme> {
+            &ABBREVIATIONS
+        }
+    }
+
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().language(&Extended).relative_to(now);
+
+    for input in ["Sptmbr. 5 2024", "sept. 5 2024", "sep 5 2024"] {
+        let date = parse_with_options(input, &options).unwrap();
+        assert_eq!(date.to_string(), "2024-09-05 12:00:00", "{input}");
+    }
+
+    let options = ParseOptions::new().relative_to(now);
+    assert!(parse_with_options("sptmbr 5 2024", &options).is_err());
+}
+
+#[cfg(all(feature = "lang-de", feature = "lang-fr", feature = "lang-es"))]
+#[test]
+fn test_localize_format() {
+    use chrono::NaiveDateTime;
+
+    use crate::{parse_with_options, ParseOptions};
+
+    let date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+    let now = NaiveDateTime::parse_from_str("2024-01-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+
+    for (language, expected) in [
+        (&German as &dyn Language, "Dienstag 5 März 2024, Die Mär"),
+        (&French, "mardi 5 mars 2024, mar mar"),
+        (&Spanish, "martes 5 marzo 2024, mar mar"),
+    ] {
+        let format = localize_format("%A %-d %B %Y, %a %b", date, language);
+        let written = date.format(&format).to_string();
+        assert_eq!(written, expected);
+
+        // Full names are read back in the same language
+        let options = ParseOptions::new().language(language).relative_to(now);
+        let full = date
+            .format(&localize_format("%B %-d %Y", date, language))
+            .to_string();
+        let parsed = parse_with_options(&full, &options).unwrap();
+        assert_eq!(parsed.date(), date, "{full}");
+    }
+}
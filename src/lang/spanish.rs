@@ -0,0 +1,288 @@
+use alloc::collections::BTreeMap;
+use lazy_static::lazy_static;
+
+use super::Language;
+use crate::lexer::Lexeme;
+
+lazy_static! {
+    /// Words which aren't month, weekday, or number names
+    static ref KEYWORDS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("el", Lexeme::The);
+        map.insert("la", Lexeme::The);
+        map.insert("los", Lexeme::The);
+        map.insert("las", Lexeme::The);
+        map.insert("un", Lexeme::A);
+        map.insert("una", Lexeme::A);
+        map.insert("después", Lexeme::After);
+        map.insert("despues", Lexeme::After);
+        map.insert("antes", Lexeme::Before);
+        map.insert("hace", Lexeme::Ago);
+        map.insert("último", Lexeme::Last);
+        map.insert("última", Lexeme::Last);
+        map.insert("ultimo", Lexeme::Last);
+        map.insert("ultima", Lexeme::Last);
+        map.insert("este", Lexeme::This);
+        map.insert("esta", Lexeme::This);
+        map.insert("próximo", Lexeme::Next);
+        map.insert("próxima", Lexeme::Next);
+        map.insert("proximo", Lexeme::Next);
+        map.insert("proxima", Lexeme::Next);
+        map.insert("día", Lexeme::Day);
+        map.insert("días", Lexeme::Day);
+        map.insert("dia", Lexeme::Day);
+        map.insert("dias", Lexeme::Day);
+        map.insert("semana", Lexeme::Week);
+        map.insert("semanas", Lexeme::Week);
+        map.insert("mes", Lexeme::Month);
+        map.insert("meses", Lexeme::Month);
+        map.insert("año", Lexeme::Year);
+        map.insert("años", Lexeme::Year);
+        map.insert("hora", Lexeme::Hour);
+        map.insert("horas", Lexeme::Hour);
+        map.insert("minuto", Lexeme::Minute);
+        map.insert("minutos", Lexeme::Minute);
+        map.insert("min", Lexeme::Minute);
+        map.insert("y", Lexeme::And);
+        map.insert("varios", Lexeme::Several);
+        map.insert("varias", Lexeme::Several);
+        map.insert("hoy", Lexeme::Today);
+        map.insert("mañana", Lexeme::Tomorrow);
+        map.insert("manana", Lexeme::Tomorrow);
+        map.insert("ayer", Lexeme::Yesterday);
+        map.insert("anteayer", Lexeme::DayBeforeYesterday);
+        map.insert("ahora", Lexeme::Now);
+        map.insert("medianoche", Lexeme::Midnight);
+        map.insert("mediodía", Lexeme::Noon);
+        map.insert("mediodia", Lexeme::Noon);
+        map.insert("tarde", Lexeme::Afternoon);
+        map.insert("noche", Lexeme::Night);
+        map.insert("a", Lexeme::At);
+        map.insert("en", Lexeme::In);
+        map.insert("h", Lexeme::Hour);
+        map.insert("cada", Lexeme::Every);
+        map.insert("diario", Lexeme::Daily);
+        map.insert("semanal", Lexeme::Weekly);
+        map.insert("mensual", Lexeme::Monthly);
+        map.insert("anual", Lexeme::Yearly);
+
+        map
+    };
+
+    /// Month names
+    static ref MONTHS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("enero", Lexeme::January);
+        map.insert("febrero", Lexeme::February);
+        map.insert("marzo", Lexeme::March);
+        map.insert("abril", Lexeme::April);
+        map.insert("mayo", Lexeme::May);
+        map.insert("junio", Lexeme::June);
+        map.insert("julio", Lexeme::July);
+        map.insert("agosto", Lexeme::August);
+        map.insert("septiembre", Lexeme::September);
+        map.insert("setiembre", Lexeme::September);
+        map.insert("octubre", Lexeme::October);
+        map.insert("noviembre", Lexeme::November);
+        map.insert("diciembre", Lexeme::December);
+
+        map
+    };
+
+    /// Weekday names
+    static ref WEEKDAYS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("lunes", Lexeme::Monday);
+        map.insert("martes", Lexeme::Tuesday);
+        map.insert("miércoles", Lexeme::Wednesday);
+        map.insert("miercoles", Lexeme::Wednesday);
+        map.insert("jueves", Lexeme::Thursday);
+        map.insert("viernes", Lexeme::Friday);
+        map.insert("sábado", Lexeme::Saturday);
+        map.insert("sabado", Lexeme::Saturday);
+        map.insert("domingo", Lexeme::Sunday);
+        map.insert("sábados", Lexeme::Saturday);
+        map.insert("sabados", Lexeme::Saturday);
+        map.insert("domingos", Lexeme::Sunday);
+
+        map
+    };
+
+    /// Abbreviated month and weekday names, which may be followed by a
+    /// period, e.g. "oct."
+    static ref ABBREVIATIONS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("ene", Lexeme::January);
+        map.insert("feb", Lexeme::February);
+        map.insert("mar", Lexeme::March);
+        map.insert("abr", Lexeme::April);
+        map.insert("jun", Lexeme::June);
+        map.insert("jul", Lexeme::July);
+        map.insert("ago", Lexeme::August);
+        map.insert("sep", Lexeme::September);
+        map.insert("sept", Lexeme::September);
+        map.insert("oct", Lexeme::October);
+        map.insert("nov", Lexeme::November);
+        map.insert("dic", Lexeme::December);
+
+        map
+    };
+
+    /// Number words
+    static ref NUMBERS: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("cero", Lexeme::Zero);
+        map.insert("uno", Lexeme::One);
+        map.insert("dos", Lexeme::Two);
+        map.insert("tres", Lexeme::Three);
+        map.insert("cuatro", Lexeme::Four);
+        map.insert("cinco", Lexeme::Five);
+        map.insert("seis", Lexeme::Six);
+        map.insert("siete", Lexeme::Seven);
+        map.insert("ocho", Lexeme::Eight);
+        map.insert("nueve", Lexeme::Nine);
+        map.insert("diez", Lexeme::Ten);
+        map.insert("once", Lexeme::Eleven);
+        map.insert("doce", Lexeme::Twelve);
+        map.insert("trece", Lexeme::Thirteen);
+        map.insert("catorce", Lexeme::Fourteen);
+        map.insert("quince", Lexeme::Fifteen);
+        map.insert("dieciséis", Lexeme::Sixteen);
+        map.insert("diecisiete", Lexeme::Seventeen);
+        map.insert("dieciocho", Lexeme::Eighteen);
+        map.insert("diecinueve", Lexeme::Nineteen);
+        map.insert("dieciseis", Lexeme::Sixteen);
+        map.insert("veinte", Lexeme::Twenty);
+        map.insert("treinta", Lexeme::Thirty);
+        map.insert("cuarenta", Lexeme::Fourty);
+        map.insert("cincuenta", Lexeme::Fifty);
+        map.insert("sesenta", Lexeme::Sixty);
+        map.insert("setenta", Lexeme::Seventy);
+        map.insert("ochenta", Lexeme::Eighty);
+        map.insert("noventa", Lexeme::Ninety);
+        map.insert("cien", Lexeme::Hundred);
+        map.insert("ciento", Lexeme::Hundred);
+        map.insert("mil", Lexeme::Thousand);
+        map.insert("millón", Lexeme::Million);
+        map.insert("millon", Lexeme::Million);
+        map.insert("millones", Lexeme::Million);
+
+        map
+    };
+
+    /// Units which may directly follow a number, e.g. the "h" in "17h"
+    static ref UNIT_SUFFIXES: BTreeMap<&'static str, Lexeme> = {
+        let mut map = BTreeMap::new();
+
+        map.insert("h", Lexeme::Hour);
+        map.insert("min", Lexeme::Minute);
+
+        map
+    };
+}
+
+/// Entries spanning several words or containing dashes
+const PHRASES: &[(&str, Lexeme)] = &[
+    ("pasado mañana", Lexeme::DayAfterTomorrow),
+    ("pasado manana", Lexeme::DayAfterTomorrow),
+    ("antes de ayer", Lexeme::DayBeforeYesterday),
+    ("dentro de", Lexeme::In),
+    ("después de", Lexeme::After),
+    ("despues de", Lexeme::After),
+    ("antes de", Lexeme::Before),
+    ("a las", Lexeme::At),
+    ("a la", Lexeme::At),
+];
+
+#[derive(Debug, Clone, Copy, Default)]
+/// The Spanish language, enabled by the `lang-es` feature
+pub struct Spanish;
+
+impl Language for Spanish {
+    fn code(&self) -> &'static str {
+        "es"
+    }
+
+    fn keywords(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &KEYWORDS
+    }
+
+    fn months(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &MONTHS
+    }
+
+    fn weekdays(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &WEEKDAYS
+    }
+
+    fn abbreviations(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &ABBREVIATIONS
+    }
+
+    fn numbers(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &NUMBERS
+    }
+
+    fn unit_suffixes(&self) -> &BTreeMap<&'static str, Lexeme> {
+        &UNIT_SUFFIXES
+    }
+
+    fn phrases(&self) -> &[(&'static str, Lexeme)] {
+        PHRASES
+    }
+
+    fn month_names(&self) -> [&'static str; 12] {
+        [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ]
+    }
+
+    fn weekday_names(&self) -> [&'static str; 7] {
+        [
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+            "domingo",
+        ]
+    }
+}
+
+#[test]
+fn test_ago_and_day_after_tomorrow() {
+    use crate::{parse_with_options, ParseOptions};
+    use chrono::NaiveDateTime;
+
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().language(&Spanish).relative_to(now);
+
+    let date = parse_with_options("hace dos días", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-05-30 12:00:00");
+
+    let date = parse_with_options("pasado mañana a las 17:30", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-06-03 17:30:00");
+
+    let date = parse_with_options("dentro de una semana", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-06-08 12:00:00");
+
+    let date = parse_with_options("tres días antes de mañana", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-05-30 12:00:00");
+}
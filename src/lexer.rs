@@ -1,109 +1,32 @@
+//! Tokenization of input strings into [`Lexeme`]s
+//!
+//! Most users want [`crate::parse`], but the lexer is exposed for tools
+//! which need to know where each token came from, e.g. for highlighting
+//! the recognized parts of an input.
+
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::ops::Range;
 use lazy_static::lazy_static;
-use std::collections::HashMap;
+
+use crate::lang::Language;
+use crate::{ParseOptions, UnrecognizedToken};
+
+/// Lexemes along with their spans in the input and the corrections made
+pub(crate) type Lexed = (Vec<Lexeme>, Vec<Range<usize>>, Vec<Correction>);
 
 lazy_static! {
-    /// Hashmap of keywords to the lexeme that they represent
-    /// Used as definitive source during lexeme
-    static ref KEYWORDS: HashMap<&'static str, Lexeme> = {
-        let mut map = HashMap::new();
-
-        map.insert("an", Lexeme::An);
-        map.insert("after", Lexeme::After);
-        map.insert("last", Lexeme::Last);
-        map.insert("this", Lexeme::This);
-        map.insert("next", Lexeme::Next);
-        map.insert("monday", Lexeme::Monday);
-        map.insert("tuesday", Lexeme::Tuesday);
-        map.insert("wednesday", Lexeme::Wednesday);
-        map.insert("thursday", Lexeme::Thursday);
-        map.insert("friday", Lexeme::Friday);
-        map.insert("saturday", Lexeme::Saturday);
-        map.insert("sunday", Lexeme::Sunday);
-        map.insert("january", Lexeme::January);
-        map.insert("february", Lexeme::February);
-        map.insert("march", Lexeme::March);
-        map.insert("april", Lexeme::April);
-        map.insert("may", Lexeme::May);
-        map.insert("june", Lexeme::June);
-        map.insert("july", Lexeme::July);
-        map.insert("august", Lexeme::August);
-        map.insert("september", Lexeme::September);
-        map.insert("october", Lexeme::October);
-        map.insert("november", Lexeme::November);
-        map.insert("december", Lexeme::December);
-        map.insert("jan", Lexeme::January);
-        map.insert("feb", Lexeme::February);
-        map.insert("mar", Lexeme::March);
-        map.insert("apr", Lexeme::April);
-        map.insert("jun", Lexeme::June);
-        map.insert("jul", Lexeme::July);
-        map.insert("aug", Lexeme::August);
-        map.insert("sep", Lexeme::September);
-        map.insert("oct", Lexeme::October);
-        map.insert("nov", Lexeme::November);
-        map.insert("dec", Lexeme::December);
-        map.insert("am", Lexeme::AM);
-        map.insert("pm", Lexeme::PM);
-        map.insert("day", Lexeme::Day);
-        map.insert("days", Lexeme::Day);
-        map.insert("week", Lexeme::Week);
-        map.insert("weeks", Lexeme::Week);
-        map.insert("month", Lexeme::Month);
-        map.insert("months", Lexeme::Month);
-        map.insert("year", Lexeme::Year);
-        map.insert("years", Lexeme::Year);
-        map.insert("hour", Lexeme::Hour);
-        map.insert("hours", Lexeme::Hour);
-        map.insert("min", Lexeme::Minute);
-        map.insert("mins", Lexeme::Minute);
-        map.insert("minute", Lexeme::Minute);
-        map.insert("minutes", Lexeme::Minute);
-        map.insert("and", Lexeme::And);
-        map.insert("today", Lexeme::Today);
-        map.insert("tomorrow", Lexeme::Tomorrow);
-        map.insert("yesterday", Lexeme::Yesterday);
-        map.insert("now", Lexeme::Now);
-        map.insert("from", Lexeme::From);
-        map.insert("zero", Lexeme::Zero);
-        map.insert("one", Lexeme::One);
-        map.insert("two", Lexeme::Two);
-        map.insert("three", Lexeme::Three);
-        map.insert("four", Lexeme::Four);
-        map.insert("five", Lexeme::Five);
-        map.insert("six", Lexeme::Six);
-        map.insert("seven", Lexeme::Seven);
-        map.insert("eight", Lexeme::Eight);
-        map.insert("nine", Lexeme::Nine);
-        map.insert("ten", Lexeme::Ten);
-        map.insert("eleven", Lexeme::Eleven);
-        map.insert("twelve", Lexeme::Twelve);
-        map.insert("thirteen", Lexeme::Thirteen);
-        map.insert("fourteen", Lexeme::Fourteen);
-        map.insert("fifteen", Lexeme::Fifteen);
-        map.insert("sixteen", Lexeme::Sixteen);
-        map.insert("seventeen", Lexeme::Seventeen);
-        map.insert("eighteen", Lexeme::Eighteen);
-        map.insert("nineteen", Lexeme::Nineteen);
-        map.insert("twenty", Lexeme::Twenty);
-        map.insert("thirty", Lexeme::Thirty);
-        map.insert("fourty", Lexeme::Fourty);
-        map.insert("fifty", Lexeme::Fifty);
-        map.insert("sixty", Lexeme::Sixty);
-        map.insert("seventy", Lexeme::Seventy);
-        map.insert("eighty", Lexeme::Eighty);
-        map.insert("ninety", Lexeme::Ninety);
-        map.insert("hundred", Lexeme::Hundred);
-        map.insert("thousand", Lexeme::Thousand);
-        map.insert("million", Lexeme::Million);
-        map.insert("billion", Lexeme::Billion);
-        map.insert("before", Lexeme::Before);
-        map.insert("ago", Lexeme::Ago);
-        map.insert("midnight", Lexeme::Midnight);
-        map.insert("noon", Lexeme::Noon);
-        map.insert("a", Lexeme::A);
-        map.insert("the", Lexeme::The);
-
-        map
+    /// The default options and their vocabulary, gathered once for the
+    /// lexing functions which don't take options
+    static ref DEFAULT: (ParseOptions, Vocabulary) = {
+        let options = ParseOptions::default();
+        let vocabulary = Vocabulary::new(&options);
+        (options, vocabulary)
     };
 }
 
@@ -148,7 +71,21 @@ pub enum Lexeme {
     December,
     AM,
     PM,
+    /// A number with a fractional part followed by a unit, holding the
+    /// whole part and the fractional part in millionths, e.g. "1.5 hours"
+    Decimal(u32, u32),
+    /// Fractions of a unit, e.g. "half an hour"
+    Half,
+    Quarter,
+    /// Minutes after an hour, e.g. "10 past the hour"
+    Past,
+    /// Counts given by words, e.g. "a couple of days"
+    Couple,
+    Few,
+    Several,
     Day,
+    /// A day which is neither on a weekend nor a holiday
+    BusinessDay,
     Week,
     Hour,
     Minute,
@@ -159,6 +96,60 @@ pub enum Lexeme {
     Ago,
     Midnight,
     Noon,
+    /// Parts of the day, e.g. "tomorrow evening"
+    Morning,
+    Afternoon,
+    Evening,
+    Night,
+    /// Today at night
+    Tonight,
+    At,
+    Plus,
+    /// Introduces a duration from now, e.g. "in 3 days"
+    In,
+    /// Follows an hour, e.g. "5 o'clock" or "17 Uhr"
+    OClock,
+    DayAfterTomorrow,
+    DayBeforeYesterday,
+    /// The unix epoch, 1970-01-01T00:00:00Z
+    Epoch,
+    LParen,
+    RParen,
+    Start,
+    End,
+    Of,
+    /// Joins a period to the day it follows, e.g. "a week on Tuesday"
+    On,
+    Leap,
+    /// A date registered by name on the options, holding its index
+    Named(usize),
+    /// Introduces a time for the whole preceding expression,
+    /// e.g. "3 hours after noon, which is at 5pm"
+    WhichIs,
+    /// Joins the start and end of a range, e.g. "monday to friday"
+    To,
+    Through,
+    Until,
+    /// Introduces a range, e.g. "between monday and friday"
+    Between,
+    /// Introduce a bound including its datetime, e.g. "no later than friday"
+    NoLaterThan,
+    NoEarlierThan,
+    /// Introduces a deadline, e.g. "by friday"
+    By,
+    /// Introduces a range up to now, e.g. "since tuesday"
+    Since,
+    /// Introduces a random datetime, e.g. "a random day between June 1 and June 30"
+    Random,
+    /// Introduces a recurrence, e.g. "every tuesday"
+    Every,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+    /// Three digit day of the year directly following a year and a dash,
+    /// e.g. the 150 in "2024-150"
+    DayOfYear(u32),
 
     // Number parsing lexemes
     Zero,
@@ -194,88 +185,820 @@ pub enum Lexeme {
     Million,
     Billion,
     Last,
+
+    // Ordinal number words, which are compounded with the tens, e.g.
+    // "twenty-first"
+    First,
+    Second,
+    Third,
+    Fourth,
+    Fifth,
+    Sixth,
+    Seventh,
+    Eighth,
+    Ninth,
+    Tenth,
+    Eleventh,
+    Twelfth,
+    Thirteenth,
+    Fourteenth,
+    Fifteenth,
+    Sixteenth,
+    Seventeenth,
+    Eighteenth,
+    Nineteenth,
+    Twentieth,
+    Thirtieth,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// A lexeme along with the byte range of the input it was lexed from
+pub struct Spanned {
+    pub lexeme: Lexeme,
+    pub span: Range<usize>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// An unrecognized word which was read as a similar word, when typo
+/// tolerance is enabled
+pub struct Correction {
+    /// The byte range of the word in the input
+    pub span: Range<usize>,
+    /// The word as written in the input
+    pub word: String,
+    /// The word it was read as
+    pub replacement: String,
 }
 
 impl Lexeme {
-    /// Lex a string into a list of Lexemes
-    pub fn lex_line(s: String) -> Result<Vec<Lexeme>, crate::Error> {
-        // Convert s to lowercase to remove case sensitive behaviour
-        let s = s.to_lowercase();
-
-        let mut lexemes = Vec::new(); // List of Lexemes
-        let chars = s.chars(); // Character iterator
-        let mut stack = String::with_capacity(10);
-
-        // Convenience closure which takes a reference to our stack
-        // and our lexemes, searches our keyword map for the stack,
-        // tries to convert the stack into a integer, adds the appropriate
-        // lexemes if successfully, and zeroes out the stack
-        let push_lexeme = |stack: &mut String, ls: &mut Vec<Lexeme>| {
-            if stack.is_empty() {
-                Ok(())
-            } else if let Some(l) = KEYWORDS.get(stack.as_str()) {
-                ls.push(*l);
-                *stack = String::with_capacity(10);
-                Ok(())
-            } else if let Ok(num) = stack.parse::<u32>() {
-                ls.push(Lexeme::Num(num));
-                stack.clear();
-                Ok(())
+    /// Lex an English string into a list of Lexemes
+    pub fn lex_line(s: &str) -> Result<Vec<Lexeme>, crate::Error> {
+        let (options, vocabulary) = &*DEFAULT;
+        Self::lex_vocabulary(s, options, vocabulary)
+    }
+
+    /// Lex a string into a list of Lexemes, using the vocabulary selected
+    /// by the options
+    pub fn lex_line_with(s: &str, options: &ParseOptions) -> Result<Vec<Lexeme>, crate::Error> {
+        Self::lex_vocabulary(s, options, &Vocabulary::new(options))
+    }
+
+    /// Lex a string into a list of Lexemes with a vocabulary gathered from
+    /// the options beforehand
+    fn lex_vocabulary(
+        s: &str,
+        options: &ParseOptions,
+        vocabulary: &Vocabulary,
+    ) -> Result<Vec<Lexeme>, crate::Error> {
+        let mut lexemes = Vec::new();
+        let emit = |lexeme, _| lexemes.push(lexeme);
+        Self::lex(s, options, vocabulary, emit, Self::abort)?;
+        Ok(lexemes)
+    }
+
+    /// Lex a string into a list of Lexemes, using the vocabulary selected
+    /// by the options, along with the corrections made to misspelled words
+    pub fn lex_line_corrected(
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<(Vec<Lexeme>, Vec<Correction>), crate::Error> {
+        let (lexemes, _, corrections) = Self::lex_compiled(s, options, &Vocabulary::new(options))?;
+        Ok((lexemes, corrections))
+    }
+
+    /// Lex a string with a vocabulary gathered from the options beforehand,
+    /// along with the span of each lexeme and the corrections made to
+    /// misspelled words
+    pub(crate) fn lex_compiled(
+        s: &str,
+        options: &ParseOptions,
+        vocabulary: &Vocabulary,
+    ) -> Result<Lexed, crate::Error> {
+        let mut lexemes = Vec::new();
+        let mut spans = Vec::new();
+        let emit = |lexeme, span| {
+            lexemes.push(lexeme);
+            spans.push(span);
+        };
+        let corrections = Self::lex(s, options, vocabulary, emit, Self::abort)?;
+
+        if options.strict_time {
+            check_clock_times(s, &lexemes, &spans)?;
+        }
+        Ok((lexemes, spans, corrections))
+    }
+
+    /// Lex an English string into a list of Lexemes, each paired with the
+    /// byte range of the input that produced it
+    pub fn lex_line_spanned(s: &str) -> Result<Vec<Spanned>, crate::Error> {
+        let (options, vocabulary) = &*DEFAULT;
+        Self::lex_spanned_vocabulary(s, options, vocabulary)
+    }
+
+    /// Lex a string into a list of Lexemes, each paired with the byte
+    /// range of the input that produced it, using the vocabulary selected
+    /// by the options
+    pub fn lex_line_spanned_with(
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<Vec<Spanned>, crate::Error> {
+        Self::lex_spanned_vocabulary(s, options, &Vocabulary::new(options))
+    }
+
+    /// Lex a string into a list of spanned Lexemes with a vocabulary
+    /// gathered from the options beforehand
+    fn lex_spanned_vocabulary(
+        s: &str,
+        options: &ParseOptions,
+        vocabulary: &Vocabulary,
+    ) -> Result<Vec<Spanned>, crate::Error> {
+        let mut lexemes = Vec::new();
+        let emit = |lexeme, span| lexemes.push(Spanned { lexeme, span });
+        Self::lex(s, options, vocabulary, emit, Self::abort)?;
+        Ok(lexemes)
+    }
+
+    /// Lex a string like [`Lexeme::lex_line_spanned_with`], but skip over
+    /// unrecognized words instead of stopping at the first one, returning
+    /// every one of them so that all problems can be reported at once
+    ///
+    /// ```rust
+    /// use fuzzydate::lexer::Lexeme;
+    /// use fuzzydate::ParseOptions;
+    ///
+    /// let input = "3 dyas aftr tomorrow";
+    /// let (lexemes, unrecognized) = Lexeme::lex_line_recovering(input, &ParseOptions::new());
+    /// assert_eq!(lexemes.len(), 2);
+    ///
+    /// let words: Vec<_> = unrecognized.iter().map(|token| &input[token.span.clone()]).collect();
+    /// assert_eq!(words, ["dyas", "aftr"]);
+    /// assert_eq!(unrecognized[0].suggestion.as_deref(), Some("days"));
+    /// ```
+    pub fn lex_line_recovering(
+        s: &str,
+        options: &ParseOptions,
+    ) -> (Vec<Spanned>, Vec<UnrecognizedToken>) {
+        let vocabulary = Vocabulary::new(options);
+        let mut lexemes = Vec::new();
+        let mut unrecognized = Vec::new();
+        let emit = |lexeme, span| lexemes.push(Spanned { lexeme, span });
+        let skip = |token| {
+            unrecognized.push(token);
+            Ok(())
+        };
+
+        // Unrecognized words are the only errors of the lexer
+        let _ = Self::lex(s, options, &vocabulary, emit, skip);
+        (lexemes, unrecognized)
+    }
+
+    /// Stop lexing at an unrecognized word
+    fn abort(token: UnrecognizedToken) -> Result<(), crate::Error> {
+        Err(crate::Error::UnrecognizedToken(token))
+    }
+
+    /// Lex a string, calling `emit` with every lexeme and its byte range,
+    /// and returning any corrections made to misspelled words
+    fn lex(
+        s: &str,
+        options: &ParseOptions,
+        vocabulary: &Vocabulary,
+        mut emit: impl FnMut(Lexeme, Range<usize>),
+        mut unrecognized: impl FnMut(UnrecognizedToken) -> Result<(), crate::Error>,
+    ) -> Result<Vec<Correction>, crate::Error> {
+        let lang = options.language;
+
+        // The last lexeme emitted, as some words are read differently after
+        // a day
+        let last = Cell::new(None);
+        let mut emit = |lexeme, span| {
+            last.set(Some(lexeme));
+            emit(lexeme, span);
+        };
+
+        // Synonyms registered on the options take precedence over the
+        // language's own vocabulary
+        let lookup = |word: &str| {
+            options
+                .synonyms
+                .get(word)
+                .copied()
+                .or_else(|| lang.lookup(word))
+                .or_else(|| vocabulary.named.get(word).copied())
+        };
+
+        // Scratch buffer used to lowercase tokens which contain uppercase
+        // characters, reused so that we allocate at most once per line
+        let mut lower = String::new();
+        let mut corrections = Vec::new();
+
+        // Convenience closure which takes the span of the current token,
+        // searches the vocabulary for it, tries to convert it into an
+        // integer, and emits the appropriate lexeme if successful
+        let mut push_lexeme = |span: Range<usize>, emit: &mut dyn FnMut(Lexeme, Range<usize>)| {
+            let token = &s[span.clone()];
+            if token.is_empty() {
+                return Ok(());
+            }
+
+            // Convert token to lowercase to remove case sensitive behaviour,
+            // only copying when the token isn't lowercase already
+            let token = if token.chars().any(char::is_uppercase) {
+                lower.clear();
+                lower.extend(token.chars().flat_map(char::to_lowercase));
+                lower.as_str()
             } else {
-                Err(crate::Error::UnrecognizedToken(stack.clone()))
+                token
+            };
+
+            // A part of the day directly after a day, e.g. the German
+            // "heute morgen", is this morning rather than tomorrow
+            if last.get().is_some_and(is_day) {
+                let part = lang
+                    .day_parts_after_day()
+                    .iter()
+                    .find(|(word, _)| *word == token);
+                if let Some(&(_, l)) = part {
+                    emit(l, span);
+                    return Ok(());
+                }
+            }
+
+            if let Some(l) = lookup(token) {
+                emit(l, span);
+                return Ok(());
+            }
+
+            if let Ok(num) = token.parse::<u32>() {
+                if is_day_of_year(s, &span) {
+                    emit(Lexeme::DayOfYear(num), span);
+                } else {
+                    emit(Lexeme::Num(num), span);
+                }
+                return Ok(());
+            }
+
+            // A number directly followed by a keyword or unit suffix,
+            // e.g. "12h", is split into the number and the keyword
+            let digits = token.bytes().take_while(u8::is_ascii_digit).count();
+            if digits > 0 {
+                let suffix = &token[digits..];
+
+                // An ordinal number, e.g. "3rd", reads as the number itself
+                if lang.ordinal_suffixes().contains(&suffix) {
+                    if let Ok(num) = token[..digits].parse::<u32>() {
+                        emit(Lexeme::Num(num), span);
+                        return Ok(());
+                    }
+                }
+
+                let keyword = lookup(suffix).or_else(|| lang.unit_suffixes().get(suffix).copied());
+
+                if let (Ok(num), Some(l)) = (token[..digits].parse::<u32>(), keyword) {
+                    emit(Lexeme::Num(num), span.start..span.start + digits);
+                    emit(l, span.start + digits..span.end);
+                    return Ok(());
+                }
             }
+
+            // Compact durations, e.g. "1h30m", are split into numbers and
+            // units
+            let unit = |suffix: &str| {
+                lookup(suffix)
+                    .or_else(|| lang.unit_suffixes().get(suffix).copied())
+                    .filter(|&l| is_unit(l))
+            };
+            if let Some(components) = compact_duration(token, unit) {
+                for (num, unit, range) in components {
+                    emit(
+                        Lexeme::Num(num),
+                        span.start + range.start..span.start + range.end,
+                    );
+                    emit(unit.0, span.start + unit.1.start..span.start + unit.1.end);
+                }
+                return Ok(());
+            }
+
+            // ISO 8601 durations, e.g. "P1Y2M" or "PT30M", are split into
+            // numbers and units
+            if let Some(components) = iso_duration(token) {
+                for (num, unit, range) in components {
+                    emit(
+                        Lexeme::Num(num),
+                        span.start + range.start..span.start + range.end - 1,
+                    );
+                    emit(unit, span.start + range.end - 1..span.start + range.end);
+                }
+                return Ok(());
+            }
+
+            // Misspelled words may be read as the closest known word
+            if options.typo_tolerant && !options.minimal_grammar {
+                if let Some((word, l)) = closest_word(token, options) {
+                    corrections.push(Correction {
+                        span: span.clone(),
+                        word: token.to_string(),
+                        replacement: word.to_string(),
+                    });
+                    emit(l, span);
+                    return Ok(());
+                }
+            }
+
+            unrecognized(UnrecognizedToken {
+                token: s[span.clone()].to_string(),
+                suggestion: closest_word(token, options).map(|(word, _)| word.to_string()),
+                span,
+            })
         };
 
+        // Byte index where the current token starts
+        let mut start = 0;
+
         // While we have characters left in the string
-        for c in chars {
-            // Whitespace always separates lexemes, push whatever we have
-            // on the stack and continue to the next character
-            if c.is_whitespace() {
-                push_lexeme(&mut stack, &mut lexemes)?;
+        for (i, c) in s.char_indices() {
+            // Skip over the remainder of a matched phrase
+            if i < start {
                 continue;
             }
 
-            match c {
-                // Comma separates lexemes, push stack and add comma
-                ',' => {
-                    push_lexeme(&mut stack, &mut lexemes)?;
-                    lexemes.push(Lexeme::Comma);
-                }
-                // Colon separates lexemes, push stack and add colon
-                ':' => {
-                    push_lexeme(&mut stack, &mut lexemes)?;
-                    lexemes.push(Lexeme::Colon);
+            // A decimal number is only read before a unit, since dots
+            // otherwise separate the fields of dates, e.g. "19.12.2023"
+            if i == start {
+                if let Some((len, whole, millionths)) = decimal(&s[i..]) {
+                    let rest = &s[i + len..];
+                    let word: String = rest
+                        .trim_start()
+                        .chars()
+                        .take_while(|c| c.is_alphabetic())
+                        .flat_map(char::to_lowercase)
+                        .collect();
+
+                    if lookup(&word).is_some_and(is_unit) {
+                        emit(Lexeme::Decimal(whole, millionths), i..i + len);
+                        start = i + len;
+                        continue;
+                    }
+
+                    // A unit suffix directly following the number, e.g. "1.5h"
+                    let suffix = lang.unit_suffixes().get(word.as_str()).copied();
+                    if let Some(unit) = suffix.filter(|_| rest.starts_with(char::is_alphabetic)) {
+                        let end = i
+                            + len
+                            + rest
+                                .find(|c: char| !c.is_alphabetic())
+                                .unwrap_or(rest.len());
+                        emit(Lexeme::Decimal(whole, millionths), i..i + len);
+                        emit(unit, i + len..end);
+                        start = end;
+                        continue;
+                    }
                 }
-                // Slash separates lexemes, push stack and add slash
-                '/' => {
-                    push_lexeme(&mut stack, &mut lexemes)?;
-                    lexemes.push(Lexeme::Slash);
+            }
+
+            // Phrases may span several tokens, so they are matched before
+            // the input is split, preferring the longest match
+            if i == start && !c.is_whitespace() {
+                let phrase = vocabulary
+                    .phrases
+                    .iter()
+                    .filter_map(|(phrase, l)| Some((match_phrase(&s[i..], phrase)?, *l)))
+                    .max_by_key(|&(len, _)| len);
+
+                if let Some((len, l)) = phrase {
+                    emit(l, i..i + len);
+                    start = i + len;
+                    continue;
                 }
-                // Dash separates lexemes, push stack and add dash
-                '-' => {
-                    push_lexeme(&mut stack, &mut lexemes)?;
-                    lexemes.push(Lexeme::Dash);
+            }
+
+            // Whitespace always separates lexemes, push whatever token we
+            // have and continue to the next character
+            if c.is_whitespace() {
+                push_lexeme(start..i, &mut emit)?;
+                start = i + c.len_utf8();
+                continue;
+            }
+
+            // A period may end an abbreviated month or weekday rather than
+            // separate lexemes, e.g. "Jan. 5" or "Mon. at 5pm"
+            if c == '.' && is_abbreviation(lang, &s[start..i]) {
+                push_lexeme(start..i, &mut emit)?;
+                start = i + c.len_utf8();
+                continue;
+            }
+
+            let separator = match c {
+                // Comma separates lexemes, push token and add comma
+                ',' => Lexeme::Comma,
+                // Colon separates lexemes, push token and add colon
+                ':' => Lexeme::Colon,
+                // Slash separates lexemes, push token and add slash
+                '/' => Lexeme::Slash,
+                // Dash separates lexemes, push token and add dash
+                '-' => Lexeme::Dash,
+                // Dot separates lexemes, push token and add dot
+                '.' => Lexeme::Dot,
+                // Plus separates lexemes, push token and add plus
+                '+' => Lexeme::Plus,
+                // Parentheses separate lexemes, push token and add them
+                '(' => Lexeme::LParen,
+                ')' => Lexeme::RParen,
+                // Else the character is part of the current token
+                _ => continue,
+            };
+
+            push_lexeme(start..i, &mut emit)?;
+            emit(separator, i..i + c.len_utf8());
+            start = i + c.len_utf8();
+        }
+
+        // If any characters remaining in the token, push them
+        push_lexeme(start..s.len(), &mut emit)?;
+
+        Ok(corrections)
+    }
+}
+
+/// The words and phrases the options add to their language, gathered once
+/// rather than for every word of the input
+#[derive(Debug, Clone)]
+pub(crate) struct Vocabulary {
+    /// Names of the registered named dates, by the first date taking them
+    named: BTreeMap<String, Lexeme>,
+    /// Phrases of the language, followed by those among the synonyms and
+    /// the names of named dates
+    phrases: Vec<(Cow<'static, str>, Lexeme)>,
+}
+
+impl Vocabulary {
+    pub(crate) fn new(options: &ParseOptions) -> Self {
+        let mut named = BTreeMap::new();
+        for (i, named_date) in options.named_dates.iter().enumerate() {
+            for name in named_date.names() {
+                named.entry(name.to_string()).or_insert(Lexeme::Named(i));
+            }
+        }
+
+        let synonyms = options
+            .synonyms
+            .iter()
+            .filter(|(word, _)| !word.chars().all(char::is_alphanumeric))
+            .map(|(word, &l)| (Cow::Owned(word.clone()), l));
+
+        let named_phrases = options
+            .named_dates
+            .iter()
+            .enumerate()
+            .flat_map(|(i, named)| named.names().into_iter().map(move |name| (name, i)))
+            .filter(|(name, _)| name.contains(' '))
+            .map(|(name, i)| (Cow::Owned(name.to_string()), Lexeme::Named(i)));
+
+        let phrases = options
+            .language
+            .phrases()
+            .iter()
+            .map(|&(phrase, l)| (Cow::Borrowed(phrase), l))
+            .chain(synonyms)
+            .chain(named_phrases)
+            .collect();
+
+        Self { named, phrases }
+    }
+}
+
+/// Whether a token is an abbreviated month or weekday name, so that a
+/// period directly after it ends the abbreviation rather than separating
+/// a date
+fn is_abbreviation(lang: &dyn Language, token: &str) -> bool {
+    lang.abbreviations()
+        .contains_key(token.to_lowercase().as_str())
+}
+
+/// Find the single known word closest to a misspelled lowercase word.
+///
+/// Only alphabetic words of at least four characters are corrected, by at
+/// most one edit, or two for words of seven characters or more. Words with
+/// several equally close candidates are left alone, as are plurals of known
+/// words, which mean something else, e.g. "seconds" isn't the ordinal
+/// "second".
+fn closest_word<'a>(word: &str, options: &'a ParseOptions) -> Option<(&'a str, Lexeme)> {
+    let len = word.chars().count();
+    if len < 4 || !word.chars().all(char::is_alphabetic) {
+        return None;
+    }
+    let singular = word.strip_suffix('s');
+    let max = if len >= 7 { 2 } else { 1 };
+
+    let lang = options.language;
+    let tables = [
+        lang.keywords(),
+        lang.months(),
+        lang.weekdays(),
+        lang.abbreviations(),
+        lang.numbers(),
+    ];
+    let candidates = tables
+        .into_iter()
+        .flat_map(|table| table.iter().map(|(&k, &l)| (k, l)))
+        .chain(options.synonyms.iter().map(|(k, &l)| (k.as_str(), l)))
+        .filter(|&(k, _)| k.chars().count() >= 3 && singular != Some(k));
+
+    let mut best: Option<(usize, &str, Lexeme)> = None;
+    let mut tied = false;
+    for (candidate, l) in candidates {
+        let distance = edit_distance(word, candidate);
+        if distance > max {
+            continue;
+        }
+
+        match best {
+            // Prefer the first candidate alphabetically so that the
+            // reported replacement doesn't depend on hashing order
+            Some((d, c, bl)) if d == distance => {
+                if bl != l {
+                    tied = true;
+                } else if candidate < c {
+                    best = Some((distance, candidate, l));
                 }
-                // Dot separates lexemes, push stack and add dash
-                '.' => {
-                    push_lexeme(&mut stack, &mut lexemes)?;
-                    lexemes.push(Lexeme::Dot);
+            }
+            Some((d, ..)) if d < distance => {}
+            _ => {
+                best = Some((distance, candidate, l));
+                tied = false;
+            }
+        }
+    }
+
+    match best {
+        Some((_, candidate, l)) if !tied => Some((candidate, l)),
+        _ => None,
+    }
+}
+
+/// The number of insertions, deletions, substitutions, and transpositions
+/// of adjacent characters needed to turn one word into another
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // Three rows of the distance matrix, for the transposition lookback
+    let mut prev2 = vec![0; b.len() + 1];
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                curr[j] = curr[j].min(prev2[j - 2] + 1);
+            }
+        }
+
+        core::mem::swap(&mut prev2, &mut prev);
+        core::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+/// Match a lowercase phrase at the start of the input, ignoring case and
+/// allowing any amount of whitespace wherever the phrase has a space.
+/// Returns the length in bytes of the matched input.
+fn match_phrase(input: &str, phrase: &str) -> Option<usize> {
+    let mut chars = input.char_indices().peekable();
+
+    for p in phrase.chars() {
+        if p == ' ' {
+            let mut spaces = 0;
+            while chars.next_if(|&(_, c)| c.is_whitespace()).is_some() {
+                spaces += 1;
+            }
+
+            if spaces == 0 {
+                return None;
+            }
+            continue;
+        }
+
+        let (_, c) = chars.next()?;
+        if !c.to_lowercase().eq(core::iter::once(p)) {
+            return None;
+        }
+    }
+
+    // The phrase must not end in the middle of a word
+    match chars.peek() {
+        Some(&(_, c)) if c.is_alphanumeric() => None,
+        Some(&(end, _)) => Some(end),
+        None => Some(input.len()),
+    }
+}
+
+/// Fail at the first clock time without am or pm which is past the end of
+/// a 24 hour clock, e.g. "25:99" or "24 o'clock"
+fn check_clock_times(
+    s: &str,
+    lexemes: &[Lexeme],
+    spans: &[Range<usize>],
+) -> Result<(), crate::Error> {
+    for (i, window) in lexemes.windows(2).enumerate() {
+        let (valid, end) = match *window {
+            [Lexeme::Num(hour), Lexeme::Colon] => match lexemes.get(i + 2) {
+                Some(&Lexeme::Num(min)) => {
+                    let am_pm = matches!(lexemes.get(i + 3), Some(Lexeme::AM | Lexeme::PM));
+                    (am_pm || (hour < 24 && min < 60), i + 2)
                 }
-                // Else just add the character to our stack
-                _ => stack.push(c),
+                _ => continue,
+            },
+            [Lexeme::Num(hour), Lexeme::OClock] => (hour < 24, i + 1),
+            _ => continue,
+        };
+
+        if !valid {
+            let span = spans[i].start..spans[end].end;
+            return Err(crate::Error::invalid_date_at(
+                format!("{} is not a time on a 24 hour clock", &s[span.clone()]),
+                span,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether the numeric token at `span` is a three digit day of the year
+/// directly following a four digit year and a dash, as in "2024-150"
+fn is_day_of_year(s: &str, span: &Range<usize>) -> bool {
+    if span.len() != 3 {
+        return false;
+    }
+
+    let Some(before) = s[..span.start].strip_suffix('-') else {
+        return false;
+    };
+
+    // The word before the dash must be exactly four digits
+    let word = before
+        .bytes()
+        .rev()
+        .take_while(u8::is_ascii_alphanumeric)
+        .count();
+    word == 4
+        && before[before.len() - 4..]
+            .bytes()
+            .all(|b| b.is_ascii_digit())
+}
+
+/// Whether a lexeme names a day, e.g. "today" or "monday"
+fn is_day(l: Lexeme) -> bool {
+    matches!(
+        l,
+        Lexeme::Today
+            | Lexeme::Tomorrow
+            | Lexeme::Yesterday
+            | Lexeme::DayAfterTomorrow
+            | Lexeme::DayBeforeYesterday
+            | Lexeme::Monday
+            | Lexeme::Tuesday
+            | Lexeme::Wednesday
+            | Lexeme::Thursday
+            | Lexeme::Friday
+            | Lexeme::Saturday
+            | Lexeme::Sunday
+    )
+}
+
+/// Whether a lexeme is a unit of time, e.g. "hours"
+fn is_unit(l: Lexeme) -> bool {
+    matches!(
+        l,
+        Lexeme::Minute
+            | Lexeme::Hour
+            | Lexeme::Day
+            | Lexeme::BusinessDay
+            | Lexeme::Week
+            | Lexeme::Month
+            | Lexeme::Year
+    )
+}
+
+/// Split a lowercased compact duration such as "1h30m" into its numbers
+/// and units, along with the byte range of each
+#[allow(clippy::type_complexity)]
+fn compact_duration(
+    token: &str,
+    unit: impl Fn(&str) -> Option<Lexeme>,
+) -> Option<Vec<(u32, (Lexeme, Range<usize>), Range<usize>)>> {
+    let mut components = Vec::new();
+    let mut start = 0;
+
+    while start < token.len() {
+        let digits = token[start..]
+            .bytes()
+            .take_while(u8::is_ascii_digit)
+            .count();
+        let letters = token[start + digits..]
+            .chars()
+            .take_while(|c| c.is_alphabetic())
+            .map(char::len_utf8)
+            .sum::<usize>();
+        if digits == 0 || letters == 0 {
+            return None;
+        }
+
+        let number = start..start + digits;
+        let suffix = start + digits..start + digits + letters;
+        let num = token[number.clone()].parse().ok()?;
+        components.push((num, (unit(&token[suffix.clone()])?, suffix.clone()), number));
+        start = suffix.end;
+    }
+
+    (components.len() > 1).then_some(components)
+}
+
+/// Read a decimal number such as "1.5" at the start of the input, returning
+/// its length, its whole part, and its fractional part in millionths
+fn decimal(s: &str) -> Option<(usize, u32, u32)> {
+    let whole = s.bytes().take_while(u8::is_ascii_digit).count();
+    if whole == 0 || s.as_bytes().get(whole) != Some(&b'.') {
+        return None;
+    }
+
+    let fraction = s[whole + 1..]
+        .bytes()
+        .take_while(u8::is_ascii_digit)
+        .count();
+    if fraction == 0 || fraction > 6 {
+        return None;
+    }
+
+    let digits = &s[whole + 1..whole + 1 + fraction];
+    let millionths = digits.parse::<u32>().ok()? * 10u32.pow(6 - fraction as u32);
+    Some((whole + 1 + fraction, s[..whole].parse().ok()?, millionths))
+}
+
+/// Split a lowercased ISO 8601 duration such as "p1y2m3dt4h5m" into its
+/// numbers and units, along with the byte range of each component.
+/// Durations are counted in minutes, so seconds are only accepted when they
+/// are whole minutes, e.g. the "pt5400s" written by chrono.
+fn iso_duration(token: &str) -> Option<Vec<(u32, Lexeme, Range<usize>)>> {
+    let rest = token.strip_prefix('p')?;
+    if rest.is_empty() || !rest.is_ascii() {
+        return None;
+    }
+
+    let mut components = Vec::new();
+    let mut time = false;
+    let mut start = 1;
+
+    for (i, c) in rest.char_indices().map(|(i, c)| (i + 1, c)) {
+        if c.is_ascii_digit() {
+            continue;
+        }
+
+        if c == 't' && start == i && !time {
+            time = true;
+            start = i + 1;
+            continue;
+        }
+
+        let unit = match (c, time) {
+            ('y', false) => Lexeme::Year,
+            ('m', false) => Lexeme::Month,
+            ('w', false) => Lexeme::Week,
+            ('d', false) => Lexeme::Day,
+            ('h', true) => Lexeme::Hour,
+            ('m', true) | ('s', true) => Lexeme::Minute,
+            _ => return None,
+        };
+
+        let mut num: u32 = token[start..i].parse().ok()?;
+        if c == 's' {
+            if !num.is_multiple_of(60) {
+                return None;
             }
+            num /= 60;
         }
 
-        // If any characters remaining on our stack, push them
-        push_lexeme(&mut stack, &mut lexemes)?;
+        components.push((num, unit, start..i + 1));
+        start = i + 1;
+    }
 
-        Ok(lexemes)
+    // Dangling digits or designators without any components are invalid
+    if start != token.len() || components.is_empty() {
+        return None;
     }
+
+    Some(components)
 }
 
 #[test]
 fn test_simple_date() {
-    let input = "5/2/2022".to_string();
+    let input = "5/2/2022";
     assert_eq!(
         Ok(vec![
             Lexeme::Num(5),
@@ -290,7 +1013,7 @@ fn test_simple_date() {
 
 #[test]
 fn test_complex_relative_date_time() {
-    let input = "fifty-five days from january 1, 2010 5:00".to_string();
+    let input = "fifty-five days from january 1, 2010 5:00";
     assert_eq!(
         Ok(vec![
             Lexeme::Fifty,
@@ -312,6 +1035,294 @@ fn test_complex_relative_date_time() {
 
 #[test]
 fn test_unknown_token() {
-    let input = "Hello World".to_string();
+    let input = "Hello World";
     assert!(Lexeme::lex_line(input).is_err());
 }
+
+#[test]
+fn test_mixed_case() {
+    let input = "Next FRIDAY";
+    assert_eq!(
+        Ok(vec![Lexeme::Next, Lexeme::Friday]),
+        Lexeme::lex_line(input)
+    );
+}
+
+#[test]
+fn test_spanned() {
+    let input = "Next  friday,5:00";
+    let spanned = Lexeme::lex_line_spanned(input).unwrap();
+    let expected = vec![
+        (Lexeme::Next, "Next"),
+        (Lexeme::Friday, "friday"),
+        (Lexeme::Comma, ","),
+        (Lexeme::Num(5), "5"),
+        (Lexeme::Colon, ":"),
+        (Lexeme::Num(0), "00"),
+    ];
+
+    assert_eq!(spanned.len(), expected.len());
+    for (token, (lexeme, text)) in spanned.iter().zip(expected) {
+        assert_eq!(token.lexeme, lexeme);
+        assert_eq!(&input[token.span.clone()], text);
+    }
+}
+
+#[test]
+fn test_ordinal_date_with_offset() {
+    let input = "2024-150 + 12h";
+    assert_eq!(
+        Ok(vec![
+            Lexeme::Num(2024),
+            Lexeme::Dash,
+            Lexeme::DayOfYear(150),
+            Lexeme::Plus,
+            Lexeme::Num(12),
+            Lexeme::Hour,
+        ]),
+        Lexeme::lex_line(input)
+    );
+}
+
+#[test]
+fn test_unit_suffix_needs_number() {
+    assert!(Lexeme::lex_line("5 d after today").is_err());
+    assert!(Lexeme::lex_line("in 3 h").is_err());
+    assert_eq!(
+        Lexeme::lex_line("5d"),
+        Ok(vec![Lexeme::Num(5), Lexeme::Day])
+    );
+}
+
+#[test]
+fn test_day_of_year_needs_year() {
+    let input = "12-150";
+    assert_eq!(
+        Ok(vec![Lexeme::Num(12), Lexeme::Dash, Lexeme::Num(150)]),
+        Lexeme::lex_line(input)
+    );
+}
+
+#[test]
+fn test_iso_duration() {
+    let input = "P1Y2MT3M";
+    let spanned = Lexeme::lex_line_spanned(input).unwrap();
+    let expected = vec![
+        (Lexeme::Num(1), "1"),
+        (Lexeme::Year, "Y"),
+        (Lexeme::Num(2), "2"),
+        (Lexeme::Month, "M"),
+        (Lexeme::Num(3), "3"),
+        (Lexeme::Minute, "M"),
+    ];
+
+    assert_eq!(spanned.len(), expected.len());
+    for (token, (lexeme, text)) in spanned.iter().zip(expected) {
+        assert_eq!(token.lexeme, lexeme);
+        assert_eq!(&input[token.span.clone()], text);
+    }
+}
+
+#[test]
+fn test_iso_duration_seconds() {
+    assert_eq!(
+        Lexeme::lex_line("PT5400S"),
+        Ok(vec![Lexeme::Num(90), Lexeme::Minute])
+    );
+    assert_eq!(
+        Lexeme::lex_line("PT1H30M0S"),
+        Ok(vec![
+            Lexeme::Num(1),
+            Lexeme::Hour,
+            Lexeme::Num(30),
+            Lexeme::Minute,
+            Lexeme::Num(0),
+            Lexeme::Minute
+        ])
+    );
+}
+
+#[test]
+fn test_decimal() {
+    assert_eq!(
+        Lexeme::lex_line("1.5 hours"),
+        Ok(vec![Lexeme::Decimal(1, 500_000), Lexeme::Hour])
+    );
+    assert_eq!(
+        Lexeme::lex_line("2.25d"),
+        Ok(vec![Lexeme::Decimal(2, 250_000), Lexeme::Day])
+    );
+
+    // Dots outside of durations still separate fields
+    assert_eq!(
+        Lexeme::lex_line("19.12"),
+        Ok(vec![Lexeme::Num(19), Lexeme::Dot, Lexeme::Num(12)])
+    );
+}
+
+#[test]
+fn test_abbreviation_period() {
+    assert_eq!(
+        Lexeme::lex_line("Jan. 5"),
+        Ok(vec![Lexeme::January, Lexeme::Num(5)])
+    );
+    assert_eq!(
+        Lexeme::lex_line("Mon.,Sep.3"),
+        Ok(vec![
+            Lexeme::Monday,
+            Lexeme::Comma,
+            Lexeme::September,
+            Lexeme::Num(3)
+        ])
+    );
+
+    // The period isn't part of the span of the word
+    let spanned = Lexeme::lex_line_spanned("Fri. 5pm").unwrap();
+    assert_eq!(spanned[0].span, 0..3);
+}
+
+#[test]
+fn test_compact_duration() {
+    assert_eq!(
+        Lexeme::lex_line("1h30m"),
+        Ok(vec![
+            Lexeme::Num(1),
+            Lexeme::Hour,
+            Lexeme::Num(30),
+            Lexeme::Minute
+        ])
+    );
+    assert_eq!(
+        Lexeme::lex_line("2d4h"),
+        Ok(vec![
+            Lexeme::Num(2),
+            Lexeme::Day,
+            Lexeme::Num(4),
+            Lexeme::Hour
+        ])
+    );
+
+    // Every component needs a unit
+    assert!(Lexeme::lex_line("1h30").is_err());
+    assert!(Lexeme::lex_line("1h30x").is_err());
+}
+
+#[test]
+fn test_invalid_iso_duration() {
+    assert!(Lexeme::lex_line("P").is_err());
+    assert!(Lexeme::lex_line("P1").is_err());
+    assert!(Lexeme::lex_line("P1H").is_err());
+    assert!(Lexeme::lex_line("PT").is_err());
+    assert!(Lexeme::lex_line("PT90S").is_err());
+    assert!(Lexeme::lex_line("P1S").is_err());
+}
+
+#[test]
+fn test_language_tables() {
+    use crate::lang::{English, Language};
+
+    assert_eq!(English.lookup("tomorrow"), Some(Lexeme::Tomorrow));
+    assert_eq!(English.lookup("sep"), Some(Lexeme::September));
+
+    let options = ParseOptions::new().language(&English);
+    assert_eq!(
+        Lexeme::lex_line_with("next friday 12h", &options).unwrap(),
+        Lexeme::lex_line("next friday 12h").unwrap()
+    );
+}
+
+#[test]
+fn test_match_phrase() {
+    assert_eq!(
+        match_phrase("Pasado  Mañana a las 5", "pasado mañana"),
+        Some(15)
+    );
+    assert_eq!(match_phrase("après-demain", "après-demain"), Some(13));
+    assert_eq!(match_phrase("a lasagna", "a las"), None);
+    assert_eq!(match_phrase("pasado", "pasado mañana"), None);
+}
+
+#[test]
+fn test_synonyms() {
+    let options = ParseOptions::new()
+        .synonym("tmrw", Lexeme::Tomorrow)
+        .synonym("Wk", Lexeme::Week)
+        .synonym("day  after   tmrw", Lexeme::DayAfterTomorrow);
+
+    assert_eq!(
+        Lexeme::lex_line_with("2wk after TMRW", &options).unwrap(),
+        vec![
+            Lexeme::Num(2),
+            Lexeme::Week,
+            Lexeme::After,
+            Lexeme::Tomorrow
+        ]
+    );
+    assert_eq!(
+        Lexeme::lex_line_with("the day after tmrw", &options).unwrap(),
+        vec![Lexeme::The, Lexeme::DayAfterTomorrow]
+    );
+    assert!(Lexeme::lex_line("tmrw").is_err());
+}
+
+#[test]
+fn test_typo_tolerance() {
+    let options = ParseOptions::new().typo_tolerant(true);
+    let (lexemes, corrections) =
+        Lexeme::lex_line_corrected("tommorrow febuary wendsday", &options).unwrap();
+
+    assert_eq!(
+        lexemes,
+        vec![Lexeme::Tomorrow, Lexeme::February, Lexeme::Wednesday]
+    );
+    assert_eq!(corrections.len(), 3);
+    assert_eq!(corrections[0].span, 0..9);
+    assert_eq!(corrections[0].word, "tommorrow");
+    assert_eq!(corrections[0].replacement, "tomorrow");
+
+    // Off by default
+    assert!(Lexeme::lex_line("tommorrow").is_err());
+    // Short words are never corrected
+    assert!(Lexeme::lex_line_with("nxt", &options).is_err());
+}
+
+#[test]
+fn test_recovering() {
+    let input = "3 dyas aftr nxt Friday at 5pm";
+    let (lexemes, unrecognized) = Lexeme::lex_line_recovering(input, &ParseOptions::new());
+
+    let lexemes: Vec<_> = lexemes.into_iter().map(|s| (s.lexeme, s.span)).collect();
+    assert_eq!(
+        lexemes,
+        vec![
+            (Lexeme::Num(3), 0..1),
+            (Lexeme::Friday, 16..22),
+            (Lexeme::At, 23..25),
+            (Lexeme::Num(5), 26..27),
+            (Lexeme::PM, 27..29),
+        ]
+    );
+
+    let spans: Vec<_> = unrecognized.iter().map(|t| t.span.clone()).collect();
+    assert_eq!(spans, vec![2..6, 7..11, 12..15]);
+    assert_eq!(unrecognized[1].token, "aftr");
+    assert_eq!(unrecognized[1].suggestion.as_deref(), Some("after"));
+
+    // Misspellings are still corrected with typo tolerance
+    let options = ParseOptions::new().typo_tolerant(true);
+    let (lexemes, unrecognized) = Lexeme::lex_line_recovering(input, &options);
+    assert_eq!(lexemes.len(), 7);
+    assert_eq!(unrecognized.len(), 1);
+
+    let (lexemes, unrecognized) = Lexeme::lex_line_recovering("tomorrow", &options);
+    assert_eq!(lexemes[0].lexeme, Lexeme::Tomorrow);
+    assert!(unrecognized.is_empty());
+}
+
+#[test]
+fn test_edit_distance() {
+    assert_eq!(edit_distance("tomorrow", "tomorrow"), 0);
+    assert_eq!(edit_distance("tommorrow", "tomorrow"), 1);
+    assert_eq!(edit_distance("wendsday", "wednesday"), 2);
+    assert_eq!(edit_distance("", "abc"), 3);
+}
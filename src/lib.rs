@@ -1,3 +1,4 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![allow(clippy::needless_doctest_main)]
 //! # FuzzyDate: Date Input for Humans
 //!
@@ -14,6 +15,8 @@
 //! ## Example
 //!
 //! ```rust
+//! # #[cfg(feature = "clock")]
+//! # {
 //! use fuzzydate::parse;
 //! use chrono::NaiveDateTime;
 //!
@@ -22,21 +25,120 @@
 //!     let date = parse(date_string).unwrap();
 //!     println!("{:?}", date);
 //! }
+//! # main();
+//! # }
 //! ```
 //!
 //! Any relevant date time information not specified is assumed to be
 //! the value of the current date time.
 //!
+//! A time directly after a date applies to that date, so in a relative
+//! expression it applies to the anchor: "3 hours after tomorrow at 5pm" is
+//! 8pm. A time after a comma, or after a parenthesized group, applies to the
+//! whole expression instead: "3 hours after tomorrow, at 5pm" is 5pm. Use
+//! [`parse_detailed`] to find out which binding was used.
+//!
+//! Numeric dates are read month first, except when separated by dots where
+//! they are read day first. Use [`ParseOptions::date_order`] to change this.
+//!
+//! Input is read as English by default. The vocabulary of the lexer comes
+//! from a [`lang::Language`], which can be chosen with
+//! [`ParseOptions::language`]; the grammar below is shared by every language.
+//!
+//! The current time is read from the system clock with the default `clock`
+//! feature. Without it the library never looks up the system time or
+//! timezone, e.g. for `wasm32-unknown-unknown` where they are unreliable, so
+//! a reference datetime must be given with [`ParseOptions::relative_to`] or
+//! [`aware_parse`], or parsing fails with [`Error::MissingReference`]. The
+//! functions which always read the current time, such as `parse`,
+//! `parse_range`, and the `FromStr` impl of [`FuzzyDateTime`], are not
+//! built, so implicit uses of the local time fail to compile.
+//! Without the default `std` feature as well, the crate builds on `no_std`
+//! with `alloc`. Targets without std also need the `spin_no_std` feature,
+//! which builds the word tables of the lexer behind a spin lock instead of
+//! the std `Once`.
+//!
+//! Beyond the grammar below the parser accepts some lenient extras, such as
+//! a time before its date or a month and day without a year, and ignores
+//! trailing words. [`ParseOptions::minimal_grammar`] restricts parsing to
+//! exactly the documented grammar, for behaviour which stays the same
+//! across versions.
+//!
 //! ## Grammar
 //! ```text
-//! <datetime> ::= <time>
-//!              | <date> <time>
-//!              | <date> , <time>
+//! <datetime> ::= <date> <time>
 //!              | <duration> after <datetime>
 //!              | <duration> from <datetime>
 //!              | <duration> before <datetime>
+//!              | [the] <weekday> after <datetime>
+//!              | [the] <weekday> before <datetime>
+//!              | <num> <weekday> after <datetime>   ; e.g. 3 sundays after easter
+//!              | <num> <weekday> before <datetime>
+//!              | [the] next business day after <datetime>
+//!              | [the] last business day before <datetime>
 //!              | <duration> ago
+//!              | <duration> ago <datetime>
+//!              | ago <duration>   ; for languages which put it first
+//!              | in <duration>
+//!              | <datetime> + <duration>
+//!              | <datetime> - <duration>
+//!              | ( <datetime> )
+//!              | ( <datetime> ) <time>
+//!              | <datetime> , <time>          ; time of the whole expression
+//!              | <datetime> , which is <time>
+//!              | tonight [<day_part_hour>]
+//!              | this <day_part> [<day_part_hour>]   ; e.g. this evening at 7
+//!              | [at] <num> [minutes] past [the hour]   ; of the current hour
+//!              | [at] half past [the hour]
+//!              | [at] quarter past [the hour]
 //!              | now
+//!              | right now
+//!              | asap
+//!              | immediately
+//!              | epoch
+//!              | the epoch
+//!              | unix epoch
+//!
+//! <range> ::= last <num> <unit>
+//!           | next <num> <unit>
+//!           | <datetime> / <datetime>
+//!           | <datetime> / <duration>
+//!           | <duration> / <datetime>
+//!           | [the] [<relative_specifier>] NAMED   ; a registered named period
+//!           | [from] <datetime> to <datetime>        ; closed by default
+//!           | [from] <datetime> through <datetime>   ; closed by default
+//!           | [from] <datetime> until <datetime>     ; half-open by default
+//!           | between <datetime> and <datetime>      ; closed by default
+//!           | [<date>] [from] <time> to <time> [[on] <date>]   ; times of one day, today by default
+//!           | [<date>] [from] <time> through <time> [[on] <date>]
+//!           | [<date>] [from] <time> until <time> [[on] <date>]
+//!           | [<date>] between <time> and <time> [[on] <date>]
+//!           | since <datetime>   ; up to now, from the start of the day unless a time is given
+//!           | since <time>    ; the last time it was, e.g. yesterday for a later time
+//!           | since <month>   ; the last time it started
+//!
+//! <random> ::= [<article>] random <unit> <range>   ; e.g. a random hour today/PT8H
+//!
+//! <recurrence> ::= every <weekday> <time>
+//!                | every <unit> <time>
+//!                | every <num> <unit> <time>
+//!                | daily <time>
+//!                | weekly <time>
+//!                | monthly <time>
+//!                | yearly <time>
+//!
+//! <constraint> ::= after <datetime>   ; after the whole day if no time is given
+//!                | before <datetime>
+//!                | no earlier than <datetime>   ; including the datetime
+//!                | no later than <datetime>   ; a deadline, at the end of its day unless set
+//!                | by <datetime>              ; with ParseOptions::deadline_time
+//!                | until <datetime>
+//!                | after <time>    ; a time today
+//!                | before <time>
+//!                | no earlier than <time>
+//!                | no later than <time>
+//!                | by <time>
+//!                | until <time>
 //!
 //! <article> ::= a
 //!            | an
@@ -45,17 +147,58 @@
 //! <date> ::= today
 //!          | tomorrow
 //!          | yesterday
+//!          | the start of <period>
+//!          | the end of <period>
+//!          | DAY_AFTER_TOMORROW    ; single words in some languages,
+//!          | DAY_BEFORE_YESTERDAY  ; e.g. "übermorgen" or "avant-hier"
 //!          | <num> / <num> / <num>
 //!          | <num> - <num> - <num>
 //!          | <num> . <num> . <num>
+//!          | <num> / <num>
+//!          | <num> - <num>
+//!          | <num> . <num>
+//!          | NUM - NUM   ; ordinal date, four digit year and three digit day
+//!          | NUM - <num>  ; four digit year and month, e.g. 2024-06
+//!          | [in] NUM     ; four digit year, January 1 unless set with ParseOptions::year_anchor
+//!          | - - <num> - <num>   ; month and day, e.g. --06-15
 //!          | <month> <num> <num>
+//!          | <month> <ordinal> [<num>]   ; e.g. June twenty-first
+//!          | <month> NUM   ; four digit year, the 1st unless set with ParseOptions::month_anchor
+//!          | [the] <num> of <month> <num>   ; e.g. the 3rd of June 2024
+//!          | the <num>   ; a day of the month, the next one from today, e.g. the 15th
+//!          | [the] <ordinal> of <month> [<num>]   ; e.g. the twenty-first of June
+//!          | the <ordinal>   ; e.g. the third
 //!          | <relative_specifier> <unit>
 //!          | <relative_specifier> <weekday>
+//!          | <relative_specifier> <month>   ; e.g. last March, on its anchor day
+//!          | [the] last <weekday> of the month   ; walking back from the end of the month
+//!          | [the] last <weekday> of <relative_specifier> month
+//!          | [the] last <weekday> of <relative_specifier> <month>   ; e.g. of next June
+//!          | [the] last <weekday> of <month> [<num>]   ; this year unless a year is given
+//!          | [the] NAMED              ; a registered named date
+//!          | [the] NAMED of <month>
+//!          | [the] NAMED of <month> <num>
+//!          | [the] <relative_specifier> NAMED
+//!          | <relative_specifier> leap day
+//!          | <relative_specifier> leap year
+//!          | <weekday> [,] <month> <num> <num>   ; e.g. Friday, June 13 2025
+//!          | <weekday> [,] <month> <num>
+//!          | <weekday> [,] <num> / <num> / <num>
+//!          | <weekday> week   ; the weekday after next, e.g. Tuesday week
+//!          | a week on <weekday>
 //!          | <weekday>
 //!
+//! <period> ::= <unit>
+//!            | the <unit>
+//!            | <relative_specifier> <unit>
+//!            | [the] [<relative_specifier>] NAMED   ; a registered named period
+//!
 //! <relative_specifier> ::= this
 //!                        | next
+//!                        | following
 //!                        | last
+//!                        | previous
+//!                        | preceding
 //!
 //! <weekday> ::= monday
 //!             | tuesday
@@ -64,13 +207,24 @@
 //!             | friday
 //!             | saturday
 //!             | sunday
+//!             | mondays
+//!             | tuesdays
+//!             | wednesdays
+//!             | thursdays
+//!             | fridays
+//!             | saturdays
+//!             | sundays
 //!             | mon
 //!             | tue
+//!             | tues
 //!             | wed
+//!             | weds
 //!             | thu
+//!             | thur
+//!             | thurs
 //!             | fri
-//!             | sat
-//!             | sun
+//!             | sat   ; always the weekday, never the verb
+//!             | sun   ; always the weekday, never the star
 //!
 //! <month> ::= january
 //!           | february
@@ -92,21 +246,63 @@
 //!           | jul
 //!           | aug
 //!           | sep
+//!           | sept
 //!           | oct
 //!           | nov
 //!           | dec
 //!
 //! <duration> ::= <num> <unit>
+//!              | NUM<unit_suffix>   ; no space, e.g. 12h
+//!              | (NUM<unit_suffix>)+ ; no spaces, e.g. 1h30m
+//!              | ISO_DURATION       ; ISO 8601 duration, e.g. P1Y2M, PT30M or PT5400S
 //!              | <article> <unit>
+//!              | [<article>] <quantity> [of] <unit>   ; e.g. a couple of days
+//!              | DECIMAL <unit>   ; e.g. 1.5 hours, a whole number of a smaller unit
+//!              | [<article>] half [of] [<article>] <unit>      ; e.g. half an hour
+//!              | [<article>] quarter [of] [<article>] <unit>   ; e.g. a quarter of a year
 //!              | <duration> and <duration>
+//!              | <duration> <duration>
+//!
+//! <quantity> ::= couple    ; 2 unless set with ParseOptions::quantity
+//!              | few       ; 3
+//!              | several   ; 3
 //!
 //! <time> ::= <num>:<num>
-//!          | <num>:<num> am
-//!          | <num>:<num> pm
+//!          | <num>:<num> am   ; hours 1 to 12, where 12am is midnight
+//!          | <num>:<num> pm   ; and 12pm is noon
+//!          | <num> am
+//!          | <num> pm
+//!          | <num> o'clock
+//!          | midnight
+//!          | noon
+//!          | half <num>   ; half past the hour unless set with ParseOptions::half_hour
+//!          | at <time>
+//!          | at <num> hour   ; e.g. the French "à 17h"
+//!          | [in the] <day_part> [<day_part_hour>]
+//!          | <num> [o'clock] [in the] <day_part>    ; e.g. 8 in the morning
+//!          | <num>:<num> [in the] <day_part>
+//!          | <num> [o'clock] at night
+//!          | <num>:<num> at night
 //!          |
 //!
+//! <day_part_hour> ::= at <num> [o'clock]   ; read in the part of the day, e.g. evening at 7
+//!                   | at <num>:<num>
+//!                   | at <num> am
+//!                   | at <num> pm
+//!                   | at <num>:<num> am
+//!                   | at <num>:<num> pm
+//!
+//! <day_part> ::= morning     ; 9:00 unless set with ParseOptions::day_part
+//!              | afternoon   ; 15:00
+//!              | evening     ; 19:00
+//!              | night       ; 22:00
+//!
 //! <unit> ::= day
 //!          | days
+//!          | business day    ; skips weekends and ParseOptions::holidays
+//!          | business days
+//!          | working day
+//!          | working days
 //!          | week
 //!          | weeks
 //!          | hour
@@ -120,6 +316,12 @@
 //!          | year
 //!          | years
 //!
+//! <unit_suffix> ::= h
+//!                 | m
+//!                 | d
+//!                 | w
+//!                 | y
+//!
 //! <num> ::= <num_triple> <num_triple_unit> and <num>
 //!         | <num_triple> <num_triple_unit> <num>
 //!         | <num_triple> <num_triple_unit>
@@ -180,55 +382,671 @@
 //!          | eight
 //!          | nine
 //!          | NUM      ; number literal less than 10
+//!
+//! <ordinal> ::= <tens> - <ordinal_ones>
+//!             | <tens> <ordinal_ones>
+//!             | twentieth
+//!             | thirtieth
+//!             | <ordinal_teens>
+//!             | <ordinal_ones>
+//!
+//! <ordinal_teens> ::= tenth
+//!                   | eleventh
+//!                   | twelfth
+//!                   | thirteenth
+//!                   | fourteenth
+//!                   | fifteenth
+//!                   | sixteenth
+//!                   | seventeenth
+//!                   | eighteenth
+//!                   | nineteenth
+//!
+//! <ordinal_ones> ::= first
+//!                  | second
+//!                  | third
+//!                  | fourth
+//!                  | fifth
+//!                  | sixth
+//!                  | seventh
+//!                  | eighth
+//!                  | ninth
 //! ```
+//!
+//! Number literals may end in an ordinal suffix of the language, e.g. the
+//! "rd" in "3rd", and read as the number itself.
+//!
+//! Abbreviated month and weekday names may be followed by a period, e.g.
+//! "Jan. 5" or "Mon.", which is dropped rather than read as a date
+//! separator.
+
+extern crate alloc;
+
+pub mod ast;
+mod constraint;
+mod diff;
+mod error;
+mod humanize;
+#[cfg(feature = "jiff")]
+mod jiff;
+pub mod lang;
+pub mod lexer;
+mod memo;
+pub mod named;
+mod options;
+mod parser;
+mod recurrence;
+mod stats;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "time")]
+pub mod time;
+
+#[cfg(feature = "clock")]
+pub use constraint::parse_constraint;
+pub use constraint::{
+    parse_constraint_relative_to, parse_constraint_with_options, Bound, Constraint,
+};
+pub use diff::{
+    between, between_with_options, time_until, time_until_with_options, CalendarDuration, Countdown,
+};
+#[cfg(feature = "testing")]
+pub use error::InvalidSnapshot;
+pub use error::{Ambiguous, Error, InvalidDate, ParseError, UnrecognizedToken};
+pub use humanize::{humanize, humanize_relative, Granularity};
+#[cfg(all(feature = "jiff", feature = "clock"))]
+pub use jiff::parse_jiff;
+#[cfg(feature = "jiff")]
+pub use jiff::{aware_parse_jiff, aware_parse_jiff_with_options, parse_jiff_with_options};
+pub use options::{
+    Bounds, Connector, DateOrder, DayPart, DefaultTime, DstPolicy, HalfHour, MonthOverflow,
+    ParseOptions, Quantity, ThreeDigitYears, WeekdayCheck,
+};
+pub use parser::Parser;
+#[cfg(feature = "clock")]
+pub use recurrence::parse_recurrence;
+pub use recurrence::{
+    parse_recurrence_relative_to, parse_recurrence_with_options, Frequency, Recurrence, Schedule,
+    ZonedSchedule,
+};
+pub use stats::ParseStats;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use chrono::{
+    DateTime, Datelike, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc,
+    Weekday,
+};
+use core::fmt::{self, Write as _};
 
-mod ast;
-mod lexer;
-
-use chrono::{Local, NaiveDateTime, NaiveTime};
-
-#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
-pub enum Error {
-    #[error("Invalid date")]
-    /// The date is invalid,
-    /// e.g. `"31st of February"`, `"December 32nd"`, `"32/13/2019"`
-    InvalidDate(String),
-    #[error("Unrecognized Token while lexing")]
-    /// The lexer found a token that it doesn't recognize
-    UnrecognizedToken(String),
-    #[error("Unable to parse date")]
-    /// The date _may_ be valid, but the parser was unable to parse it,
-    /// e.g. `"tomorrow at at 5pm"`
-    ParseError,
-}
 // so that we don't have to change this in both places
 // doesn't show up in the docs
 type Output = Result<NaiveDateTime, Error>;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A period of time between two datetimes, as produced by `parse_range`
+pub struct DateRange {
+    /// The beginning of the period
+    pub start: NaiveDateTime,
+    /// The end of the period
+    pub end: NaiveDateTime,
+    /// Whether the end is part of the period
+    pub bounds: Bounds,
+}
+
+impl DateRange {
+    /// Whether the datetime falls within the period
+    pub fn contains(&self, datetime: NaiveDateTime) -> bool {
+        match self.bounds {
+            Bounds::HalfOpen => self.start <= datetime && datetime < self.end,
+            Bounds::Closed => self.start <= datetime && datetime <= self.end,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Which part of an expression an explicit time applies to
+pub enum TimeBinding {
+    /// The input has no explicit time, so the default time was used
+    Default,
+    /// The time applies to the result of the whole expression, e.g.
+    /// "tomorrow at 5pm" or "3 hours after tomorrow, at 5pm"
+    Expression,
+    /// The time applies to the anchor of a relative expression before the
+    /// offset is applied, e.g. "3 hours after tomorrow at 5pm" is 8pm
+    Anchor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A date led by a weekday it doesn't fall on, e.g. "Friday, June 12 2025"
+pub struct WeekdayMismatch {
+    /// The weekday given in the input
+    pub weekday: Weekday,
+    /// The date given in the input
+    pub date: NaiveDate,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A parsed datetime along with details of how the input was interpreted,
+/// as produced by `parse_detailed`
+pub struct Parsed {
+    /// The resulting datetime
+    pub datetime: NaiveDateTime,
+    /// The expression the input was read as, which can be described with
+    /// [`ast::DateTime::describe`]
+    pub expression: ast::DateTime,
+    /// Where the explicit time in the input was applied
+    pub time_binding: TimeBinding,
+    /// Misspelled words which were read as known words, see
+    /// [`ParseOptions::typo_tolerant`]
+    pub corrections: Vec<lexer::Correction>,
+    /// A weekday which doesn't match the date it leads, when checked with
+    /// [`WeekdayCheck::Warn`]
+    pub weekday_mismatch: Option<WeekdayMismatch>,
+    /// The work done by the lexer and parser
+    #[cfg(feature = "stats")]
+    pub stats: ParseStats,
+}
+
+impl Parsed {
+    /// Replace the time of the result, e.g. when the user picked a
+    /// different time in a UI. The time then applies to the whole result.
+    ///
+    /// ```rust
+    /// use chrono::{NaiveDateTime, NaiveTime};
+    /// use fuzzydate::{parse_detailed, ParseOptions};
+    ///
+    /// let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    /// let options = ParseOptions::new().relative_to(now);
+    /// let parsed = parse_detailed("tomorrow at 5pm", &options).unwrap();
+    /// let parsed = parsed.with_time(NaiveTime::from_hms_opt(9, 30, 0).unwrap());
+    /// assert_eq!(parsed.datetime.time().to_string(), "09:30:00");
+    /// ```
+    pub fn with_time(mut self, time: NaiveTime) -> Self {
+        self.datetime = self.datetime.date().and_time(time);
+        self.time_binding = TimeBinding::Expression;
+        self
+    }
+
+    /// Replace the date of the result, keeping its time
+    pub fn with_date(mut self, date: NaiveDate) -> Self {
+        self.datetime = date.and_time(self.datetime.time());
+        self
+    }
+
+    /// Replace the year of the result, keeping its month, day and time.
+    /// Fails with `Error::InvalidDate` for the 29th of February in a year
+    /// which isn't a leap year.
+    pub fn with_year(mut self, year: i32) -> Result<Self, Error> {
+        self.datetime = self
+            .datetime
+            .with_year(year)
+            .ok_or(Error::invalid_date(format!(
+                "{} in {year}",
+                self.datetime.format("%B %-d")
+            )))?;
+        Ok(self)
+    }
+}
+
+/// Parse an input string into a chrono NaiveDateTime, reporting how it was
+/// interpreted, according to the given options
+pub fn parse_detailed(input: impl AsRef<str>, options: &ParseOptions) -> Result<Parsed, Error> {
+    let vocabulary = lexer::Vocabulary::new(options);
+    parse_detailed_compiled(input.as_ref(), options, &vocabulary, None)
+}
+
+/// Parse an input string with a vocabulary gathered from the options
+/// beforehand, relative to the given datetime or else the current time of
+/// the options
+pub(crate) fn parse_detailed_compiled(
+    input: &str,
+    options: &ParseOptions,
+    vocabulary: &lexer::Vocabulary,
+    now: Option<NaiveDateTime>,
+) -> Result<Parsed, Error> {
+    stats::reset();
+    let (lexemes, spans, corrections) = lexer::Lexeme::lex_compiled(input, options, vocabulary)?;
+    stats::tokens(lexemes.len());
+    let (tree, tokens) = ast::DateTime::parse(lexemes.as_slice())
+        .ok_or_else(|| Error::parse_error_at(&spans, input.len()))?;
+    if options.minimal_grammar && tokens < lexemes.len() {
+        return Err(Error::trailing_tokens(&spans, tokens));
+    }
+    if options.minimal_grammar && !tree.is_documented() {
+        return Err(Error::parse_error());
+    }
+
+    let now = now.map_or_else(|| options.now(), Ok)?;
+    Ok(Parsed {
+        datetime: tree.to_chrono_with(options.time(now), now, options)?,
+        time_binding: tree.time_binding(),
+        weekday_mismatch: match options.weekday_check {
+            WeekdayCheck::Warn => tree.weekday_mismatch(now.date(), options),
+            _ => None,
+        },
+        expression: tree,
+        corrections,
+        #[cfg(feature = "stats")]
+        stats: stats::take(),
+    })
+}
+
+/// Parse an input string into a chrono NaiveDateTime, interpreting it
+/// according to the given options
+pub fn parse_with_options(input: impl AsRef<str>, options: &ParseOptions) -> Output {
+    parse_detailed(input, options).map(|parsed| parsed.datetime)
+}
+
 /// Parse an input string into a chrono NaiveDateTime, using the default
 /// values from the specified default value where not specified
-pub fn parse_with_default_time(input: impl Into<String>, default: NaiveTime) -> Output {
-    let lexemes = lexer::Lexeme::lex_line(input.into())?;
-    let (tree, _) = ast::DateTime::parse(lexemes.as_slice()).ok_or(Error::ParseError)?;
-
-    tree.to_chrono(default, None)
+#[cfg(feature = "clock")]
+pub fn parse_with_default_time(input: impl AsRef<str>, default: NaiveTime) -> Output {
+    parse_with_options(
+        input,
+        &ParseOptions::new().default_time(DefaultTime::Fixed(default)),
+    )
 }
 
 /// Parse an input string into a chrono NaiveDateTime, treating the default as
 /// if it was the current time.
-pub fn parse_relative_to(input: impl Into<String>, default: NaiveDateTime) -> Output {
-    let lexemes = lexer::Lexeme::lex_line(input.into())?;
-    let (tree, _) = ast::DateTime::parse(lexemes.as_slice()).ok_or(Error::ParseError)?;
-
-    tree.to_chrono(default.time(), Some(default))
+pub fn parse_relative_to(input: impl AsRef<str>, default: NaiveDateTime) -> Output {
+    parse_with_options(input, &ParseOptions::new().relative_to(default))
 }
 
 /// Parse an input string into a chrono NaiveDateTime with the default
 /// time being now
-pub fn parse(input: impl Into<String>) -> Output {
-    parse_with_default_time(input, Local::now().naive_local().time())
+#[cfg(feature = "clock")]
+pub fn parse(input: impl AsRef<str>) -> Output {
+    parse_with_options(input, &ParseOptions::new())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A NaiveDateTime whose `FromStr` implementation uses `parse`, so that
+/// fuzzy dates can be read anywhere the standard trait is used, e.g. by
+/// argument parsers or, with the `serde` feature, by deserializers
+///
+/// ```rust
+/// # #[cfg(feature = "clock")]
+/// # {
+/// use fuzzydate::FuzzyDateTime;
+///
+/// let date: FuzzyDateTime = "tomorrow at 5:00 pm".parse().unwrap();
+/// println!("{}", date);
+/// # }
+/// ```
+pub struct FuzzyDateTime(pub NaiveDateTime);
+
+#[cfg(feature = "clock")]
+impl core::str::FromStr for FuzzyDateTime {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s).map(FuzzyDateTime)
+    }
+}
+
+impl core::ops::Deref for FuzzyDateTime {
+    type Target = NaiveDateTime;
+
+    fn deref(&self) -> &NaiveDateTime {
+        &self.0
+    }
+}
+
+impl From<FuzzyDateTime> for NaiveDateTime {
+    fn from(date: FuzzyDateTime) -> Self {
+        date.0
+    }
+}
+
+impl From<NaiveDateTime> for FuzzyDateTime {
+    fn from(date: NaiveDateTime) -> Self {
+        FuzzyDateTime(date)
+    }
+}
+
+impl core::fmt::Display for FuzzyDateTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for FuzzyDateTime {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+#[cfg(all(feature = "clock", feature = "serde"))]
+impl<'de> serde::Deserialize<'de> for FuzzyDateTime {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <alloc::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How a local time which is ambiguous or doesn't exist in the timezone
+/// was resolved, see [`DstPolicy`]
+pub enum DstAdjustment {
+    /// The earlier of two instants was taken
+    Earliest,
+    /// The later of two instants was taken
+    Latest,
+    /// The time was moved forward past a gap
+    ShiftedForward,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A timezone aware datetime along with how its local time was resolved,
+/// as produced by `aware_parse_detailed`
+pub struct AwareParsed<Tz: TimeZone> {
+    /// The resulting datetime
+    pub datetime: DateTime<Tz>,
+    /// How the local time was resolved, `None` if it was unambiguous
+    pub dst_adjustment: Option<DstAdjustment>,
+}
+
+/// Parse an input string into a timezone aware chrono DateTime, reporting
+/// how its local time was resolved in the timezone, see
+/// [`aware_parse_with_options`]
+pub fn aware_parse_detailed<Tz: TimeZone>(
+    input: impl AsRef<str>,
+    now: DateTime<Tz>,
+    options: &ParseOptions,
+) -> Result<AwareParsed<Tz>, Error> {
+    let tz = now.timezone();
+    let mut options = options.clone().relative_to(now.naive_local());
+    options.epoch = Some(DateTime::UNIX_EPOCH.with_timezone(&tz).naive_local());
+
+    let date = parse_with_options(input, &options)?;
+    let (datetime, dst_adjustment) = match (tz.from_local_datetime(&date), options.dst_policy) {
+        (LocalResult::Single(datetime), _) => (datetime, None),
+        (LocalResult::Ambiguous(earliest, _), DstPolicy::Earliest | DstPolicy::ShiftForward) => {
+            (earliest, Some(DstAdjustment::Earliest))
+        }
+        (LocalResult::Ambiguous(_, latest), DstPolicy::Latest) => {
+            (latest, Some(DstAdjustment::Latest))
+        }
+        (LocalResult::None, DstPolicy::ShiftForward) => {
+            // Read the time with the offset from before the gap
+            let before = tz
+                .offset_from_local_datetime(&(date - chrono::Duration::days(1)))
+                .earliest()
+                .ok_or(Error::invalid_date(format!(
+                    "Nonexistent local time: {date}"
+                )))?;
+            let utc = date - chrono::Duration::seconds(before.fix().local_minus_utc().into());
+            (
+                tz.from_utc_datetime(&utc),
+                Some(DstAdjustment::ShiftedForward),
+            )
+        }
+        (LocalResult::Ambiguous(..), _) => {
+            return Err(Error::ambiguous(format!("Ambiguous local time: {date}")))
+        }
+        (LocalResult::None, _) => {
+            return Err(Error::invalid_date(format!(
+                "Nonexistent local time: {date}"
+            )))
+        }
+    };
+
+    Ok(AwareParsed {
+        datetime,
+        dst_adjustment,
+    })
+}
+
+/// Parse an input string into a timezone aware chrono DateTime, relative
+/// to `now` and in its timezone, interpreting it according to the given
+/// options. The `relative_to` option is ignored in favour of `now`.
+///
+/// Local times which are ambiguous or don't exist, e.g. because of a DST
+/// transition, are resolved by [`ParseOptions::dst_policy`]. By default
+/// ambiguous times resolve to the earliest of the two, and times which
+/// don't exist are an error.
+pub fn aware_parse_with_options<Tz: TimeZone>(
+    input: impl AsRef<str>,
+    now: DateTime<Tz>,
+    options: &ParseOptions,
+) -> Result<DateTime<Tz>, Error> {
+    aware_parse_detailed(input, now, options).map(|parsed| parsed.datetime)
+}
+
+/// Parse an input string into a timezone aware chrono DateTime, relative
+/// to `now` and in its timezone
+pub fn aware_parse<Tz: TimeZone>(
+    input: impl AsRef<str>,
+    now: DateTime<Tz>,
+) -> Result<DateTime<Tz>, Error> {
+    aware_parse_with_options(input, now, &ParseOptions::new())
+}
+
+/// Parse an input string into a UTC datetime, relative to `relative_to`
+/// or, if it isn't given, to the current time. Without the `clock` feature
+/// there is no current time, and parsing without `relative_to` fails with
+/// [`Error::MissingReference`].
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+///
+/// let now = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+/// let date = fuzzydate::parse_utc("tomorrow at 5pm", Some(now)).unwrap();
+/// assert_eq!(date, Utc.with_ymd_and_hms(2024, 6, 2, 17, 0, 0).unwrap());
+/// ```
+pub fn parse_utc(
+    input: impl AsRef<str>,
+    relative_to: Option<DateTime<Utc>>,
+) -> Result<DateTime<Utc>, Error> {
+    let now = match relative_to {
+        Some(now) => now,
+        None => options::utc_now()?,
+    };
+    aware_parse(input, now)
+}
+
+/// The IANA name of the system timezone, e.g. "Europe/Berlin", if it can
+/// be determined
+#[cfg(feature = "clock")]
+pub fn system_timezone() -> Option<String> {
+    iana_time_zone::get_timezone().ok()
+}
+
+/// Format the result of a parse for display, with a chrono strftime format
+/// or, if none is given, as "%Y-%m-%d %H:%M:%S". Fails if the format is
+/// invalid or needs an offset, e.g. "%z", which a naive result doesn't have;
+/// use [`format_result_in`] for those.
+///
+/// Frontends which show results to users can use this so that they all
+/// read the same.
+///
+/// ```rust
+/// use chrono::NaiveDate;
+///
+/// let now = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+/// let result = fuzzydate::parse_relative_to("tomorrow at 5pm", now);
+/// assert_eq!(fuzzydate::format_result(&result, None).unwrap(), "2024-06-02 17:00:00");
+/// assert!(fuzzydate::format_result(&result, Some("%H:%M %z")).is_err());
+///
+/// let result = fuzzydate::parse_relative_to("whenever", now);
+/// assert!(fuzzydate::format_result(&result, None).unwrap().starts_with("error: "));
+/// ```
+pub fn format_result(result: &Output, format: Option<&str>) -> Result<String, fmt::Error> {
+    let mut output = String::new();
+    match result {
+        Ok(date) => write!(output, "{}", date.format(format.unwrap_or(DEFAULT_FORMAT)))?,
+        Err(e) => write!(output, "error: {e}")?,
+    }
+    Ok(output)
+}
+
+/// Format the result of a parse like [`format_result`], reading it as a
+/// wall clock time in `tz` so that the format may include its offset, e.g.
+/// "%z". Where clocks go back the earlier instant is used, and a time
+/// skipped where they go forward is formatted without an offset.
+///
+/// ```rust
+/// use chrono::{FixedOffset, NaiveDate};
+///
+/// let now = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+/// let result = fuzzydate::parse_relative_to("tomorrow at 5pm", now);
+/// let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+/// let formatted = fuzzydate::format_result_in(&result, &tz, Some("%H:%M %z")).unwrap();
+/// assert_eq!(formatted, "17:00 +0200");
+/// ```
+pub fn format_result_in<Tz: TimeZone>(
+    result: &Output,
+    tz: &Tz,
+    format: Option<&str>,
+) -> Result<String, fmt::Error>
+where
+    Tz::Offset: fmt::Display,
+{
+    let Ok(date) = result else {
+        return format_result(result, format);
+    };
+    let Some(local) = tz.from_local_datetime(date).earliest() else {
+        return format_result(result, format);
+    };
+
+    let mut output = String::new();
+    write!(output, "{}", local.format(format.unwrap_or(DEFAULT_FORMAT)))?;
+    Ok(output)
+}
+
+/// The format of results when none is given
+const DEFAULT_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Parse an input string describing a duration and rebalance it into its
+/// largest whole units, e.g. "90 minutes" into "1 hour and 30 minutes"
+///
+/// ```rust
+/// let duration = fuzzydate::normalize_duration("90 minutes and 2 weeks").unwrap();
+/// assert_eq!(duration.to_string(), "2 weeks, 1 hour and 30 minutes");
+/// ```
+pub fn normalize_duration(input: impl AsRef<str>) -> Result<ast::Duration, Error> {
+    let lexemes = lexer::Lexeme::lex_line(input.as_ref())?;
+    let (duration, _) = ast::Duration::parse(lexemes.as_slice()).ok_or(Error::parse_error())?;
+    duration.normalize()
+}
+
+/// Parse an input string describing a period of time into a DateRange,
+/// interpreting it according to the given options
+pub fn parse_range_with_options(
+    input: impl AsRef<str>,
+    options: &ParseOptions,
+) -> Result<DateRange, Error> {
+    let vocabulary = lexer::Vocabulary::new(options);
+    parse_range_compiled(input.as_ref(), options, &vocabulary, None)
+}
+
+/// Parse an input string describing a period of time with a vocabulary
+/// gathered from the options beforehand, relative to the given datetime or
+/// else the current time of the options
+pub(crate) fn parse_range_compiled(
+    input: &str,
+    options: &ParseOptions,
+    vocabulary: &lexer::Vocabulary,
+    now: Option<NaiveDateTime>,
+) -> Result<DateRange, Error> {
+    let (lexemes, spans, _) = lexer::Lexeme::lex_compiled(input, options, vocabulary)?;
+    let (tree, tokens) = ast::Range::parse(lexemes.as_slice())
+        .ok_or_else(|| Error::parse_error_at(&spans, input.len()))?;
+    if options.minimal_grammar && tokens < lexemes.len() {
+        return Err(Error::trailing_tokens(&spans, tokens));
+    }
+    if options.minimal_grammar && !tree.is_documented() {
+        return Err(Error::parse_error());
+    }
+
+    let now = now.map_or_else(|| options.now(), Ok)?;
+    let (start, end) = tree.to_chrono(options.time(now), now, options)?;
+    Ok(DateRange {
+        start,
+        end,
+        bounds: tree.bounds(options),
+    })
+}
+
+/// Parse an input string describing a period of time into a DateRange,
+/// treating the default as if it was the current time.
+pub fn parse_range_relative_to(
+    input: impl AsRef<str>,
+    default: NaiveDateTime,
+) -> Result<DateRange, Error> {
+    parse_range_with_options(input, &ParseOptions::new().relative_to(default))
+}
+
+/// Parse an input string describing a period of time into a DateRange
+/// relative to now
+///
+/// ```rust
+/// let range = fuzzydate::parse_range("from May 1 2024 to May 5 2024").unwrap();
+/// assert_eq!(range.start.date().to_string(), "2024-05-01");
+/// assert_eq!(range.end.date().to_string(), "2024-05-05");
+/// ```
+#[cfg(feature = "clock")]
+pub fn parse_range(input: impl AsRef<str>) -> Result<DateRange, Error> {
+    parse_range_with_options(input, &ParseOptions::new())
+}
+
+/// Parse an input string describing a random datetime within a period of
+/// time, interpreting it according to the given options. `rng` must return
+/// uniformly random numbers, e.g. `|| rand::random()`.
+///
+/// ```rust
+/// use chrono::NaiveDateTime;
+/// use fuzzydate::{DefaultTime, ParseOptions};
+///
+/// let now = NaiveDateTime::parse_from_str("2024-05-20 12:00", "%Y-%m-%d %H:%M").unwrap();
+/// let options = ParseOptions::new().relative_to(now).default_time(DefaultTime::StartOfDay);
+///
+/// let input = "a random day between June 1 and June 30";
+/// let date = fuzzydate::parse_random_with_options(input, &options, || u64::MAX).unwrap();
+/// assert_eq!(date.to_string(), "2024-06-30 00:00:00");
+/// ```
+pub fn parse_random_with_options(
+    input: impl AsRef<str>,
+    options: &ParseOptions,
+    mut rng: impl FnMut() -> u64,
+) -> Output {
+    let input = input.as_ref();
+    let (lexemes, spans, _) =
+        lexer::Lexeme::lex_compiled(input, options, &lexer::Vocabulary::new(options))?;
+    let (tree, tokens) = ast::Random::parse(lexemes.as_slice())
+        .ok_or_else(|| Error::parse_error_at(&spans, input.len()))?;
+    if options.minimal_grammar && tokens < lexemes.len() {
+        return Err(Error::trailing_tokens(&spans, tokens));
+    }
+    if options.minimal_grammar && !tree.1.is_documented() {
+        return Err(Error::parse_error());
+    }
+
+    let now = options.now()?;
+    tree.to_chrono(options.time(now), now, options, &mut rng)
+}
+
+/// Parse an input string describing a random datetime within a period of
+/// time, treating the default as if it was the current time
+pub fn parse_random_relative_to(
+    input: impl AsRef<str>,
+    default: NaiveDateTime,
+    rng: impl FnMut() -> u64,
+) -> Output {
+    parse_random_with_options(input, &ParseOptions::new().relative_to(default), rng)
 }
 
+/// Parse an input string describing a random datetime within a period of
+/// time relative to now
+#[cfg(feature = "clock")]
+pub fn parse_random(input: impl AsRef<str>, rng: impl FnMut() -> u64) -> Output {
+    parse_random_with_options(input, &ParseOptions::new(), rng)
+}
+
+#[cfg(feature = "clock")]
 #[test]
 fn test_parse() {
     use chrono::Datelike;
@@ -240,6 +1058,1519 @@ fn test_parse() {
     assert_eq!(2022, date.year());
 }
 
+#[test]
+fn test_anniversary() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let date = parse_relative_to("50 years ago today at midnight", now).unwrap();
+    assert_eq!(date.to_string(), "1974-06-01 00:00:00");
+}
+
+#[test]
+fn test_parse_range() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let range = parse_range_relative_to("last 3 days", now).unwrap();
+    assert_eq!(range.start.to_string(), "2024-05-29 12:00:00");
+    assert_eq!(range.end, now);
+
+    // A counted period is not a single point in time
+    assert!(parse_relative_to("last 3 days", now).is_err());
+
+    // Dates without a time are whole days, not at the current time, and an
+    // included end lasts until the end of its day
+    let now = NaiveDateTime::parse_from_str("2024-06-01 10:25", "%Y-%m-%d %H:%M").unwrap();
+    let end_of = |date: &str| format!("{date} 23:59:59.999999999");
+    for (input, start, end) in [
+        (
+            "from May 1 to May 5",
+            "2024-05-01 00:00:00",
+            end_of("2024-05-05"),
+        ),
+        (
+            "monday through friday",
+            "2024-06-03 00:00:00",
+            end_of("2024-06-07"),
+        ),
+        (
+            "monday until friday",
+            "2024-06-03 00:00:00",
+            "2024-06-07 00:00:00".into(),
+        ),
+        (
+            "June 3 until June 5 at 5pm",
+            "2024-06-03 00:00:00",
+            "2024-06-05 17:00:00".into(),
+        ),
+        (
+            "June 3 to June 5 at 5pm",
+            "2024-06-03 00:00:00",
+            "2024-06-05 17:00:00".into(),
+        ),
+        (
+            "3 days ago to tomorrow",
+            "2024-05-29 10:25:00",
+            end_of("2024-06-02"),
+        ),
+        (
+            "2024-06-03/2024-06-07",
+            "2024-06-03 00:00:00",
+            "2024-06-07 00:00:00".into(),
+        ),
+    ] {
+        let range = parse_range_relative_to(input, now).unwrap();
+        assert_eq!(range.start.to_string(), start, "{input}");
+        assert_eq!(range.end.to_string(), end, "{input}");
+    }
+}
+
+#[test]
+fn test_time_range() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+
+    for (input, start, end, bounds) in [
+        (
+            "9am to 5pm",
+            "2024-06-01 09:00:00",
+            "2024-06-01 17:00:00",
+            Bounds::Closed,
+        ),
+        (
+            "between 14:00 and 16:30",
+            "2024-06-01 14:00:00",
+            "2024-06-01 16:30:00",
+            Bounds::Closed,
+        ),
+        (
+            "tomorrow 9am to 5pm",
+            "2024-06-02 09:00:00",
+            "2024-06-02 17:00:00",
+            Bounds::Closed,
+        ),
+        (
+            "monday from 9:30 until noon",
+            "2024-06-03 09:30:00",
+            "2024-06-03 12:00:00",
+            Bounds::HalfOpen,
+        ),
+        (
+            "9am to 5pm on friday",
+            "2024-06-07 09:00:00",
+            "2024-06-07 17:00:00",
+            Bounds::Closed,
+        ),
+        (
+            "from 10pm to 2am tomorrow",
+            "2024-06-02 22:00:00",
+            "2024-06-03 02:00:00",
+            Bounds::Closed,
+        ),
+        (
+            "tomorrow morning to evening",
+            "2024-06-02 09:00:00",
+            "2024-06-02 19:00:00",
+            Bounds::Closed,
+        ),
+    ] {
+        let range = parse_range_relative_to(input, now).unwrap();
+        assert_eq!(range.start.to_string(), start, "{input}");
+        assert_eq!(range.end.to_string(), end, "{input}");
+        assert_eq!(range.bounds, bounds, "{input}");
+    }
+
+    // Times on different days are still two datetimes
+    let range = parse_range_relative_to("monday at 9am to friday at 5pm", now).unwrap();
+    assert_eq!(range.start.to_string(), "2024-06-03 09:00:00");
+    assert_eq!(range.end.to_string(), "2024-06-07 17:00:00");
+}
+
+#[test]
+fn test_past_the_hour() {
+    let now = NaiveDateTime::parse_from_str("2024-06-13 15:42", "%Y-%m-%d %H:%M").unwrap();
+
+    for (input, expected) in [
+        ("10 past the hour", "2024-06-13 15:10:00"),
+        ("10 minutes past the hour", "2024-06-13 15:10:00"),
+        ("at half past", "2024-06-13 15:30:00"),
+        ("quarter past the hour", "2024-06-13 15:15:00"),
+        ("at 5 past", "2024-06-13 15:05:00"),
+        ("an hour after half past", "2024-06-13 16:30:00"),
+    ] {
+        let date = parse_relative_to(input, now).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+
+    // A given hour isn't the current one
+    for input in [
+        "10 past 5",
+        "10 past noon",
+        "5 past 5pm",
+        "half past midnight",
+    ] {
+        assert!(parse_relative_to(input, now).is_err(), "{input}");
+    }
+    assert!(parse_relative_to("75 past the hour", now).is_err());
+}
+
+#[test]
+fn test_since() {
+    // A Thursday
+    let now = NaiveDateTime::parse_from_str("2024-06-13 15:30", "%Y-%m-%d %H:%M").unwrap();
+
+    for (input, start) in [
+        ("since tuesday", "2024-06-11 00:00:00"),
+        ("since thursday", "2024-06-13 00:00:00"),
+        ("since last March", "2024-03-01 00:00:00"),
+        ("since last June", "2023-06-01 00:00:00"),
+        ("since yesterday at 9am", "2024-06-12 09:00:00"),
+        ("since 9am", "2024-06-13 09:00:00"),
+        ("since 3 days ago", "2024-06-10 15:30:00"),
+    ] {
+        let range = parse_range_relative_to(input, now).unwrap();
+        assert_eq!(range.start.to_string(), start, "{input}");
+        assert_eq!(range.end, now, "{input}");
+    }
+
+    assert!(parse_range_relative_to("since tomorrow", now).is_err());
+
+    for (input, expected) in [
+        ("last march", "2024-03-01 15:30:00"),
+        ("last august", "2023-08-01 15:30:00"),
+        ("next june", "2025-06-01 15:30:00"),
+        ("next july", "2024-07-01 15:30:00"),
+        ("this january", "2024-01-01 15:30:00"),
+    ] {
+        let date = parse_relative_to(input, now).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+
+    // A time or month on its own which is yet to come is the last one
+    let now = NaiveDateTime::parse_from_str("2024-10-13 10:25", "%Y-%m-%d %H:%M").unwrap();
+    for (input, start) in [
+        ("since 11pm", "2024-10-12 23:00:00"),
+        ("since 9am", "2024-10-13 09:00:00"),
+        ("since december", "2023-12-01 00:00:00"),
+        ("since october", "2024-10-01 00:00:00"),
+        ("since march", "2024-03-01 00:00:00"),
+        ("since december 5", "2023-12-05 00:00:00"),
+    ] {
+        let range = parse_range_relative_to(input, now).unwrap();
+        assert_eq!(range.start.to_string(), start, "{input}");
+        assert_eq!(range.end, now, "{input}");
+    }
+}
+
+#[cfg(feature = "clock")]
+#[test]
+fn test_ordinal_date_offset() {
+    let date = parse_with_default_time("2024-150 + 12h", NaiveTime::MIN).unwrap();
+    assert_eq!(date.to_string(), "2024-05-29 12:00:00");
+}
+
+#[test]
+fn test_date_order_option() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new()
+        .relative_to(now)
+        .date_order(DateOrder::DayMonthYear)
+        .default_time(DefaultTime::StartOfDay);
+    let date = parse_with_options("5/12/2023", &options).unwrap();
+    assert_eq!(date.to_string(), "2023-12-05 00:00:00");
+
+    // A leading four digit year is read as year, month, day whatever the order
+    let date = parse_with_options("2024/06/15", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-06-15 00:00:00");
+}
+
+#[test]
+fn test_truncated_iso() {
+    let now = NaiveDateTime::parse_from_str("2024-01-01 08:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new()
+        .relative_to(now)
+        .default_time(DefaultTime::StartOfDay);
+
+    let date = parse_with_options("--06-15", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-06-15 00:00:00");
+
+    let date = parse_with_options("2023-06", &options).unwrap();
+    assert_eq!(date.to_string(), "2023-06-01 00:00:00");
+}
+
+#[test]
+fn test_iso_interval() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new()
+        .relative_to(now)
+        .default_time(DefaultTime::StartOfDay);
+    let range = parse_range_with_options("2024-01-01/P1M", &options).unwrap();
+    assert_eq!(range.start.to_string(), "2024-01-01 00:00:00");
+    assert_eq!(range.end.to_string(), "2024-02-01 00:00:00");
+}
+
+#[test]
+fn test_range_bounds() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new()
+        .relative_to(now)
+        .default_time(DefaultTime::StartOfDay);
+
+    // An included end without a time lasts until the end of its day
+    for (input, end, bounds) in [
+        ("monday to friday", "23:59:59.999999999", Bounds::Closed),
+        (
+            "from monday through friday",
+            "23:59:59.999999999",
+            Bounds::Closed,
+        ),
+        ("monday until friday", "00:00:00", Bounds::HalfOpen),
+        (
+            "between monday and friday",
+            "23:59:59.999999999",
+            Bounds::Closed,
+        ),
+        ("2024-06-03/2024-06-07", "00:00:00", Bounds::HalfOpen),
+    ] {
+        let range = parse_range_with_options(input, &options).unwrap();
+        assert_eq!(range.start.to_string(), "2024-06-03 00:00:00", "{input}");
+        assert_eq!(
+            range.end.to_string(),
+            format!("2024-06-07 {end}"),
+            "{input}"
+        );
+        assert_eq!(range.bounds, bounds, "{input}");
+        assert_eq!(
+            range.contains(range.end),
+            bounds == Bounds::Closed,
+            "{input}"
+        );
+    }
+
+    // The end is read from the start when it would otherwise come first
+    let now = NaiveDateTime::parse_from_str("2024-04-10 12:00", "%Y-%m-%d %H:%M").unwrap();
+    for (input, start, end) in [
+        ("between monday and friday", "2024-04-15", "2024-04-19"),
+        ("from May 1 to May 5", "2024-05-01", "2024-05-05"),
+        ("december 28 until january 3", "2024-12-28", "2025-01-03"),
+        ("3 days ago to today", "2024-04-07", "2024-04-10"),
+    ] {
+        let range = parse_range_relative_to(input, now).unwrap();
+        assert_eq!(range.start.date().to_string(), start, "{input}");
+        assert_eq!(range.end.date().to_string(), end, "{input}");
+    }
+
+    let options = options.range_bounds(Connector::To, Bounds::HalfOpen);
+    let range = parse_range_with_options("monday to friday", &options).unwrap();
+    assert_eq!(range.bounds, Bounds::HalfOpen);
+}
+
+#[test]
+fn test_day_parts() {
+    use chrono::NaiveTime;
+
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now);
+
+    for (input, expected) in [
+        ("tomorrow evening", "2024-06-02 19:00:00"),
+        ("tonight", "2024-06-01 22:00:00"),
+        ("this morning", "2024-06-01 09:00:00"),
+        ("friday in the afternoon", "2024-06-07 15:00:00"),
+        ("yesterday at night", "2024-05-31 22:00:00"),
+        ("tomorrow morning at 8", "2024-06-02 08:00:00"),
+        ("yesterday afternoon", "2024-05-31 15:00:00"),
+        ("tonight at 11", "2024-06-01 23:00:00"),
+        ("this evening at 7:30", "2024-06-01 19:30:00"),
+        ("tomorrow evening at 19", "2024-06-02 19:00:00"),
+        ("tomorrow evening at 8pm", "2024-06-02 20:00:00"),
+        ("tomorrow at 8 in the evening", "2024-06-02 20:00:00"),
+        ("monday at 6 o'clock in the morning", "2024-06-03 06:00:00"),
+        ("tomorrow at 5 in the afternoon", "2024-06-02 17:00:00"),
+        ("8 in the evening tomorrow", "2024-06-02 20:00:00"),
+        ("friday at 7:30 in the morning", "2024-06-07 07:30:00"),
+        ("tomorrow at 12 in the afternoon", "2024-06-02 12:00:00"),
+        ("tomorrow at 10 at night", "2024-06-02 22:00:00"),
+        // The small hours of a night are on the next day
+        ("saturday at 2 at night", "2024-06-02 02:00:00"),
+        ("tonight at 1", "2024-06-02 01:00:00"),
+        ("tonight at 2am", "2024-06-02 02:00:00"),
+        ("tonight at 12", "2024-06-02 00:00:00"),
+        ("this evening at 12", "2024-06-02 00:00:00"),
+    ] {
+        let date = parse_with_options(input, &options).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+
+    // Times outside of the part of the day they are given with
+    for input in [
+        "tomorrow morning at 8pm",
+        "this evening at 9am",
+        "tonight at 3pm",
+        "this afternoon at 12am",
+        "this morning at 20",
+    ] {
+        assert!(
+            matches!(
+                parse_with_options(input, &options),
+                Err(Error::InvalidDate(_))
+            ),
+            "{input}"
+        );
+    }
+
+    let evening = NaiveTime::from_hms_opt(18, 0, 0).unwrap();
+    let options = options.day_part(DayPart::Evening, evening);
+    let date = parse_with_options("tomorrow evening", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-06-02 18:00:00");
+}
+
+#[test]
+fn test_parse_random() {
+    let now = NaiveDateTime::parse_from_str("2024-05-20 12:00", "%Y-%m-%d %H:%M").unwrap();
+
+    // A splitmix64 generator, for reproducible random numbers
+    let mut state = 0u64;
+    let mut rng = || {
+        state = state.wrapping_add(0x9e3779b97f4a7c15);
+        let z = (state ^ (state >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        let z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    };
+
+    // Days of a range of whole days start at midnight
+    let input = "a random day between June 1 and June 30";
+    let mut seen = alloc::collections::BTreeSet::new();
+    for _ in 0..1000 {
+        let date = parse_random_relative_to(input, now, &mut rng).unwrap();
+        assert_eq!(date.time().to_string(), "00:00:00");
+        seen.insert(date.date());
+    }
+    assert_eq!(seen.len(), 30);
+    assert_eq!(seen.first().unwrap().to_string(), "2024-06-01");
+    assert_eq!(seen.last().unwrap().to_string(), "2024-06-30");
+
+    // The end of a half-open range is never chosen
+    let input = "random hour 2024-06-01/PT3H";
+    let date = parse_random_relative_to(input, now, || u64::MAX).unwrap();
+    assert_eq!(date.to_string(), "2024-06-01 02:00:00");
+    let date = parse_random_relative_to(input, now, || 0).unwrap();
+    assert_eq!(date.to_string(), "2024-06-01 00:00:00");
+
+    let input = "random month from january 1 2024 until april 1 2024";
+    let date = parse_random_relative_to(input, now, || u64::MAX).unwrap();
+    assert_eq!(date.date().to_string(), "2024-03-01");
+
+    let input = "a random day 2024-06-01/2024-06-01";
+    assert!(parse_random_relative_to(input, now, rng).is_err());
+}
+
+#[test]
+fn test_in_duration() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let date = parse_relative_to("in 3 days", now).unwrap();
+    assert_eq!(date.to_string(), "2024-06-04 12:00:00");
+
+    let date = parse_relative_to("tomorrow at 5 o'clock", now).unwrap();
+    assert_eq!(date.to_string(), "2024-06-02 05:00:00");
+
+    // Durations too long for the calendar are out of range rather than
+    // overflowing
+    for input in [
+        "in 4294967295 hours",
+        "4294967295 weeks ago",
+        "4294967295 days after today",
+        "in 4294967295 weeks and 4294967295 days",
+    ] {
+        assert!(
+            matches!(parse_relative_to(input, now), Err(Error::InvalidDate(_))),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn test_business_days() {
+    use chrono::NaiveDate;
+
+    // A thursday
+    let now = NaiveDateTime::parse_from_str("2024-07-04 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now);
+
+    for (input, expected) in [
+        ("5 business days from today", "2024-07-11 12:00:00"),
+        ("1 working day after today", "2024-07-05 12:00:00"),
+        ("2 business days after today", "2024-07-08 12:00:00"),
+        ("3 business days ago", "2024-07-01 12:00:00"),
+        ("in 2 business days and 3 hours", "2024-07-08 15:00:00"),
+        ("12 business days after today", "2024-07-22 12:00:00"),
+        ("12 business days before today", "2024-06-18 12:00:00"),
+        (
+            "6 business days after 7/6/2024 at noon",
+            "2024-07-15 12:00:00",
+        ),
+        (
+            "5 business days before 7/7/2024 at noon",
+            "2024-07-01 12:00:00",
+        ),
+    ] {
+        let date = parse_with_options(input, &options).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+
+    let holidays = [
+        NaiveDate::from_ymd_opt(2024, 7, 5).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 7, 8).unwrap(),
+    ];
+    let options = options.holidays(holidays);
+    let date = parse_with_options("2 business days after today", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-07-10 12:00:00");
+    let date = parse_with_options("12 business days after today", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-07-24 12:00:00");
+    let date = parse_with_options("8 business days before 7/12/2024 at noon", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-06-28 12:00:00");
+
+    // Far off business days are out of range rather than walked one by one
+    assert!(matches!(
+        parse_with_options("4000000000 business days after today", &options),
+        Err(Error::InvalidDate(_))
+    ));
+
+    assert!(parse_recurrence_relative_to("every 2 business days", now).is_err());
+
+    // Business days as dates roll over weekends and holidays
+    for (input, expected) in [
+        ("next business day", "2024-07-09"),
+        ("previous business day", "2024-07-03"),
+        ("the following working day after July 4", "2024-07-09"),
+        ("the next business day after 7/6/2024", "2024-07-09"),
+        ("the last business day before July 8", "2024-07-04"),
+        ("the business day after tomorrow", "2024-07-09"),
+    ] {
+        let date = parse_with_options(input, &options).unwrap();
+        assert_eq!(date.date().to_string(), expected, "{input}");
+    }
+}
+
+#[test]
+fn test_now_synonyms() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    for input in ["asap", "immediately", "right now", "Right now"] {
+        assert_eq!(parse_relative_to(input, now).unwrap(), now, "{input}");
+    }
+
+    let date = parse_relative_to("2 hours after asap", now).unwrap();
+    assert_eq!(date.to_string(), "2024-06-01 14:00:00");
+}
+
+#[test]
+fn test_aware_epoch() {
+    use chrono::{FixedOffset, Utc};
+
+    let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+    let now = Utc::now().with_timezone(&tz);
+
+    let date = aware_parse("5 minutes after the unix epoch", now).unwrap();
+    assert_eq!(date.timestamp(), 300);
+    assert_eq!(date.to_rfc3339(), "1970-01-01T02:05:00+02:00");
+}
+
+#[test]
+fn test_dst_policy() {
+    use chrono::{FixedOffset, NaiveDate};
+
+    /// Central European time in 2024, which skips from 2:00 to 3:00 on
+    /// March 31 and repeats 2:00 to 3:00 on October 27
+    #[derive(Debug, Clone, Copy)]
+    struct Cet;
+
+    impl TimeZone for Cet {
+        type Offset = FixedOffset;
+
+        fn from_offset(_: &FixedOffset) -> Self {
+            Cet
+        }
+
+        fn offset_from_local_date(&self, date: &NaiveDate) -> LocalResult<FixedOffset> {
+            self.offset_from_local_datetime(&date.and_hms_opt(12, 0, 0).unwrap())
+        }
+
+        fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+            let mut offsets = [2, 1]
+                .map(|hours| FixedOffset::east_opt(hours * 3600).unwrap())
+                .into_iter()
+                .filter(|&offset| self.offset_from_utc_datetime(&(*local - offset)) == offset);
+
+            match (offsets.next(), offsets.next()) {
+                (Some(earliest), Some(latest)) => LocalResult::Ambiguous(earliest, latest),
+                (Some(offset), None) => LocalResult::Single(offset),
+                _ => LocalResult::None,
+            }
+        }
+
+        fn offset_from_utc_date(&self, date: &NaiveDate) -> FixedOffset {
+            self.offset_from_utc_datetime(&date.and_hms_opt(12, 0, 0).unwrap())
+        }
+
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+            let date = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+            let summer = date("2024-03-31 01:00") <= *utc && *utc < date("2024-10-27 01:00");
+            FixedOffset::east_opt(if summer { 2 * 3600 } else { 3600 }).unwrap()
+        }
+    }
+
+    let spring = Cet.with_ymd_and_hms(2024, 3, 30, 12, 0, 0).unwrap();
+    let fall = Cet.with_ymd_and_hms(2024, 10, 26, 12, 0, 0).unwrap();
+    let input = "tomorrow at 2:30";
+
+    for (policy, nonexistent, ambiguous) in [
+        (
+            DstPolicy::Earliest,
+            None,
+            Some(("2024-10-27T02:30:00+02:00", DstAdjustment::Earliest)),
+        ),
+        (
+            DstPolicy::Latest,
+            None,
+            Some(("2024-10-27T02:30:00+01:00", DstAdjustment::Latest)),
+        ),
+        (
+            DstPolicy::ShiftForward,
+            Some(("2024-03-31T03:30:00+02:00", DstAdjustment::ShiftedForward)),
+            Some(("2024-10-27T02:30:00+02:00", DstAdjustment::Earliest)),
+        ),
+        (DstPolicy::Error, None, None),
+    ] {
+        let options = ParseOptions::new().dst_policy(policy);
+        let resolve = |now| {
+            aware_parse_detailed(input, now, &options)
+                .ok()
+                .map(|parsed| (parsed.datetime.to_rfc3339(), parsed.dst_adjustment.unwrap()))
+        };
+
+        let expected = nonexistent.map(|(date, adjustment)| (date.to_string(), adjustment));
+        assert_eq!(resolve(spring), expected, "{policy:?}");
+        let expected = ambiguous.map(|(date, adjustment)| (date.to_string(), adjustment));
+        assert_eq!(resolve(fall), expected, "{policy:?}");
+
+        // Unambiguous times are never adjusted
+        let parsed = aware_parse_detailed("tomorrow at 5pm", spring, &options).unwrap();
+        assert_eq!(parsed.datetime.to_rfc3339(), "2024-03-31T17:00:00+02:00");
+        assert_eq!(parsed.dst_adjustment, None);
+    }
+
+    let options = ParseOptions::new().dst_policy(DstPolicy::Error);
+    assert!(matches!(
+        aware_parse_with_options(input, fall, &options),
+        Err(Error::Ambiguous(_))
+    ));
+}
+
+#[test]
+fn test_time_binding() {
+    let now = NaiveDateTime::parse_from_str("2024-06-10 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new()
+        .relative_to(now)
+        .default_time(DefaultTime::StartOfDay);
+
+    // Without a comma the time applies to the anchor
+    let date = parse_with_options("2 hours before the start of the month at 5pm", &options);
+    assert_eq!(date.unwrap().to_string(), "2024-06-01 15:00:00");
+
+    // Grouped or after a comma it applies to the whole expression
+    for input in [
+        "(2 hours before the start of the month) at 5pm",
+        "2 hours before the start of the month, at 5pm",
+        "2 hours before the start of the month, which is at 5pm",
+    ] {
+        let date = parse_with_options(input, &options).unwrap();
+        assert_eq!(date.to_string(), "2024-05-31 17:00:00", "{input}");
+    }
+}
+
+#[test]
+fn test_time_binding_reported() {
+    let now = NaiveDateTime::parse_from_str("2024-06-10 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now);
+
+    for (input, binding) in [
+        ("3 hours after tomorrow", TimeBinding::Default),
+        ("tomorrow at 5pm", TimeBinding::Expression),
+        ("3 hours after tomorrow, at 5pm", TimeBinding::Expression),
+        ("3 hours after tomorrow at 5pm", TimeBinding::Anchor),
+    ] {
+        let parsed = parse_detailed(input, &options).unwrap();
+        assert_eq!(parsed.time_binding, binding, "{input}");
+    }
+}
+
+#[test]
+fn test_start_and_end_of() {
+    let now = NaiveDateTime::parse_from_str("2024-02-14 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new()
+        .relative_to(now)
+        .default_time(DefaultTime::StartOfDay);
+
+    for (input, expected) in [
+        ("the end of the month", "2024-02-29"),
+        ("end of next week", "2024-02-25"),
+        ("the start of this week", "2024-02-12"),
+        ("beginning of last year", "2023-01-01"),
+        ("end of the year", "2024-12-31"),
+    ] {
+        let date = parse_with_options(input, &options).unwrap();
+        assert_eq!(date.date().to_string(), expected, "{input}");
+    }
+}
+
+#[test]
+fn test_typo_corrections() {
+    let now = NaiveDateTime::parse_from_str("2024-06-10 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now).typo_tolerant(true);
+
+    let parsed = parse_detailed("tommorrow at 5pm", &options).unwrap();
+    assert_eq!(parsed.datetime.to_string(), "2024-06-11 17:00:00");
+    assert_eq!(parsed.corrections.len(), 1);
+    assert_eq!(parsed.corrections[0].replacement, "tomorrow");
+}
+
+#[test]
+fn test_leap_days() {
+    let now = NaiveDateTime::parse_from_str("2024-06-10 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now);
+
+    for (input, expected) in [
+        ("next leap year", "2028-02-29"),
+        ("last leap year", "2020-02-29"),
+        ("this leap year", "2024-02-29"),
+        ("last leap day", "2024-02-29"),
+        ("next leap day", "2028-02-29"),
+    ] {
+        let date = parse_with_options(input, &options).unwrap();
+        assert_eq!(date.date().to_string(), expected, "{input}");
+    }
+
+    let now = NaiveDateTime::parse_from_str("2023-01-10 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now);
+    let date = parse_with_options("next leap day", &options).unwrap();
+    assert_eq!(date.date().to_string(), "2024-02-29");
+    assert!(parse_with_options("this leap year", &options).is_err());
+}
+
+#[test]
+fn test_year_only() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now);
+
+    for (input, expected) in [
+        ("2030", "2030-01-01 12:00:00"),
+        ("in 1999", "1999-01-01 12:00:00"),
+        ("three months after 2030", "2030-04-01 12:00:00"),
+        ("2030 at 5pm", "2030-01-01 17:00:00"),
+        ("2024-06", "2024-06-01 12:00:00"),
+        ("in 2030 minutes", "2024-06-02 21:50:00"),
+    ] {
+        let date = parse_with_options(input, &options).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+
+    let fiscal = options.clone().year_anchor(4, 1);
+    let date = parse_with_options("2030", &fiscal).unwrap();
+    assert_eq!(date.to_string(), "2030-04-01 12:00:00");
+
+    let leap = options.year_anchor(2, 29);
+    assert!(parse_with_options("2030", &leap).is_err());
+    assert!(parse_with_options("2028", &leap).is_ok());
+}
+
+#[test]
+fn test_last_weekday_of_month() {
+    // A Thursday
+    let now = NaiveDateTime::parse_from_str("2024-02-15 12:00", "%Y-%m-%d %H:%M").unwrap();
+
+    for (input, expected) in [
+        ("the last friday of the month", "2024-02-23 12:00:00"),
+        ("last thursday of the month", "2024-02-29 12:00:00"),
+        ("the last monday of next month", "2024-03-25 12:00:00"),
+        ("the last sunday of last month", "2024-01-28 12:00:00"),
+        ("last sunday of march", "2024-03-31 12:00:00"),
+        ("the last monday of next june", "2024-06-24 12:00:00"),
+        (
+            "the last wednesday of june 2025 at 5pm",
+            "2025-06-25 17:00:00",
+        ),
+        (
+            "2 days before the last friday of the month",
+            "2024-02-21 12:00:00",
+        ),
+    ] {
+        let date = parse_relative_to(input, now).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+
+    // Without "of" it is still the last weekday before today
+    let date = parse_relative_to("last friday", now).unwrap();
+    assert_eq!(date.to_string(), "2024-02-09 12:00:00");
+
+    assert!(parse_relative_to("the last friday of the week", now).is_err());
+}
+
+#[test]
+fn test_month_year() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new()
+        .relative_to(now)
+        .default_time(DefaultTime::StartOfDay);
+
+    for (input, expected) in [
+        ("June 2025", "2025-06-01"),
+        ("Feb 2020", "2020-02-01"),
+        ("a week before June 2025", "2025-05-25"),
+        ("June 5 2025", "2025-06-05"),
+    ] {
+        let date = parse_with_options(input, &options).unwrap();
+        assert_eq!(date.date().to_string(), expected, "{input}");
+    }
+
+    let options = options.month_anchor(31);
+    let date = parse_with_options("Feb 2020", &options).unwrap();
+    assert_eq!(date.date().to_string(), "2020-02-29");
+}
+
+#[test]
+fn test_iso_durations() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+
+    for (input, expected) in [
+        ("P3D after 2024-05-01", "2024-05-04 12:00:00"),
+        ("PT1H30M ago", "2024-06-01 10:30:00"),
+        ("in PT5400S", "2024-06-01 13:30:00"),
+        ("2024-05-01 + P1DT12H", "2024-05-03 00:00:00"),
+    ] {
+        let date = parse_relative_to(input, now).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+
+    // Durations formatted by chrono can be read back
+    let formatted = chrono::Duration::minutes(90).to_string();
+    let duration = normalize_duration(&formatted).unwrap();
+    assert_eq!(duration.to_string(), "1 hour and 30 minutes");
+}
+
+#[test]
+fn test_vague_quantities() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now);
+
+    for (input, expected) in [
+        ("a couple of days ago", "2024-05-30 12:00:00"),
+        ("in a few hours", "2024-06-01 15:00:00"),
+        ("several weeks after today", "2024-06-22 12:00:00"),
+        ("a couple days and a few hours ago", "2024-05-30 09:00:00"),
+    ] {
+        let date = parse_with_options(input, &options).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+
+    let range = parse_range_with_options("next few days", &options).unwrap();
+    assert_eq!(range.end.to_string(), "2024-06-04 12:00:00");
+
+    let options = options.quantity(Quantity::Several, 5);
+    let date = parse_with_options("several weeks after today", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-07-06 12:00:00");
+}
+
+#[test]
+fn test_fractional_units() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+
+    for (input, expected) in [
+        ("half an hour ago", "2024-06-01 11:30:00"),
+        ("in a quarter of an hour", "2024-06-01 12:15:00"),
+        ("half a year after 1/31/2024", "2024-07-31 12:00:00"),
+        ("a quarter of a year before today", "2024-03-01 12:00:00"),
+        ("in half a day", "2024-06-02 00:00:00"),
+        ("in half a week", "2024-06-05 00:00:00"),
+        ("1 hour and half an hour ago", "2024-06-01 10:30:00"),
+    ] {
+        let date = parse_relative_to(input, now).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+
+    // Fractions which aren't a whole number of a smaller unit
+    assert!(parse_relative_to("in half a month", now).is_err());
+    assert!(parse_relative_to("in half a minute", now).is_err());
+
+    let duration = normalize_duration("half a year").unwrap();
+    assert_eq!(duration.to_string(), "6 months");
+}
+
+#[test]
+fn test_decimal_durations() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+
+    for (input, expected) in [
+        ("in 1.5 hours", "2024-06-01 13:30:00"),
+        ("2.25 days ago", "2024-05-30 06:00:00"),
+        ("in 1.05 hours", "2024-06-01 13:03:00"),
+        ("1.5 years after 1/31/2024", "2025-07-31 12:00:00"),
+        ("in 1.5h", "2024-06-01 13:30:00"),
+        ("in 2.0 weeks", "2024-06-15 12:00:00"),
+        ("19.12.2024", "2024-12-19 12:00:00"),
+    ] {
+        let date = parse_relative_to(input, now).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+
+    // Fractions which aren't a whole number of a smaller unit
+    assert!(parse_relative_to("in 1.5 months", now).is_err());
+    assert!(parse_relative_to("in 1.5 minutes", now).is_err());
+
+    let duration = normalize_duration("1.5 hours").unwrap();
+    assert_eq!(duration.to_string(), "1 hour and 30 minutes");
+}
+
+#[test]
+fn test_compact_durations() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+
+    for (input, expected) in [
+        ("in 1h30m", "2024-06-01 13:30:00"),
+        ("2d4h ago", "2024-05-30 08:00:00"),
+        ("in 90m", "2024-06-01 13:30:00"),
+        ("1w2d after 6/1/2024", "2024-06-10 12:00:00"),
+    ] {
+        let date = parse_relative_to(input, now).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+
+    let duration = normalize_duration("1h30m").unwrap();
+    assert_eq!(duration.to_string(), "1 hour and 30 minutes");
+}
+
+#[test]
+fn test_week_on_weekday() {
+    // A Saturday
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+
+    for (input, expected) in [
+        ("tuesday week", "2024-06-11 12:00:00"),
+        ("a week on tuesday", "2024-06-11 12:00:00"),
+        ("a week on saturday", "2024-06-15 12:00:00"),
+        ("saturday week at 5pm", "2024-06-15 17:00:00"),
+    ] {
+        let date = parse_relative_to(input, now).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+}
+
+#[test]
+fn test_weekday_dates() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+
+    for (input, expected) in [
+        ("Friday, June 13 2025", "2025-06-13 12:00:00"),
+        ("friday june 13 2025", "2025-06-13 12:00:00"),
+        ("Friday, June 13 2025 at 5pm", "2025-06-13 17:00:00"),
+        ("Wednesday, 6/5/2024", "2024-06-05 12:00:00"),
+        ("tuesday, june 4", "2024-06-04 12:00:00"),
+        ("friday, at 5pm", "2024-06-07 17:00:00"),
+    ] {
+        let date = parse_relative_to(input, now).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+}
+
+#[test]
+fn test_weekday_abbreviations() {
+    // A Saturday
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+
+    for (input, expected) in [
+        ("mon", "2024-06-03 12:00:00"),
+        ("tue", "2024-06-04 12:00:00"),
+        ("Tues at 5pm", "2024-06-04 17:00:00"),
+        ("next wed", "2024-06-12 12:00:00"),
+        ("thu", "2024-06-06 12:00:00"),
+        ("thur", "2024-06-06 12:00:00"),
+        ("thurs", "2024-06-06 12:00:00"),
+        ("weds", "2024-06-05 12:00:00"),
+        ("Weds. at 9am", "2024-06-05 09:00:00"),
+        ("fri, june 7", "2024-06-07 12:00:00"),
+        ("sat", "2024-06-01 12:00:00"),
+        ("sun", "2024-06-02 12:00:00"),
+        ("the sat after next month", "2024-07-06 12:00:00"),
+    ] {
+        let date = parse_relative_to(input, now).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+}
+
+#[test]
+fn test_abbreviation_periods() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+
+    for (input, expected) in [
+        ("Jan. 5", "2024-01-05 12:00:00"),
+        ("Jan. 5 2025", "2025-01-05 12:00:00"),
+        ("Mon. at 5pm", "2024-06-03 17:00:00"),
+        ("Tue., Sep. 3 2024", "2024-09-03 12:00:00"),
+        ("the 4th of Jul.", "2024-07-04 12:00:00"),
+        ("Sept. 5 2024", "2024-09-05 12:00:00"),
+        ("sept 5", "2024-09-05 12:00:00"),
+        // Dotted dates are still read
+        ("5.1.2025", "2025-01-05 12:00:00"),
+    ] {
+        let date = parse_relative_to(input, now).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+}
+
+#[test]
+fn test_weekday_check() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now);
+
+    // June 12 2025 is a Thursday
+    let parsed = parse_detailed("Friday, June 12 2025", &options).unwrap();
+    assert_eq!(parsed.datetime.to_string(), "2025-06-12 12:00:00");
+    assert_eq!(parsed.weekday_mismatch, None);
+
+    let options = options.weekday_check(WeekdayCheck::Warn);
+    let parsed = parse_detailed("3 days after Friday, June 12 2025", &options).unwrap();
+    assert_eq!(parsed.datetime.to_string(), "2025-06-15 12:00:00");
+    assert_eq!(
+        parsed.weekday_mismatch,
+        Some(WeekdayMismatch {
+            weekday: Weekday::Fri,
+            date: NaiveDate::from_ymd_opt(2025, 6, 12).unwrap(),
+        })
+    );
+
+    let parsed = parse_detailed("Thursday, June 12 2025", &options).unwrap();
+    assert_eq!(parsed.weekday_mismatch, None);
+
+    let options = options.weekday_check(WeekdayCheck::Error);
+    assert!(matches!(
+        parse_with_options("Friday, June 12 2025", &options),
+        Err(Error::InvalidDate(_))
+    ));
+    assert!(parse_with_options("Thursday, June 12 2025", &options).is_ok());
+}
+
+#[test]
+fn test_describe() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new()
+        .relative_to(now)
+        .date_order(DateOrder::DayMonthYear);
+
+    for (input, expected) in [
+        (
+            "next friday",
+            "the first Friday on or after a week from today",
+        ),
+        ("3 days ago", "3 days ago"),
+        ("tomorrow evening at 7", "tomorrow at 7:00 in the evening"),
+        ("5/12/2023 at 5pm", "December 5 2023 at 5:00 pm"),
+        (
+            "the friday after next month",
+            "the 1st Friday after next month",
+        ),
+        (
+            "2 hours before the end of the month, at noon",
+            "2 hours before the end of this month, at 12:00",
+        ),
+        ("Friday, June 13 2025", "June 13 2025, given as a Friday"),
+    ] {
+        let parsed = parse_detailed(input, &options).unwrap();
+        assert_eq!(parsed.expression.describe(&options), expected, "{input}");
+    }
+}
+
+#[test]
+fn test_day_of_month() {
+    let now = NaiveDateTime::parse_from_str("2024-06-12 09:00", "%Y-%m-%d %H:%M").unwrap();
+
+    for (input, expected) in [
+        ("3rd of June 2024", "2024-06-03 09:00:00"),
+        ("the 1st of May 2026", "2026-05-01 09:00:00"),
+        ("the 22nd of march", "2024-03-22 09:00:00"),
+        ("twenty one of july at 5pm", "2024-07-21 17:00:00"),
+        ("june 3rd", "2024-06-03 09:00:00"),
+        ("2 days after the 4th of july", "2024-07-06 09:00:00"),
+        ("the 15th", "2024-06-15 09:00:00"),
+        ("the 3rd at noon", "2024-07-03 12:00:00"),
+    ] {
+        let date = parse_relative_to(input, now).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+
+    assert!(parse_relative_to("the 31st of june", now).is_err());
+    assert!(parse_relative_to("3xy of june", now).is_err());
+
+    // Without a month months too short for the day are skipped
+    let now = NaiveDateTime::parse_from_str("2024-01-31 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let date = parse_relative_to("2 days before the 30th", now).unwrap();
+    assert_eq!(date.to_string(), "2024-03-28 12:00:00");
+}
+
+#[test]
+fn test_ordinal_words() {
+    let now = NaiveDateTime::parse_from_str("2024-06-12 09:00", "%Y-%m-%d %H:%M").unwrap();
+
+    for (input, expected) in [
+        ("twenty-first of June", "2024-06-21 09:00:00"),
+        ("the twenty first of june 2025", "2025-06-21 09:00:00"),
+        ("the first of july", "2024-07-01 09:00:00"),
+        ("the third", "2024-07-03 09:00:00"),
+        ("the twelfth", "2024-06-12 09:00:00"),
+        ("the nineteenth of march", "2024-03-19 09:00:00"),
+        ("the twentieth", "2024-06-20 09:00:00"),
+        ("the thirtieth", "2024-06-30 09:00:00"),
+        ("the thirty-first", "2024-07-31 09:00:00"),
+        ("june second", "2024-06-02 09:00:00"),
+        ("august twenty-fifth 2025 at 5pm", "2025-08-25 17:00:00"),
+    ] {
+        let date = parse_relative_to(input, now).unwrap();
+        assert_eq!(date.to_string(), expected, "{input}");
+    }
+
+    assert!(parse_relative_to("the thirty-first of june", now).is_err());
+
+    // An ordinal counting weekdays isn't a day of the month
+    for input in [
+        "the first monday of june",
+        "the second friday of march",
+        "the 3rd tuesday",
+    ] {
+        assert!(
+            matches!(parse_relative_to(input, now), Err(Error::ParseError(_))),
+            "{input}"
+        );
+    }
+
+    // Plurals aren't corrected to an ordinal, e.g. "seconds" to "second"
+    match parse_relative_to("in 30 seconds", now) {
+        Err(Error::UnrecognizedToken(details)) => {
+            assert_eq!(details.token, "seconds");
+            assert_eq!(details.suggestion, None);
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+#[test]
+fn test_half_hour() {
+    let now = NaiveDateTime::parse_from_str("2024-06-12 09:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now);
+
+    for (input, half_hour, expected) in [
+        ("tomorrow at half eleven", None, Some("2024-06-13 11:30:00")),
+        ("half 7 friday", None, Some("2024-06-14 07:30:00")),
+        ("today at half 24", None, None),
+        (
+            "tomorrow at half eleven",
+            Some(HalfHour::To),
+            Some("2024-06-13 10:30:00"),
+        ),
+        ("today at half 0", Some(HalfHour::To), None),
+    ] {
+        let options = match half_hour {
+            Some(half_hour) => options.clone().half_hour(half_hour),
+            None => options.clone(),
+        };
+        let date = parse_with_options(input, &options).ok();
+        assert_eq!(date.map(|d| d.to_string()).as_deref(), expected, "{input}");
+    }
+
+    #[cfg(feature = "lang-de")]
+    {
+        let options = options.language(&lang::German);
+        let date = parse_with_options("morgen um halb elf", &options).unwrap();
+        assert_eq!(date.to_string(), "2024-06-13 10:30:00");
+
+        let options = options.half_hour(HalfHour::Past);
+        let date = parse_with_options("morgen um halb elf", &options).unwrap();
+        assert_eq!(date.to_string(), "2024-06-13 11:30:00");
+    }
+}
+
+#[test]
+fn test_month_overflow() {
+    let now = NaiveDateTime::parse_from_str("2023-01-31 12:00", "%Y-%m-%d %H:%M").unwrap();
+
+    for (overflow, before, next) in [
+        (MonthOverflow::Clamp, Some("2023-02-28"), Some("2023-02-28")),
+        (MonthOverflow::Roll, Some("2023-03-03"), Some("2023-03-03")),
+        (MonthOverflow::Error, None, None),
+    ] {
+        let options = ParseOptions::new()
+            .relative_to(now)
+            .month_overflow(overflow);
+        let date = |input| parse_with_options(input, &options).ok();
+
+        let before_date = date("3 months before May 31");
+        assert_eq!(
+            before_date.map(|d| d.date().to_string()).as_deref(),
+            before,
+            "{overflow:?}"
+        );
+        let next_date = date("next month");
+        assert_eq!(
+            next_date.map(|d| d.date().to_string()).as_deref(),
+            next,
+            "{overflow:?}"
+        );
+
+        // Days which exist in the target month are never adjusted
+        assert_eq!(
+            date("1 month after 3/15/2023").unwrap().date().to_string(),
+            "2023-04-15"
+        );
+    }
+}
+
+#[cfg(feature = "clock")]
+#[test]
+fn test_fuzzy_datetime() {
+    let date: FuzzyDateTime = "5/2/2022 5:00".parse().unwrap();
+    assert_eq!(date.to_string(), "2022-05-02 05:00:00");
+    assert_eq!(date.date().to_string(), "2022-05-02");
+    assert!("Hello World".parse::<FuzzyDateTime>().is_err());
+
+    #[cfg(feature = "serde")]
+    {
+        let date: FuzzyDateTime = serde_json::from_str("\"5/2/2022 5:00\"").unwrap();
+        let json = serde_json::to_string(&date).unwrap();
+        assert_eq!(json, "\"2022-05-02 05:00:00\"");
+        assert_eq!(serde_json::from_str::<FuzzyDateTime>(&json).unwrap(), date);
+    }
+}
+
+#[test]
+fn test_weekday_relative_to() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new()
+        .relative_to(now)
+        .default_time(DefaultTime::StartOfDay);
+
+    let date = parse_with_options("the Monday before 6/12/2024", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-06-10 00:00:00");
+
+    let date = parse_with_options("wednesday after 6/12/2024", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-06-19 00:00:00");
+
+    let date = parse_with_options("3 Sundays after 6/12/2024", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-06-30 00:00:00");
+
+    let date = parse_with_options("two fridays before 6/12/2024", &options).unwrap();
+    assert_eq!(date.to_string(), "2024-05-31 00:00:00");
+
+    assert!(parse_with_options("0 sundays after 6/12/2024", &options).is_err());
+
+    // Far off weekdays are out of range rather than overflowing
+    let date = parse_with_options("100000 mondays after 6/12/2024", &options).unwrap();
+    assert_eq!(date.to_string(), "3940-12-23 00:00:00");
+    let date = parse_with_options("100000 fridays before 6/12/2024", &options).unwrap();
+    assert_eq!(date.to_string(), "0107-12-02 00:00:00");
+    assert!(matches!(
+        parse_with_options("99999999 sundays after today", &options),
+        Err(Error::InvalidDate(_))
+    ));
+    assert!(matches!(
+        parse_with_options("99999999 sundays before today", &options),
+        Err(Error::InvalidDate(_))
+    ));
+}
+
+#[test]
+fn test_normalize_duration() {
+    for (input, expected) in [
+        ("90 minutes", "1 hour and 30 minutes"),
+        ("an hour and 60 minutes", "2 hours"),
+        ("36 hours", "1 day and 12 hours"),
+        ("10 days", "1 week and 3 days"),
+        ("14 months 1 week", "1 year, 2 months and 1 week"),
+        ("0 days", "0 minutes"),
+    ] {
+        let duration = normalize_duration(input).unwrap();
+        assert_eq!(duration.to_string(), expected, "{input}");
+    }
+
+    assert!(normalize_duration("tomorrow").is_err());
+    assert!(matches!(
+        normalize_duration("4294967295 years and 12 months"),
+        Err(Error::InvalidDate(_))
+    ));
+    assert!(matches!(
+        normalize_duration("4294967295 weeks and 7 days"),
+        Err(Error::InvalidDate(_))
+    ));
+}
+
+#[cfg(feature = "stats")]
+#[test]
+fn test_parse_stats() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now);
+
+    let simple = parse_detailed("tomorrow", &options).unwrap().stats;
+    assert_eq!(simple.tokens, 1);
+    assert!(simple.rule_attempts > 0);
+    assert_eq!(simple.allocations, 0);
+    assert_eq!(simple.backtracks, 0);
+
+    let complex = parse_detailed("3 days and 2 hours after tomorrow", &options)
+        .unwrap()
+        .stats;
+    assert_eq!(complex.tokens, 7);
+    assert!(complex.rule_attempts > simple.rule_attempts);
+    assert!(complex.backtracks > 0);
+    assert!(complex.allocations >= 3);
+
+    // Counters don't carry over between parses
+    assert_eq!(parse_detailed("tomorrow", &options).unwrap().stats, simple);
+}
+
+#[cfg(feature = "stats")]
+#[test]
+fn test_memoization() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now);
+
+    // The number is parsed once, although every alternative starting with a
+    // number or a duration tries it
+    let parsed = parse_detailed("twenty one days ago", &options).unwrap();
+    assert_eq!(parsed.datetime.to_string(), "2024-05-11 12:00:00");
+    assert!(parsed.stats.memo_hits > 0);
+
+    // Results of one input are never reused for another at the same offset
+    for (input, expected) in [
+        ("5/6/2024", "2024-05-06 12:00:00"),
+        ("6/5/2024", "2024-06-05 12:00:00"),
+        ("june 5 2024 at 3pm", "2024-06-05 15:00:00"),
+        ("july 4 2024 at 9am", "2024-07-04 09:00:00"),
+    ] {
+        let parsed = parse_detailed(input, &options).unwrap();
+        assert_eq!(parsed.datetime.to_string(), expected, "{input}");
+    }
+}
+
+#[test]
+fn test_format_result() {
+    use chrono::FixedOffset;
+
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let result = parse_relative_to("tomorrow at 5pm", now);
+    let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+
+    assert_eq!(
+        format_result(&result, Some("%d %b %H:%M")).unwrap(),
+        "02 Jun 17:00"
+    );
+    assert_eq!(
+        format_result_in(&result, &tz, Some("%H:%M %:z")).unwrap(),
+        "17:00 -05:00"
+    );
+
+    // Invalid formats, and offsets of naive results, fail instead of
+    // panicking
+    for format in ["%Q", "%z", "%Y-%m-%d %Z"] {
+        assert!(format_result(&result, Some(format)).is_err(), "{format}");
+    }
+    assert!(format_result_in(&result, &tz, Some("%Q")).is_err());
+}
+
+#[test]
+fn test_parse_utc() {
+    use chrono::Utc;
+
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 23, 30, 0).unwrap();
+    let date = parse_utc("3 hours after now", Some(now)).unwrap();
+    assert_eq!(date, Utc.with_ymd_and_hms(2024, 6, 2, 2, 30, 0).unwrap());
+
+    let date = parse_utc("the epoch", Some(now)).unwrap();
+    assert_eq!(date, DateTime::UNIX_EPOCH);
+
+    #[cfg(not(feature = "clock"))]
+    assert_eq!(parse_utc("tomorrow", None), Err(Error::MissingReference));
+    #[cfg(feature = "clock")]
+    assert!(parse_utc("tomorrow", None).is_ok());
+}
+
+#[test]
+fn test_strict() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now).strict(true);
+
+    for input in ["5/12/2023", "12/5/24", "friday", "3 days after 6/4"] {
+        let result = parse_with_options(input, &options);
+        assert!(
+            matches!(result, Err(Error::Ambiguous(_))),
+            "{input}: {result:?}"
+        );
+    }
+
+    for input in [
+        "5/13/2023",
+        "6/6/2024",
+        "2024-05-12",
+        "next friday",
+        "tomorrow",
+    ] {
+        assert!(parse_with_options(input, &options).is_ok(), "{input}");
+    }
+
+    let options = options.date_order(DateOrder::DayMonthYear);
+    assert!(parse_with_options("5/12/2023", &options).is_ok());
+}
+
+#[test]
+fn test_strict_time() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now).strict_time(true);
+
+    for (input, span) in [
+        ("tomorrow at 25:99", 12..17),
+        ("24:00 on June 3 2024", 0..5),
+        ("3 hours after 23:60 tomorrow", 14..19),
+        ("tomorrow at 30 o'clock", 12..22),
+    ] {
+        match parse_with_options(input, &options) {
+            Err(Error::InvalidDate(invalid)) => {
+                assert_eq!(invalid.span, Some(span.clone()), "{input}");
+                assert_eq!(
+                    invalid.reason,
+                    format!("{} is not a time on a 24 hour clock", &input[span]),
+                );
+            }
+            result => panic!("{input}: {result:?}"),
+        }
+    }
+
+    for input in [
+        "tomorrow at 23:59",
+        "tomorrow at 0:00",
+        "tomorrow at 11:30 pm",
+    ] {
+        assert!(parse_with_options(input, &options).is_ok(), "{input}");
+    }
+
+    // Without the option the time is only rejected once resolved
+    let result = parse_with_options("tomorrow at 25:99", &options.strict_time(false));
+    assert!(matches!(
+        result,
+        Err(Error::InvalidDate(InvalidDate { span: None, .. }))
+    ));
+}
+
+#[test]
+fn test_minimal_grammar() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new()
+        .relative_to(now)
+        .typo_tolerant(true)
+        .minimal_grammar(true);
+
+    for input in ["5pm tomorrow", "june 5", "tomorrow at at 5pm", "tommorrow"] {
+        let result = parse_with_options(input, &options);
+        assert!(
+            matches!(
+                result,
+                Err(Error::ParseError(_) | Error::UnrecognizedToken(_))
+            ),
+            "{input}: {result:?}"
+        );
+    }
+
+    for input in [
+        "tomorrow at 5pm",
+        "june 5 2024",
+        "3 days after 6/4/2024",
+        "(3 hours after tomorrow) at 5pm",
+    ] {
+        assert!(parse_with_options(input, &options).is_ok(), "{input}");
+    }
+    assert!(parse_range_with_options("last 3 days", &options).is_ok());
+
+    // Trailing words are pointed at, rather than where parsing gave up
+    let Err(Error::ParseError(error)) = parse_with_options("tomorrow at at 5pm", &options) else {
+        panic!("trailing words are rejected");
+    };
+    assert_eq!(error.span, Some(9..11));
+    assert_eq!(error.expected, ["the end of the input"]);
+
+    // A time needs a date, as in the grammar
+    assert!(parse_with_options("5pm", &options).is_err());
+    assert!(parse_with_options("today at 5pm", &options).is_ok());
+
+    // The extended grammar remains the default
+    let options = options.minimal_grammar(false);
+    for input in ["5pm tomorrow", "june 5", "tomorrow at at 5pm", "tommorrow"] {
+        assert!(parse_with_options(input, &options).is_ok(), "{input}");
+    }
+}
+
+#[test]
+fn test_parsed_overrides() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now);
+
+    let parsed = parse_detailed("3 days after tomorrow", &options).unwrap();
+    assert_eq!(parsed.time_binding, TimeBinding::Default);
+
+    let time = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+    let parsed = parsed.with_time(time);
+    assert_eq!(parsed.datetime.to_string(), "2024-06-05 17:00:00");
+    assert_eq!(parsed.time_binding, TimeBinding::Expression);
+
+    let parsed = parsed.with_year(2025).unwrap();
+    assert_eq!(parsed.datetime.to_string(), "2025-06-05 17:00:00");
+
+    let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    let parsed = parsed.with_date(date);
+    assert_eq!(parsed.datetime.to_string(), "2024-02-29 17:00:00");
+
+    match parsed.with_year(2023) {
+        Err(Error::InvalidDate(details)) => assert_eq!(details.reason, "February 29 in 2023"),
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+#[test]
+fn test_error_details() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now);
+
+    match parse_with_options("next Tusday at 5pm", &options) {
+        Err(Error::UnrecognizedToken(details)) => {
+            assert_eq!(details.token, "Tusday");
+            assert_eq!(details.span, 5..11);
+            assert_eq!(details.suggestion.as_deref(), Some("tuesday"));
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+
+    match parse_with_options("2/30/2024", &options) {
+        Err(Error::InvalidDate(details)) => assert!(!details.reason.is_empty()),
+        other => panic!("unexpected result: {other:?}"),
+    }
+
+    match parse_with_options("at at", &options) {
+        Err(Error::ParseError(details)) => {
+            assert_eq!(details.span, Some(3..5));
+            assert!(details.expected.contains(&"a time"));
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+
+    match parse_with_options("3 days after", &options) {
+        Err(Error::ParseError(details)) => {
+            assert_eq!(details.span, Some(12..12));
+            assert!(details.expected.contains(&"a date"));
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+#[cfg(feature = "clock")]
 #[test]
 fn test_malformed() {
     let input = "Hello World";
@@ -247,9 +2578,36 @@ fn test_malformed() {
     assert!(date.is_err());
 }
 
+#[test]
+fn test_owned_and_borrowed_input() {
+    let borrowed = parse_relative_to("5/2/2022 5:00", NaiveDateTime::MIN).unwrap();
+    let owned = parse_relative_to(String::from("5/2/2022 5:00"), NaiveDateTime::MIN).unwrap();
+    assert_eq!(borrowed, owned);
+}
+
+#[cfg(feature = "clock")]
 #[test]
 fn test_empty() {
     let input = "";
     let date = parse(input);
     assert!(date.is_err());
 }
+
+#[cfg(not(feature = "clock"))]
+#[test]
+fn test_without_clock() {
+    use chrono::{FixedOffset, NaiveDate};
+
+    let options = ParseOptions::new();
+    let date = parse_with_options("tomorrow", &options);
+    assert!(matches!(date, Err(Error::MissingReference)));
+
+    let now = NaiveDate::from_ymd_opt(2024, 6, 1)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap()
+        .and_local_timezone(FixedOffset::east_opt(3600).unwrap())
+        .unwrap();
+    let date = aware_parse("tomorrow at 5pm", now).unwrap();
+    assert_eq!(date.to_rfc3339(), "2024-06-02T17:00:00+01:00");
+}
@@ -0,0 +1,308 @@
+//! Packrat memoization of the parser, enabled by the `std` feature
+//!
+//! Alternatives of the grammar often try the same rule at the same position,
+//! e.g. a numeric date and a month and day both start by parsing a number.
+//! While a public entry point of the parser runs, the result of a rule at
+//! an offset is kept in a thread local table, so the rule runs at most once
+//! per offset however much the parser backtracks. Without `std` there is no
+//! thread local storage and rules are parsed every time.
+//!
+//! Only rules producing small nodes are memoized. Rules producing nested
+//! trees, such as a datetime, are re-entered at a new offset each time, so
+//! caching them would only add the cost of cloning their trees.
+//!
+//! The table also keeps the furthest offset any rule was tried at, and the
+//! rules tried there, which is where a failed parse went wrong and what it
+//! expected to find, see [`furthest`]. It is packed into a single word, which
+//! is thread local with `std`. Without it the word is shared by every
+//! thread, so parses running on several threads at once may report each
+//! other's furthest offsets.
+
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::lexer::Lexeme;
+
+/// The rules of the grammar, which are memoized or whose attempts are
+/// tracked for errors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Rule {
+    DateTime,
+    Range,
+    Recurrence,
+    Random,
+    Constraint,
+    Duration,
+    Date,
+    Time,
+    Num,
+    Unit,
+    Weekday,
+    Month,
+}
+
+impl Rule {
+    /// Every rule, in the order they are reported as expected
+    const ALL: [Rule; 12] = [
+        Rule::DateTime,
+        Rule::Range,
+        Rule::Recurrence,
+        Rule::Random,
+        Rule::Constraint,
+        Rule::Duration,
+        Rule::Date,
+        Rule::Time,
+        Rule::Num,
+        Rule::Unit,
+        Rule::Weekday,
+        Rule::Month,
+    ];
+
+    /// What the rule parses, for the expected input of an error
+    pub(crate) fn description(self) -> &'static str {
+        match self {
+            Rule::DateTime => "a date or time",
+            Rule::Range => "a period",
+            Rule::Recurrence => "a recurrence",
+            Rule::Random => "a random choice",
+            Rule::Constraint => "a bound",
+            Rule::Duration => "a duration",
+            Rule::Date => "a date",
+            Rule::Time => "a time",
+            Rule::Num => "a number",
+            Rule::Unit => "a unit",
+            Rule::Weekday => "a weekday",
+            Rule::Month => "a month",
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+type Table = std::collections::HashMap<(Rule, usize), alloc::boxed::Box<dyn core::any::Any>>;
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// Results by rule and offset, and how many parses are running
+    static MEMO: core::cell::RefCell<(Table, usize)> = core::cell::RefCell::new((Table::new(), 0));
+}
+
+/// Marks a parse as running, and clears the table once the outermost parse
+/// is done, even if it panicked
+#[cfg(feature = "std")]
+struct Depth;
+
+#[cfg(feature = "std")]
+impl Depth {
+    fn enter() -> Self {
+        MEMO.with_borrow_mut(|(_, depth)| *depth += 1);
+        with_furthest(Furthest::enter);
+        Depth
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for Depth {
+    fn drop(&mut self) {
+        MEMO.with_borrow_mut(|(table, depth)| {
+            *depth -= 1;
+            if *depth == 0 {
+                table.clear();
+            }
+        });
+        with_furthest(Furthest::leave);
+    }
+}
+
+/// Run a public entry point of the parser, keeping memoized results until
+/// the outermost one returns.
+///
+/// Every slice parsed meanwhile is a suffix of the same lexemes, so an
+/// offset is identified by the number of remaining lexemes.
+#[cfg(feature = "std")]
+pub(crate) fn scoped<T>(
+    rule: Rule,
+    l: &[Lexeme],
+    parse: fn(&[Lexeme]) -> Option<(T, usize)>,
+) -> Option<(T, usize)> {
+    let _depth = Depth::enter();
+    attempt(rule, l);
+    parse(l)
+}
+
+/// Parse a rule, or reuse its result if it was already parsed at the start
+/// of `l`
+#[cfg(feature = "std")]
+pub(crate) fn memoized<T: Clone + 'static>(
+    rule: Rule,
+    l: &[Lexeme],
+    parse: fn(&[Lexeme]) -> Option<(T, usize)>,
+) -> Option<(T, usize)> {
+    attempt(rule, l);
+    let key = (rule, l.len());
+    let cached = MEMO.with_borrow(|(table, _)| {
+        table
+            .get(&key)?
+            .downcast_ref::<Option<(T, usize)>>()
+            .cloned()
+    });
+    if let Some(result) = cached {
+        crate::stats::memo_hit();
+        return result;
+    }
+
+    let _depth = Depth::enter();
+    let result = parse(l);
+    MEMO.with_borrow_mut(|(table, _)| table.insert(key, alloc::boxed::Box::new(result.clone())));
+    result
+}
+
+#[cfg(not(feature = "std"))]
+struct Depth;
+
+#[cfg(not(feature = "std"))]
+impl Depth {
+    fn enter() -> Self {
+        with_furthest(Furthest::enter);
+        Depth
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl Drop for Depth {
+    fn drop(&mut self) {
+        with_furthest(Furthest::leave);
+    }
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn scoped<T>(
+    rule: Rule,
+    l: &[Lexeme],
+    parse: fn(&[Lexeme]) -> Option<(T, usize)>,
+) -> Option<(T, usize)> {
+    let _depth = Depth::enter();
+    attempt(rule, l);
+    parse(l)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn memoized<T>(
+    rule: Rule,
+    l: &[Lexeme],
+    parse: fn(&[Lexeme]) -> Option<(T, usize)>,
+) -> Option<(T, usize)> {
+    attempt(rule, l);
+    parse(l)
+}
+
+/// The furthest offset any rule was tried at since the outermost parse
+/// started, and the rules tried there
+struct Furthest {
+    /// One more than the number of lexemes left at the offset, above a bit
+    /// for each rule tried there, or zero before any attempt
+    packed: AtomicUsize,
+    /// How many parses are running
+    depth: AtomicUsize,
+}
+
+impl Furthest {
+    /// The bits below the number of lexemes left
+    const RULE_BITS: u32 = Rule::ALL.len() as u32;
+
+    const fn new() -> Self {
+        Self {
+            packed: AtomicUsize::new(0),
+            depth: AtomicUsize::new(0),
+        }
+    }
+
+    /// Start a parse, forgetting the last one if it is the outermost
+    fn enter(&self) {
+        if self.depth.fetch_add(1, Ordering::Relaxed) == 0 {
+            self.packed.store(0, Ordering::Relaxed);
+        }
+    }
+
+    fn leave(&self) {
+        self.depth.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    fn attempt(&self, rule: Rule, left: usize) {
+        // Inputs too long to pack are tracked as if they were shorter
+        let left = left.min((usize::MAX >> Self::RULE_BITS) - 1) + 1;
+        let bit = 1 << rule as usize;
+        let _ =
+            self.packed
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |packed| {
+                    match packed >> Self::RULE_BITS {
+                        furthest if furthest == left => Some(packed | bit),
+                        furthest if furthest != 0 && furthest < left => None,
+                        _ => Some(left << Self::RULE_BITS | bit),
+                    }
+                });
+    }
+
+    fn get(&self) -> Option<(usize, Vec<Rule>)> {
+        let packed = self.packed.load(Ordering::Relaxed);
+        let left = (packed >> Self::RULE_BITS).checked_sub(1)?;
+        let rules = Rule::ALL
+            .into_iter()
+            .filter(|&rule| packed & 1 << rule as usize != 0)
+            .collect();
+        Some((left, rules))
+    }
+}
+
+#[cfg(any(feature = "std", test))]
+std::thread_local! {
+    static FURTHEST: Furthest = const { Furthest::new() };
+}
+
+#[cfg(not(any(feature = "std", test)))]
+static FURTHEST: Furthest = Furthest::new();
+
+#[cfg(any(feature = "std", test))]
+fn with_furthest<R>(f: impl FnOnce(&Furthest) -> R) -> R {
+    FURTHEST.with(f)
+}
+
+#[cfg(not(any(feature = "std", test)))]
+fn with_furthest<R>(f: impl FnOnce(&Furthest) -> R) -> R {
+    f(&FURTHEST)
+}
+
+/// Note that a rule was tried at the start of `l`
+pub(crate) fn attempt(rule: Rule, l: &[Lexeme]) {
+    with_furthest(|furthest| furthest.attempt(rule, l.len()));
+}
+
+/// The number of lexemes left at the furthest offset any rule was tried at
+/// by the last parse, and the rules tried there
+pub(crate) fn furthest() -> Option<(usize, Vec<Rule>)> {
+    with_furthest(Furthest::get)
+}
+
+#[test]
+fn test_furthest() {
+    let furthest = Furthest::new();
+    assert_eq!(furthest.get(), None);
+
+    furthest.enter();
+    furthest.attempt(Rule::DateTime, 3);
+    furthest.attempt(Rule::Time, 1);
+    furthest.attempt(Rule::Num, 1);
+    furthest.attempt(Rule::Date, 2);
+    furthest.attempt(Rule::Num, 1);
+    assert_eq!(furthest.get(), Some((1, vec![Rule::Time, Rule::Num])));
+
+    // The end of the input is the furthest offset there is
+    furthest.attempt(Rule::Date, 0);
+    assert_eq!(furthest.get(), Some((0, vec![Rule::Date])));
+    furthest.leave();
+
+    // A new parse starts over
+    furthest.enter();
+    furthest.attempt(Rule::Month, 4);
+    assert_eq!(furthest.get(), Some((4, vec![Rule::Month])));
+    furthest.leave();
+}
@@ -0,0 +1,151 @@
+//! Named dates defined by applications, e.g. "payday" or "release day"
+//!
+//! An [`Anchor`] gives a name to a fixed date, a weekday, or a rule
+//! evaluated against a date, so domain specific words can be read without
+//! implementing [`NamedDate`] by hand.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+use super::NamedDate;
+
+/// How the occurrences of an anchor are found
+enum Rule {
+    Fixed(NaiveDate),
+    Weekday(Weekday),
+    Fn(Box<dyn Fn(NaiveDate) -> Option<NaiveDate> + Send + Sync>),
+}
+
+/// A date referred to by a name chosen by the application
+///
+/// ```rust
+/// use chrono::{NaiveDate, NaiveDateTime, Weekday};
+/// use fuzzydate::named::Anchor;
+/// use fuzzydate::{parse_with_options, ParseOptions};
+///
+/// let release = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+/// let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+/// let options = ParseOptions::new()
+///     .relative_to(now)
+///     .named_date(Anchor::fixed(["release day"], release))
+///     .named_date(Anchor::weekday(["payday"], Weekday::Fri));
+///
+/// let date = parse_with_options("2 days before release day", &options).unwrap();
+/// assert_eq!(date.date(), NaiveDate::from_ymd_opt(2024, 6, 29).unwrap());
+///
+/// let date = parse_with_options("next payday", &options).unwrap();
+/// assert_eq!(date.date(), NaiveDate::from_ymd_opt(2024, 6, 7).unwrap());
+/// ```
+pub struct Anchor {
+    names: Vec<String>,
+    rule: Rule,
+}
+
+impl Anchor {
+    /// An anchor on a single date
+    pub fn fixed(names: impl IntoIterator<Item = impl Into<String>>, date: NaiveDate) -> Self {
+        Self {
+            names: names.into_iter().map(Into::into).collect(),
+            rule: Rule::Fixed(date),
+        }
+    }
+
+    /// An anchor on every given weekday
+    pub fn weekday(names: impl IntoIterator<Item = impl Into<String>>, weekday: Weekday) -> Self {
+        Self {
+            names: names.into_iter().map(Into::into).collect(),
+            rule: Rule::Weekday(weekday),
+        }
+    }
+
+    /// An anchor whose first occurrence on or after a date is found by the
+    /// given function, e.g. the 15th of the month for a payday
+    pub fn from_fn(
+        names: impl IntoIterator<Item = impl Into<String>>,
+        next_occurrence: impl Fn(NaiveDate) -> Option<NaiveDate> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            names: names.into_iter().map(Into::into).collect(),
+            rule: Rule::Fn(Box::new(next_occurrence)),
+        }
+    }
+}
+
+impl core::fmt::Debug for Anchor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Anchor").field(&self.names).finish()
+    }
+}
+
+impl NamedDate for Anchor {
+    fn names(&self) -> Vec<&str> {
+        self.names.iter().map(String::as_str).collect()
+    }
+
+    fn next_occurrence(&self, date: NaiveDate) -> Option<NaiveDate> {
+        match &self.rule {
+            Rule::Fixed(fixed) => Some(*fixed).filter(|&fixed| fixed >= date),
+            Rule::Weekday(weekday) => {
+                let days = weekday.days_since(date.weekday());
+                date.checked_add_days(Days::new(days.into()))
+            }
+            Rule::Fn(next_occurrence) => next_occurrence(date).filter(|&next| next >= date),
+        }
+    }
+
+    fn previous_occurrence(&self, date: NaiveDate) -> Option<NaiveDate> {
+        match &self.rule {
+            Rule::Fixed(fixed) => Some(*fixed).filter(|&fixed| fixed <= date),
+            Rule::Weekday(weekday) => {
+                let days = date.weekday().days_since(*weekday);
+                date.checked_sub_days(Days::new(days.into()))
+            }
+            // A day is an occurrence if it is its own next occurrence, and
+            // rules are expected to recur at least once a year
+            Rule::Fn(next_occurrence) => (0..=366)
+                .filter_map(|days| date.checked_sub_days(Days::new(days)))
+                .find(|&day| next_occurrence(day) == Some(day)),
+        }
+    }
+}
+
+#[test]
+fn test_anchor() {
+    use crate::{parse_with_options, ParseOptions};
+    use chrono::NaiveDateTime;
+
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let payday = Anchor::from_fn(["payday"], |date| match date.day() <= 15 {
+        true => date.with_day(15),
+        false => date
+            .checked_add_months(chrono::Months::new(1))?
+            .with_day(15),
+    });
+    let sprint = Anchor::weekday(["sprint start"], Weekday::Wed);
+    let release = Anchor::fixed(["release day"], date(2024, 3, 20));
+
+    let now = NaiveDateTime::parse_from_str("2024-03-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new()
+        .relative_to(now)
+        .named_date(payday)
+        .named_date(sprint)
+        .named_date(release);
+
+    for (input, expected) in [
+        ("payday", "2024-03-15"),
+        ("next payday", "2024-03-15"),
+        ("last payday", "2024-02-15"),
+        ("the day after payday", "2024-03-16"),
+        ("sprint start", "2024-03-06"),
+        ("last sprint start", "2024-02-28"),
+        ("release day", "2024-03-20"),
+        ("3 days before release day", "2024-03-17"),
+    ] {
+        let date = parse_with_options(input, &options).unwrap();
+        assert_eq!(date.date().to_string(), expected, "{input}");
+    }
+
+    assert!(parse_with_options("last release day", &options).is_err());
+}
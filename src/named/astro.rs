@@ -0,0 +1,160 @@
+//! Phases of the moon, enabled by the `astro` feature
+//!
+//! Phases are computed with the truncated series from Jean Meeus'
+//! _Astronomical Algorithms_, chapter 49, which is accurate to within a few
+//! minutes. Dates are in UTC.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime};
+
+use super::NamedDate;
+
+/// Days between new moons on average
+const SYNODIC_MONTH: f64 = 29.530588861;
+
+/// Julian ephemeris day of the first new moon of 2000
+const NEW_MOON_2000: f64 = 2451550.09766;
+
+/// Julian day of the unix epoch
+const JD_UNIX_EPOCH: f64 = 2440587.5;
+
+/// The datetime of a phase of the moon, where `k` counts lunations since
+/// the first new moon of 2000 and is a whole number for new moons or half a
+/// number more for full moons
+fn phase(k: f64) -> Option<NaiveDateTime> {
+    let t = k / 1236.85;
+    let jde = NEW_MOON_2000 + SYNODIC_MONTH * k + 0.00015437 * t.powi(2) - 0.000000150 * t.powi(3)
+        + 0.00000000073 * t.powi(4);
+
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t.powi(2);
+    let m = (2.5534 + 29.10535670 * k - 0.0000014 * t.powi(2)).to_radians();
+    let mp = (201.5643 + 385.81693528 * k + 0.0107582 * t.powi(2)).to_radians();
+    let f = (160.7108 + 390.67050284 * k - 0.0016118 * t.powi(2)).to_radians();
+    let omega = (124.7746 - 1.56375588 * k + 0.0020672 * t.powi(2)).to_radians();
+
+    // The two leading coefficients differ between new and full moons
+    let (c1, c2, c3, c4, c5) = if k.fract() == 0.0 {
+        (-0.40720, 0.17241, 0.01608, 0.01039, 0.00739)
+    } else {
+        (-0.40614, 0.17302, 0.01614, 0.01043, 0.00734)
+    };
+
+    let correction = c1 * mp.sin()
+        + c2 * e * m.sin()
+        + c3 * (2.0 * mp).sin()
+        + c4 * (2.0 * f).sin()
+        + c5 * e * (mp - m).sin()
+        - 0.00514 * e * (mp + m).sin()
+        + 0.00208 * e * e * (2.0 * m).sin()
+        - 0.00111 * (mp - 2.0 * f).sin()
+        - 0.00057 * (mp + 2.0 * f).sin()
+        + 0.00056 * e * (2.0 * mp + m).sin()
+        - 0.00042 * (3.0 * mp).sin()
+        - 0.00017 * omega.sin();
+
+    let seconds = (jde + correction - JD_UNIX_EPOCH) * 86400.0;
+    DateTime::UNIX_EPOCH
+        .naive_utc()
+        .checked_add_signed(Duration::seconds(seconds as i64))
+}
+
+/// The number of lunations between the first new moon of 2000 and a date,
+/// rounded down
+fn lunations(date: NaiveDate) -> f64 {
+    let days = (date - NaiveDate::from_ymd_opt(2000, 1, 6).unwrap()).num_days();
+    (days as f64 / SYNODIC_MONTH).floor()
+}
+
+/// The first phase on or after the date, where `offset` is zero for new
+/// moons and a half for full moons
+fn next_phase(date: NaiveDate, offset: f64) -> Option<NaiveDate> {
+    let k = lunations(date) - 1.0;
+    (0..4)
+        .filter_map(|i| phase(k + i as f64 + offset))
+        .map(|datetime| datetime.date())
+        .find(|&phase| phase >= date)
+}
+
+/// The last phase on or before the date, where `offset` is zero for new
+/// moons and a half for full moons
+fn previous_phase(date: NaiveDate, offset: f64) -> Option<NaiveDate> {
+    let k = lunations(date) + 1.0;
+    (0..4)
+        .filter_map(|i| phase(k - i as f64 + offset))
+        .map(|datetime| datetime.date())
+        .find(|&phase| phase <= date)
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+/// The day of a new moon
+pub struct NewMoon;
+
+impl NamedDate for NewMoon {
+    fn names(&self) -> Vec<&str> {
+        vec!["new moon"]
+    }
+
+    fn next_occurrence(&self, date: NaiveDate) -> Option<NaiveDate> {
+        next_phase(date, 0.0)
+    }
+
+    fn previous_occurrence(&self, date: NaiveDate) -> Option<NaiveDate> {
+        previous_phase(date, 0.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+/// The day of a full moon
+pub struct FullMoon;
+
+impl NamedDate for FullMoon {
+    fn names(&self) -> Vec<&str> {
+        vec!["full moon"]
+    }
+
+    fn next_occurrence(&self, date: NaiveDate) -> Option<NaiveDate> {
+        next_phase(date, 0.5)
+    }
+
+    fn previous_occurrence(&self, date: NaiveDate) -> Option<NaiveDate> {
+        previous_phase(date, 0.5)
+    }
+}
+
+#[test]
+fn test_phases() {
+    // New moon on 2024-06-06 12:38 UTC, full moon on 2024-06-22 01:08 UTC
+    let new_moon = phase(302.0).unwrap();
+    assert_eq!(new_moon.format("%Y-%m-%d %H").to_string(), "2024-06-06 12");
+    let full_moon = phase(302.5).unwrap();
+    assert_eq!(full_moon.format("%Y-%m-%d %H").to_string(), "2024-06-22 01");
+
+    let date = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
+    assert_eq!(
+        FullMoon.next_occurrence(date).unwrap().to_string(),
+        "2024-06-22"
+    );
+    assert_eq!(
+        NewMoon.previous_occurrence(date).unwrap().to_string(),
+        "2024-06-06"
+    );
+    assert_eq!(
+        NewMoon.next_occurrence(date).unwrap().to_string(),
+        "2024-07-05"
+    );
+}
+
+#[test]
+fn test_friday_after_full_moon() {
+    use crate::{parse_with_options, ParseOptions};
+
+    let now = NaiveDateTime::parse_from_str("2024-06-10 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now);
+
+    let date = parse_with_options("the Friday after the next full moon", &options).unwrap();
+    assert_eq!(date.date().to_string(), "2024-06-28");
+
+    let date = parse_with_options("last new moon", &options).unwrap();
+    assert_eq!(date.date().to_string(), "2024-06-06");
+}
@@ -0,0 +1,84 @@
+//! Days of the Roman calendar, enabled by the `classical` feature
+//!
+//! Each month has three named days: the kalends on the first, the nones on
+//! the fifth, and the ides on the thirteenth. In March, May, July, and
+//! October the nones and ides fall two days later.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use chrono::NaiveDate;
+
+use super::NamedDate;
+
+/// Whether the nones and ides of the month fall two days later
+fn is_long_month(month: u32) -> bool {
+    matches!(month, 3 | 5 | 7 | 10)
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+/// The first day of the month
+pub struct Kalends;
+
+impl NamedDate for Kalends {
+    fn names(&self) -> Vec<&str> {
+        vec!["kalends", "calends"]
+    }
+
+    fn in_month(&self, year: i32, month: u32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(year, month, 1)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+/// The fifth day of the month, or the seventh in March, May, July, and
+/// October
+pub struct Nones;
+
+impl NamedDate for Nones {
+    fn names(&self) -> Vec<&str> {
+        vec!["nones"]
+    }
+
+    fn in_month(&self, year: i32, month: u32) -> Option<NaiveDate> {
+        let day = if is_long_month(month) { 7 } else { 5 };
+        NaiveDate::from_ymd_opt(year, month, day)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+/// The thirteenth day of the month, or the fifteenth in March, May, July,
+/// and October
+pub struct Ides;
+
+impl NamedDate for Ides {
+    fn names(&self) -> Vec<&str> {
+        vec!["ides"]
+    }
+
+    fn in_month(&self, year: i32, month: u32) -> Option<NaiveDate> {
+        let day = if is_long_month(month) { 15 } else { 13 };
+        NaiveDate::from_ymd_opt(year, month, day)
+    }
+}
+
+#[test]
+fn test_ides_of_march() {
+    use crate::{parse_with_options, ParseOptions};
+    use chrono::NaiveDateTime;
+
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now);
+
+    for (input, expected) in [
+        ("the ides of March", "2024-03-15"),
+        ("the Ides of March 44", "1944-03-15"),
+        ("the nones of june", "2024-06-05"),
+        ("kalends of July", "2024-07-01"),
+        ("the ides", "2024-06-13"),
+        ("next kalends", "2024-07-01"),
+        ("last ides", "2024-05-15"),
+    ] {
+        let date = parse_with_options(input, &options).unwrap();
+        assert_eq!(date.date().to_string(), expected, "{input}");
+    }
+}
@@ -0,0 +1,79 @@
+//! Holidays whose date moves from year to year, enabled by the `holidays`
+//! feature
+
+use alloc::vec;
+use alloc::vec::Vec;
+use chrono::NaiveDate;
+
+use super::NamedDate;
+
+#[derive(Debug, Clone, Copy, Default)]
+/// Easter Sunday in the Gregorian calendar
+pub struct Easter;
+
+impl Easter {
+    /// The date of Easter Sunday in a year, by the anonymous Gregorian
+    /// algorithm
+    pub fn in_year(year: i32) -> Option<NaiveDate> {
+        let a = year.rem_euclid(19);
+        let b = year.div_euclid(100);
+        let c = year.rem_euclid(100);
+        let d = b / 4;
+        let e = b % 4;
+        let f = (b + 8) / 25;
+        let g = (b - f + 1) / 3;
+        let h = (19 * a + b - d - g + 15) % 30;
+        let i = c / 4;
+        let k = c % 4;
+        let l = (32 + 2 * e + 2 * i - h - k) % 7;
+        let m = (a + 11 * h + 22 * l) / 451;
+        let month = (h + l - 7 * m + 114) / 31;
+        let day = (h + l - 7 * m + 114) % 31 + 1;
+
+        NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+    }
+}
+
+impl NamedDate for Easter {
+    fn names(&self) -> Vec<&str> {
+        vec!["easter", "easter sunday"]
+    }
+
+    fn in_month(&self, year: i32, month: u32) -> Option<NaiveDate> {
+        Self::in_year(year).filter(|date| chrono::Datelike::month(date) == month)
+    }
+}
+
+#[test]
+fn test_easter() {
+    for (year, expected) in [
+        (2000, "2000-04-23"),
+        (2019, "2019-04-21"),
+        (2024, "2024-03-31"),
+        (2025, "2025-04-20"),
+        (2038, "2038-04-25"),
+    ] {
+        assert_eq!(Easter::in_year(year).unwrap().to_string(), expected);
+    }
+}
+
+#[test]
+fn test_sundays_after_easter() {
+    use crate::{parse_with_options, ParseOptions};
+    use chrono::NaiveDateTime;
+
+    let now = NaiveDateTime::parse_from_str("2024-01-10 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now);
+
+    for (input, expected) in [
+        ("easter", "2024-03-31"),
+        ("easter sunday", "2024-03-31"),
+        ("the friday before easter", "2024-03-29"),
+        ("3 sundays after easter", "2024-04-21"),
+        ("two fridays before next easter", "2024-03-22"),
+        ("3 sundays after 6/1/2024", "2024-06-16"),
+    ] {
+        let date = parse_with_options(input, &options).unwrap();
+        assert_eq!(date.date().to_string(), expected, "{input}");
+    }
+}
@@ -0,0 +1,97 @@
+//! Dates which are referred to by name, e.g. "the ides of March"
+//!
+//! A [`NamedDate`] knows the names it is referred to by and how to find its
+//! occurrences. Named dates are registered on [`crate::ParseOptions`], so
+//! applications can add their own, and the dates enabled by cargo features
+//! are registered by default:
+//!
+//! - `classical`: the Roman kalends, nones, and ides of a month
+//! - `astro`: new and full moons
+//! - `holidays`: movable feasts, e.g. Easter
+//!
+//! A named date may also name a period, like a [`Term`] of a school
+//! calendar, whose occurrences are the days each period starts. An
+//! [`Anchor`] names a fixed date, a weekday, or a rule of the application,
+//! e.g. "payday".
+
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use chrono::{Datelike, Months, NaiveDate};
+
+pub mod anchor;
+#[cfg(feature = "astro")]
+pub mod astro;
+#[cfg(feature = "classical")]
+pub mod classical;
+#[cfg(feature = "holidays")]
+pub mod holidays;
+pub mod term;
+
+pub use anchor::Anchor;
+pub use term::Term;
+
+/// A date, or a series of dates, which is referred to by name
+pub trait NamedDate: Send + Sync {
+    /// Words or phrases naming the date, in lowercase
+    fn names(&self) -> Vec<&str>;
+
+    /// The occurrence within a month, for dates which occur once a month
+    /// and may be qualified by one, e.g. "the ides of March"
+    fn in_month(&self, _year: i32, _month: u32) -> Option<NaiveDate> {
+        None
+    }
+
+    /// The first occurrence on or after the given date
+    fn next_occurrence(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let first = date.with_day(1)?;
+        (0..12)
+            .filter_map(|i| first.checked_add_months(Months::new(i)))
+            .filter_map(|month| self.in_month(month.year(), month.month()))
+            .find(|&occurrence| occurrence >= date)
+    }
+
+    /// The last occurrence on or before the given date
+    fn previous_occurrence(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let first = date.with_day(1)?;
+        (0..12)
+            .filter_map(|i| first.checked_sub_months(Months::new(i)))
+            .filter_map(|month| self.in_month(month.year(), month.month()))
+            .find(|&occurrence| occurrence <= date)
+    }
+
+    /// The last day of the period starting on the given occurrence, for
+    /// dates which name a period, e.g. a school term
+    fn period_end(&self, _start: NaiveDate) -> Option<NaiveDate> {
+        None
+    }
+}
+
+impl core::fmt::Debug for dyn NamedDate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("NamedDate").field(&self.names()).finish()
+    }
+}
+
+/// The named dates enabled by cargo features
+pub(crate) fn builtin() -> Vec<Arc<dyn NamedDate>> {
+    #[allow(unused_mut)]
+    let mut dates: Vec<Arc<dyn NamedDate>> = Vec::new();
+
+    #[cfg(feature = "classical")]
+    dates.extend([
+        Arc::new(classical::Kalends) as Arc<dyn NamedDate>,
+        Arc::new(classical::Nones),
+        Arc::new(classical::Ides),
+    ]);
+
+    #[cfg(feature = "astro")]
+    dates.extend([
+        Arc::new(astro::NewMoon) as Arc<dyn NamedDate>,
+        Arc::new(astro::FullMoon),
+    ]);
+
+    #[cfg(feature = "holidays")]
+    dates.push(Arc::new(holidays::Easter));
+
+    dates
+}
@@ -0,0 +1,115 @@
+//! Named periods with a start and an end, e.g. school terms
+//!
+//! A [`Term`] is a named date whose occurrences are the first days of its
+//! periods, so "the fall semester" is the day it starts. The end of a period
+//! can be referred to with "the end of the fall semester", and
+//! [`crate::parse_range`] reads the name as the whole period.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use chrono::NaiveDate;
+
+use super::NamedDate;
+
+#[derive(Debug, Clone, Default)]
+/// A named period which recurs on the given dates, e.g. a semester
+///
+/// ```rust
+/// use chrono::{NaiveDate, NaiveDateTime};
+/// use fuzzydate::named::Term;
+/// use fuzzydate::{parse_with_options, ParseOptions};
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+/// let fall = Term::new(["fall semester", "fall term"])
+///     .period(date(2024, 8, 26), date(2024, 12, 13))
+///     .period(date(2025, 8, 25), date(2025, 12, 12));
+///
+/// let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+/// let options = ParseOptions::new()
+///     .relative_to(now)
+///     .named_date(fall);
+///
+/// let end = parse_with_options("the end of the fall semester", &options).unwrap();
+/// assert_eq!(end.date(), date(2024, 12, 13));
+/// ```
+pub struct Term {
+    names: Vec<String>,
+    periods: Vec<(NaiveDate, NaiveDate)>,
+}
+
+impl Term {
+    /// A term referred to by the given names, in lowercase, without any
+    /// periods
+    pub fn new(names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            names: names.into_iter().map(Into::into).collect(),
+            periods: Vec::new(),
+        }
+    }
+
+    /// Add a period from its first to its last day, inclusive
+    pub fn period(mut self, start: NaiveDate, end: NaiveDate) -> Self {
+        let i = self.periods.partition_point(|&(s, _)| s < start);
+        self.periods.insert(i, (start, end));
+        self
+    }
+}
+
+impl NamedDate for Term {
+    fn names(&self) -> Vec<&str> {
+        self.names.iter().map(String::as_str).collect()
+    }
+
+    fn next_occurrence(&self, date: NaiveDate) -> Option<NaiveDate> {
+        self.periods
+            .iter()
+            .map(|&(start, _)| start)
+            .find(|&start| start >= date)
+    }
+
+    fn previous_occurrence(&self, date: NaiveDate) -> Option<NaiveDate> {
+        self.periods
+            .iter()
+            .rev()
+            .map(|&(start, _)| start)
+            .find(|&start| start <= date)
+    }
+
+    fn period_end(&self, start: NaiveDate) -> Option<NaiveDate> {
+        self.periods
+            .iter()
+            .find(|&&(s, _)| s == start)
+            .map(|&(_, end)| end)
+    }
+}
+
+#[test]
+fn test_term() {
+    use crate::{parse_range_with_options, parse_with_options, ParseOptions};
+    use chrono::NaiveDateTime;
+
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let spring = Term::new(["spring semester"])
+        .period(date(2025, 1, 13), date(2025, 5, 2))
+        .period(date(2024, 1, 15), date(2024, 5, 3));
+
+    let now = NaiveDateTime::parse_from_str("2024-03-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let options = ParseOptions::new().relative_to(now).named_date(spring);
+
+    for (input, expected) in [
+        ("spring semester", "2024-01-15"),
+        ("the start of the spring semester", "2024-01-15"),
+        ("the end of the spring semester", "2024-05-03"),
+        ("end of next spring semester", "2025-05-02"),
+        ("the next spring semester", "2025-01-13"),
+    ] {
+        let date = parse_with_options(input, &options).unwrap();
+        assert_eq!(date.date().to_string(), expected, "{input}");
+    }
+
+    let range = parse_range_with_options("the spring semester", &options).unwrap();
+    assert_eq!(range.start, date(2024, 1, 15).and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(range.end, date(2024, 5, 4).and_hms_opt(0, 0, 0).unwrap());
+
+    assert!(parse_with_options("last spring semester", &options).is_err());
+}
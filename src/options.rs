@@ -0,0 +1,545 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+#[cfg(feature = "clock")]
+use chrono::Local;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
+
+use crate::lang::{English, Language};
+use crate::lexer::Lexeme;
+use crate::named::NamedDate;
+
+#[derive(Debug, Clone, Copy, Default)]
+/// How to interpret years written with three digits, e.g. `"5/12/225"`.
+///
+/// These are almost always data-entry mistakes, so applications may prefer
+/// to reject them or map them onto a plausible year.
+pub enum ThreeDigitYears {
+    /// Use the year as written, `225` is the year 225 AD
+    #[default]
+    Literal,
+    /// Fail with `Error::InvalidDate`
+    Reject,
+    /// Keep the last two digits and place them in the given century,
+    /// e.g. with `Century(20)` the year `225` becomes `2025`
+    Century(u32),
+    /// Map the year with a custom rule, returning `None` to reject it
+    Map(fn(u32) -> Option<u32>),
+}
+
+impl ThreeDigitYears {
+    /// Apply the rule to a year between 100 and 999
+    pub(crate) fn resolve(&self, year: u32) -> Option<u32> {
+        match *self {
+            ThreeDigitYears::Literal => Some(year),
+            ThreeDigitYears::Reject => None,
+            ThreeDigitYears::Century(century) => Some(century * 100 + year % 100),
+            ThreeDigitYears::Map(rule) => rule(year),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Whether the end of a range is part of it
+pub enum Bounds {
+    /// The range includes its start but not its end
+    HalfOpen,
+    /// The range includes both its start and its end, which is at the end
+    /// of its day when it was given without a time
+    Closed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The words joining the start and end of a range
+pub enum Connector {
+    /// "monday to friday", closed by default
+    To,
+    /// "monday through friday", closed by default
+    Through,
+    /// "monday until friday", half-open by default
+    Until,
+    /// "between monday and friday", closed by default
+    Between,
+}
+
+impl Connector {
+    fn default_bounds(self) -> Bounds {
+        match self {
+            Connector::Until => Bounds::HalfOpen,
+            Connector::To | Connector::Through | Connector::Between => Bounds::Closed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A part of the day named by a word, e.g. "tomorrow evening"
+pub enum DayPart {
+    /// "morning", 9:00 by default
+    Morning,
+    /// "afternoon", 15:00 by default
+    Afternoon,
+    /// "evening", 19:00 by default
+    Evening,
+    /// "night" or "tonight", 22:00 by default
+    Night,
+}
+
+impl DayPart {
+    fn default_time(self) -> NaiveTime {
+        let hour = match self {
+            DayPart::Morning => 9,
+            DayPart::Afternoon => 15,
+            DayPart::Evening => 19,
+            DayPart::Night => 22,
+        };
+        NaiveTime::from_hms_opt(hour, 0, 0).unwrap()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// A count given by a word, e.g. "a couple of days"
+pub enum Quantity {
+    /// "a couple", 2 by default
+    Couple,
+    /// "a few", 3 by default
+    Few,
+    /// "several", 3 by default
+    Several,
+}
+
+impl Quantity {
+    pub(crate) fn default_count(self) -> u32 {
+        match self {
+            Quantity::Couple => 2,
+            Quantity::Few | Quantity::Several => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// How to resolve a day past the end of the month reached by adding or
+/// subtracting months, e.g. "3 months before May 31"
+pub enum MonthOverflow {
+    /// Use the last day of the month, February 29th in 2024
+    #[default]
+    Clamp,
+    /// Carry the extra days into the next month, March 2nd in 2024
+    Roll,
+    /// Fail with [`crate::Error::InvalidDate`]
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// How `aware_parse` resolves a local time which is ambiguous or doesn't
+/// exist in the timezone, e.g. because of a DST transition
+pub enum DstPolicy {
+    /// Take the earlier of two instants, and fail for nonexistent times
+    #[default]
+    Earliest,
+    /// Take the later of two instants, and fail for nonexistent times
+    Latest,
+    /// Take the earlier of two instants, and move nonexistent times forward
+    /// by the length of the gap, e.g. 2:30 to 3:30 when clocks skip an hour
+    ShiftForward,
+    /// Fail for both ambiguous and nonexistent times
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// What to do when a date led by its weekday falls on another weekday,
+/// e.g. "Friday, June 12 2025" when June 12 is a Thursday
+pub enum WeekdayCheck {
+    /// Use the date and disregard the weekday
+    #[default]
+    Ignore,
+    /// Use the date and report the mismatch in
+    /// [`crate::Parsed::weekday_mismatch`]
+    Warn,
+    /// Fail with [`crate::Error::InvalidDate`]
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Which half hour "half" and an hour refer to, e.g. "half eleven"
+pub enum HalfHour {
+    /// Half past the hour, "half eleven" is 11:30 as in British English
+    Past,
+    /// Half to the hour, "halb elf" is 10:30 as in German
+    To,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// Which time to use when the input doesn't specify one, e.g. `"tomorrow"`
+pub enum DefaultTime {
+    /// The time of the reference datetime
+    #[default]
+    Current,
+    /// Midnight at the start of the day
+    StartOfDay,
+    /// A fixed time of day
+    Fixed(NaiveTime),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The order of the fields in numeric dates such as `"5/12/2023"`
+pub enum DateOrder {
+    /// Month, day, year: `"5/12/2023"` is May 12th
+    MonthDayYear,
+    /// Day, month, year: `"5/12/2023"` is December 5th
+    DayMonthYear,
+    /// Year, month, day: `"2023/5/12"` is May 12th
+    YearMonthDay,
+}
+
+#[derive(Debug, Clone)]
+/// Options controlling how input strings are interpreted, built up with
+/// chained calls and passed to the `*_with_options` functions
+///
+/// ```rust
+/// use fuzzydate::{parse_with_options, DefaultTime, ParseOptions};
+/// use chrono::NaiveDate;
+///
+/// let now = NaiveDate::from_ymd_opt(2024, 6, 1)
+///     .unwrap()
+///     .and_hms_opt(12, 0, 0)
+///     .unwrap();
+/// let options = ParseOptions::new()
+///     .relative_to(now)
+///     .default_time(DefaultTime::StartOfDay);
+///
+/// let date = parse_with_options("tomorrow", &options).unwrap();
+/// assert_eq!(date.to_string(), "2024-06-02 00:00:00");
+/// ```
+pub struct ParseOptions {
+    pub(crate) relative_to: Option<NaiveDateTime>,
+    pub(crate) default_time: DefaultTime,
+    pub(crate) three_digit_years: ThreeDigitYears,
+    pub(crate) date_order: Option<DateOrder>,
+    pub(crate) language: &'static dyn Language,
+    pub(crate) synonyms: BTreeMap<String, Lexeme>,
+    pub(crate) typo_tolerant: bool,
+    pub(crate) strict: bool,
+    pub(crate) strict_time: bool,
+    pub(crate) minimal_grammar: bool,
+    pub(crate) range_bounds: BTreeMap<Connector, Bounds>,
+    pub(crate) day_parts: BTreeMap<DayPart, NaiveTime>,
+    pub(crate) quantities: BTreeMap<Quantity, u32>,
+    pub(crate) holidays: BTreeSet<NaiveDate>,
+    pub(crate) month_overflow: MonthOverflow,
+    pub(crate) dst_policy: DstPolicy,
+    pub(crate) weekday_check: WeekdayCheck,
+    pub(crate) half_hour: Option<HalfHour>,
+    pub(crate) deadline_time: Option<NaiveTime>,
+    pub(crate) year_anchor: (u32, u32),
+    pub(crate) month_anchor: u32,
+    pub(crate) named_dates: Vec<Arc<dyn NamedDate>>,
+    /// The unix epoch as a local datetime, set by `aware_parse`
+    pub(crate) epoch: Option<NaiveDateTime>,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            relative_to: None,
+            default_time: DefaultTime::default(),
+            three_digit_years: ThreeDigitYears::default(),
+            date_order: None,
+            language: &English,
+            synonyms: BTreeMap::new(),
+            typo_tolerant: false,
+            strict: false,
+            strict_time: false,
+            minimal_grammar: false,
+            range_bounds: BTreeMap::new(),
+            day_parts: BTreeMap::new(),
+            quantities: BTreeMap::new(),
+            holidays: BTreeSet::new(),
+            month_overflow: MonthOverflow::default(),
+            dst_policy: DstPolicy::default(),
+            weekday_check: WeekdayCheck::default(),
+            half_hour: None,
+            deadline_time: None,
+            year_anchor: (1, 1),
+            month_anchor: 1,
+            named_dates: crate::named::builtin(),
+            epoch: None,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Create options with the default behaviour of `parse`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Treat the given datetime as the current time, rather than now
+    pub fn relative_to(mut self, relative_to: NaiveDateTime) -> Self {
+        self.relative_to = Some(relative_to);
+        self
+    }
+
+    /// Set the time used when the input doesn't specify one
+    pub fn default_time(mut self, default_time: DefaultTime) -> Self {
+        self.default_time = default_time;
+        self
+    }
+
+    /// Set how years between 100 and 999 are interpreted
+    pub fn three_digit_years(mut self, three_digit_years: ThreeDigitYears) -> Self {
+        self.three_digit_years = three_digit_years;
+        self
+    }
+
+    /// Set the order of the fields in numeric dates.
+    ///
+    /// By default dates separated by dots are read day first and all
+    /// others month first. Dates starting with a four digit year are always
+    /// read year, month, day.
+    pub fn date_order(mut self, date_order: DateOrder) -> Self {
+        self.date_order = Some(date_order);
+        self
+    }
+
+    /// Set the language of the input, English by default
+    pub fn language(mut self, language: &'static dyn Language) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// Register an additional word or phrase for an existing lexeme, e.g.
+    /// "tmrw" for [`Lexeme::Tomorrow`]. Synonyms are case insensitive and
+    /// take precedence over the words of the language.
+    ///
+    /// ```rust
+    /// use chrono::NaiveDateTime;
+    /// use fuzzydate::lexer::Lexeme;
+    /// use fuzzydate::ParseOptions;
+    ///
+    /// let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    /// let options = ParseOptions::new()
+    ///     .relative_to(now)
+    ///     .synonym("tmrw", Lexeme::Tomorrow)
+    ///     .synonym("wk", Lexeme::Week);
+    ///
+    /// assert!(fuzzydate::parse_with_options("1 wk after tmrw", &options).is_ok());
+    /// ```
+    pub fn synonym(mut self, word: impl AsRef<str>, lexeme: Lexeme) -> Self {
+        let word = word
+            .as_ref()
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        self.synonyms.insert(word, lexeme);
+        self
+    }
+
+    /// Register a date which may be referred to by name, in addition to
+    /// the named dates enabled by cargo features
+    pub fn named_date(mut self, named_date: impl NamedDate + 'static) -> Self {
+        self.named_dates.push(Arc::new(named_date));
+        self
+    }
+
+    /// Read misspelled words as the closest known word, e.g. "tommorrow" as
+    /// "tomorrow". Off by default; corrections are reported by
+    /// [`crate::parse_detailed`].
+    pub fn typo_tolerant(mut self, typo_tolerant: bool) -> Self {
+        self.typo_tolerant = typo_tolerant;
+        self
+    }
+
+    /// Fail with `Error::Ambiguous` instead of guessing when the input has
+    /// more than one reading: numeric dates whose month and day could be
+    /// swapped while no date order is configured, two digit years, and bare
+    /// weekdays. Off by default.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Fail with `Error::InvalidDate` before parsing when a clock time
+    /// without am or pm is past the end of a 24 hour clock, e.g. "25:99"
+    /// or "24:00", reporting where it is in the input. Such times are
+    /// otherwise only rejected once the parsed expression is resolved. Off
+    /// by default.
+    pub fn strict_time(mut self, strict_time: bool) -> Self {
+        self.strict_time = strict_time;
+        self
+    }
+
+    /// Only accept input matching the grammar documented at the root of
+    /// the crate, for behaviour which stays the same across versions. The
+    /// whole input must be used, where trailing words are otherwise
+    /// ignored, misspelled words are not corrected, and undocumented forms
+    /// like "5pm tomorrow" or "June 5" fail with `Error::ParseError`. Off
+    /// by default.
+    pub fn minimal_grammar(mut self, minimal_grammar: bool) -> Self {
+        self.minimal_grammar = minimal_grammar;
+        self
+    }
+
+    /// Set whether ranges joined by the given connector include their end,
+    /// e.g. to read "monday to friday" as half-open. ISO 8601 intervals,
+    /// counted periods, and named periods are always half-open.
+    pub fn range_bounds(mut self, connector: Connector, bounds: Bounds) -> Self {
+        self.range_bounds.insert(connector, bounds);
+        self
+    }
+
+    /// Whether ranges joined by the given connector include their end
+    pub(crate) fn bounds(&self, connector: Connector) -> Bounds {
+        self.range_bounds
+            .get(&connector)
+            .copied()
+            .unwrap_or(connector.default_bounds())
+    }
+
+    /// Set the time a part of the day resolves to, e.g. to read "tomorrow
+    /// evening" as 18:00
+    pub fn day_part(mut self, part: DayPart, time: NaiveTime) -> Self {
+        self.day_parts.insert(part, time);
+        self
+    }
+
+    /// The time a part of the day resolves to
+    pub(crate) fn day_part_time(&self, part: DayPart) -> NaiveTime {
+        self.day_parts
+            .get(&part)
+            .copied()
+            .unwrap_or(part.default_time())
+    }
+
+    /// Set how to resolve a day past the end of the month reached by
+    /// adding or subtracting months or years
+    pub fn month_overflow(mut self, month_overflow: MonthOverflow) -> Self {
+        self.month_overflow = month_overflow;
+        self
+    }
+
+    /// Set the month and day a year on its own is read as, e.g. `(4, 1)` to
+    /// read "2030" as the start of a fiscal year on April 1st
+    pub fn year_anchor(mut self, month: u32, day: u32) -> Self {
+        self.year_anchor = (month, day);
+        self
+    }
+
+    /// Set the day a month and year without a day is read as, e.g. 15 to
+    /// read "June 2025" as June 15th. Days past the end of the month are
+    /// read as its last day, so 31 always reads the end of the month.
+    pub fn month_anchor(mut self, day: u32) -> Self {
+        self.month_anchor = day;
+        self
+    }
+
+    /// Set how `aware_parse` resolves local times which are ambiguous or
+    /// don't exist in the timezone
+    pub fn dst_policy(mut self, dst_policy: DstPolicy) -> Self {
+        self.dst_policy = dst_policy;
+        self
+    }
+
+    /// Set how to treat a date whose leading weekday doesn't match it, e.g.
+    /// to catch typos in "Friday, June 12 2025"
+    pub fn weekday_check(mut self, weekday_check: WeekdayCheck) -> Self {
+        self.weekday_check = weekday_check;
+        self
+    }
+
+    /// Set which half hour "half" and an hour refer to, rather than the one
+    /// of the language, e.g. [`HalfHour::To`] to read "half eleven" as 10:30
+    pub fn half_hour(mut self, half_hour: HalfHour) -> Self {
+        self.half_hour = Some(half_hour);
+        self
+    }
+
+    /// The half hour "half" and an hour refer to
+    pub(crate) fn half_hour_reading(&self) -> HalfHour {
+        self.half_hour.unwrap_or(self.language.half_hour())
+    }
+
+    /// Set the time of a deadline given without one, e.g. "by friday" or
+    /// "no later than the 15th". By default the whole day is allowed, up to
+    /// but excluding the start of the next day.
+    pub fn deadline_time(mut self, deadline_time: NaiveTime) -> Self {
+        self.deadline_time = Some(deadline_time);
+        self
+    }
+
+    /// Set the count a word stands for, e.g. to read "several weeks" as 5
+    /// weeks
+    pub fn quantity(mut self, quantity: Quantity, count: u32) -> Self {
+        self.quantities.insert(quantity, count);
+        self
+    }
+
+    /// Add dates which aren't business days, so they are skipped by
+    /// durations such as "5 business days from today"
+    pub fn holidays(mut self, holidays: impl IntoIterator<Item = NaiveDate>) -> Self {
+        self.holidays.extend(holidays);
+        self
+    }
+
+    /// Whether a date is neither on a weekend nor a holiday
+    pub(crate) fn is_business_day(&self, date: NaiveDate) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !self.holidays.contains(&date)
+    }
+
+    /// The count a word stands for
+    pub(crate) fn count(&self, quantity: Quantity) -> u32 {
+        self.quantities
+            .get(&quantity)
+            .copied()
+            .unwrap_or(quantity.default_count())
+    }
+
+    /// The datetime treated as the current time
+    pub(crate) fn now(&self) -> Result<NaiveDateTime, crate::Error> {
+        match self.relative_to {
+            Some(now) => Ok(now),
+            None => local_now(),
+        }
+    }
+
+    /// The datetime "epoch" refers to, which is midnight UTC on
+    /// 1970-01-01 unless a timezone is known
+    pub(crate) fn epoch(&self) -> NaiveDateTime {
+        self.epoch.unwrap_or(DateTime::UNIX_EPOCH.naive_utc())
+    }
+
+    /// The time used when the input doesn't specify one, given the
+    /// datetime treated as the current time
+    pub(crate) fn time(&self, now: NaiveDateTime) -> NaiveTime {
+        match self.default_time {
+            DefaultTime::Current => now.time(),
+            DefaultTime::StartOfDay => NaiveTime::MIN,
+            DefaultTime::Fixed(time) => time,
+        }
+    }
+}
+
+/// The current local datetime, which is only available with the `clock`
+/// feature
+pub(crate) fn local_now() -> Result<NaiveDateTime, crate::Error> {
+    #[cfg(feature = "clock")]
+    return Ok(Local::now().naive_local());
+
+    #[cfg(not(feature = "clock"))]
+    Err(crate::Error::MissingReference)
+}
+
+/// The current UTC datetime, which is only available with the `clock`
+/// feature
+pub(crate) fn utc_now() -> Result<DateTime<Utc>, crate::Error> {
+    #[cfg(feature = "clock")]
+    return Ok(Utc::now());
+
+    #[cfg(not(feature = "clock"))]
+    Err(crate::Error::MissingReference)
+}
@@ -0,0 +1,165 @@
+//! A parser configured once and then used for any number of inputs
+
+use chrono::NaiveDateTime;
+
+use crate::lexer::Vocabulary;
+use crate::{
+    constraint, recurrence, Constraint, DateRange, Error, Output, ParseOptions, Parsed, Recurrence,
+};
+
+/// Parses inputs with a fixed set of options, gathering the vocabulary of
+/// the language, synonyms and named dates once rather than for every input
+///
+/// ```rust
+/// use chrono::NaiveDateTime;
+/// use fuzzydate::lexer::Lexeme;
+/// use fuzzydate::{DateOrder, ParseOptions, Parser};
+///
+/// let parser = Parser::new(
+///     ParseOptions::new()
+///         .date_order(DateOrder::DayMonthYear)
+///         .synonym("tmrw", Lexeme::Tomorrow),
+/// );
+///
+/// let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+/// let date = parser.parse("tmrw at 5pm", now).unwrap();
+/// assert_eq!(date.to_string(), "2024-06-02 17:00:00");
+///
+/// let date = parser.parse("3/7/2024", now).unwrap();
+/// assert_eq!(date.to_string(), "2024-07-03 12:00:00");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Parser {
+    options: ParseOptions,
+    vocabulary: Vocabulary,
+}
+
+impl Parser {
+    /// A parser which interprets inputs according to the given options
+    pub fn new(options: ParseOptions) -> Self {
+        let vocabulary = Vocabulary::new(&options);
+        Self {
+            options,
+            vocabulary,
+        }
+    }
+
+    /// The options inputs are interpreted with
+    pub fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
+    /// Parse an input string into a chrono NaiveDateTime, treating `now` as
+    /// the current time
+    pub fn parse(&self, input: impl AsRef<str>, now: NaiveDateTime) -> Output {
+        self.parse_detailed(input, now)
+            .map(|parsed| parsed.datetime)
+    }
+
+    /// Parse an input string into a chrono NaiveDateTime, reporting how it
+    /// was interpreted, treating `now` as the current time
+    pub fn parse_detailed(
+        &self,
+        input: impl AsRef<str>,
+        now: NaiveDateTime,
+    ) -> Result<Parsed, Error> {
+        crate::parse_detailed_compiled(input.as_ref(), &self.options, &self.vocabulary, Some(now))
+    }
+
+    /// Parse an input string describing a period of time into a DateRange,
+    /// treating `now` as the current time
+    pub fn parse_range(
+        &self,
+        input: impl AsRef<str>,
+        now: NaiveDateTime,
+    ) -> Result<DateRange, Error> {
+        crate::parse_range_compiled(input.as_ref(), &self.options, &self.vocabulary, Some(now))
+    }
+
+    /// Parse an input string describing a recurrence, starting from `now`
+    pub fn parse_recurrence(
+        &self,
+        input: impl AsRef<str>,
+        now: NaiveDateTime,
+    ) -> Result<Recurrence, Error> {
+        recurrence::parse_recurrence_compiled(
+            input.as_ref(),
+            &self.options,
+            &self.vocabulary,
+            Some(now),
+        )
+    }
+
+    /// Parse an input string describing an open-ended bound on a datetime,
+    /// treating `now` as the current time
+    pub fn parse_constraint(
+        &self,
+        input: impl AsRef<str>,
+        now: NaiveDateTime,
+    ) -> Result<Constraint, Error> {
+        constraint::parse_constraint_compiled(
+            input.as_ref(),
+            &self.options,
+            &self.vocabulary,
+            Some(now),
+        )
+    }
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self::new(ParseOptions::default())
+    }
+}
+
+impl From<ParseOptions> for Parser {
+    fn from(options: ParseOptions) -> Self {
+        Self::new(options)
+    }
+}
+
+#[test]
+fn test_parser() {
+    use crate::lexer::Lexeme;
+    use crate::named::Anchor;
+    use crate::parse_with_options;
+    use chrono::NaiveDate;
+
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let release = NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+    let options = ParseOptions::new()
+        .synonym("eod", Lexeme::Midnight)
+        .synonym("the morrow", Lexeme::Tomorrow)
+        .named_date(Anchor::fixed(["release day"], release))
+        .named_date(Anchor::fixed(["launch"], release));
+    let parser = Parser::new(options.clone());
+
+    // The same input means the same with a parser as with its options
+    for input in [
+        "tomorrow at 5pm",
+        "the morrow at eod",
+        "2 days before release day",
+        "launch",
+    ] {
+        let expected = parse_with_options(input, &options.clone().relative_to(now)).unwrap();
+        assert_eq!(parser.parse(input, now).unwrap(), expected, "{input}");
+    }
+
+    // The reference is given with every input rather than by the options
+    let later = now + chrono::Duration::days(7);
+    assert_eq!(
+        parser.parse("tomorrow", later).unwrap().to_string(),
+        "2024-06-09 12:00:00"
+    );
+
+    let range = parser
+        .parse_range("from today until release day", now)
+        .unwrap();
+    assert_eq!(range.end.date(), release);
+
+    let recurrence = parser.parse_recurrence("every monday", now).unwrap();
+    assert_eq!(
+        recurrence.occurrences().next().unwrap().to_string(),
+        "2024-06-03 12:00:00"
+    );
+}
@@ -0,0 +1,375 @@
+//! Recurring expressions, e.g. "every tuesday at 9am" or "every 2 weeks"
+
+use alloc::vec::Vec;
+use chrono::{
+    DateTime, Duration as ChronoDuration, Months, NaiveDateTime, NaiveTime, TimeZone, Weekday,
+};
+
+use crate::{ast, lexer, Error, ParseOptions};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The unit of the interval between occurrences of a recurrence
+pub enum Frequency {
+    Minutely,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A recurring datetime, as produced by `parse_recurrence`
+pub struct Recurrence {
+    /// The unit of the interval between occurrences
+    pub frequency: Frequency,
+    /// The number of units between occurrences, e.g. 2 for "every 2 weeks"
+    pub interval: u32,
+    /// The weekday of every occurrence, e.g. for "every tuesday"
+    pub weekday: Option<Weekday>,
+    /// The time of every occurrence, if the input gave one
+    pub time: Option<NaiveTime>,
+    /// The first occurrence at or after the reference datetime
+    pub start: NaiveDateTime,
+}
+
+impl Recurrence {
+    /// The occurrence `n` intervals after the start, if it can be
+    /// represented. Monthly and yearly occurrences are counted from the
+    /// start, so one starting on the 31st falls on the last day of shorter
+    /// months.
+    pub fn nth(&self, n: u32) -> Option<NaiveDateTime> {
+        let count = n.checked_mul(self.interval)?;
+        match self.frequency {
+            Frequency::Minutely => self
+                .start
+                .checked_add_signed(ChronoDuration::minutes(count.into())),
+            Frequency::Hourly => self
+                .start
+                .checked_add_signed(ChronoDuration::hours(count.into())),
+            Frequency::Daily => self
+                .start
+                .checked_add_signed(ChronoDuration::days(count.into())),
+            Frequency::Weekly => self
+                .start
+                .checked_add_signed(ChronoDuration::weeks(count.into())),
+            Frequency::Monthly => self.start.checked_add_months(Months::new(count)),
+            Frequency::Yearly => self
+                .start
+                .checked_add_months(Months::new(count.checked_mul(12)?)),
+        }
+    }
+
+    /// Every occurrence in order, starting with the first
+    ///
+    /// ```rust
+    /// use chrono::NaiveDateTime;
+    ///
+    /// let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    /// let recurrence = fuzzydate::parse_recurrence_relative_to("every tuesday at 9am", now).unwrap();
+    ///
+    /// let dates: Vec<_> = recurrence.occurrences().take(2).map(|d| d.to_string()).collect();
+    /// assert_eq!(dates, ["2024-06-04 09:00:00", "2024-06-11 09:00:00"]);
+    /// ```
+    pub fn occurrences(&self) -> Schedule {
+        self.schedule(self.start)
+    }
+
+    /// The occurrences at or after `after`
+    pub fn schedule(&self, after: NaiveDateTime) -> Schedule {
+        // Skip whole intervals of a fixed length at once
+        let step = match self.frequency {
+            Frequency::Minutely => Some(ChronoDuration::minutes(1)),
+            Frequency::Hourly => Some(ChronoDuration::hours(1)),
+            Frequency::Daily => Some(ChronoDuration::days(1)),
+            Frequency::Weekly => Some(ChronoDuration::weeks(1)),
+            Frequency::Monthly | Frequency::Yearly => None,
+        };
+        let skipped = match step {
+            Some(step) if after > self.start => {
+                let steps = (after - self.start).num_seconds() / step.num_seconds();
+                u32::try_from(steps / i64::from(self.interval.max(1))).unwrap_or(u32::MAX)
+            }
+            _ => 0,
+        };
+
+        let mut schedule = Schedule {
+            recurrence: self.clone(),
+            n: Some(skipped),
+        };
+        while schedule.peek().is_some_and(|date| date < after) {
+            schedule.next();
+        }
+
+        schedule
+    }
+
+    /// The occurrences from `start` up to but not including `end`
+    pub fn between(&self, start: NaiveDateTime, end: NaiveDateTime) -> Vec<NaiveDateTime> {
+        self.schedule(start)
+            .take_while(|&date| date < end)
+            .collect()
+    }
+
+    /// The occurrences at or after `after`, in its time zone. Occurrences
+    /// which don't exist in the time zone, in the gap of a DST transition,
+    /// are skipped, and ambiguous ones are at the earlier offset.
+    pub fn schedule_in<Tz: TimeZone>(&self, after: &DateTime<Tz>) -> ZonedSchedule<Tz> {
+        ZonedSchedule {
+            schedule: self.schedule(after.naive_local()),
+            after: after.clone(),
+        }
+    }
+
+    /// The occurrences from `start` up to but not including `end`, in the
+    /// time zone of `start`
+    pub fn between_in<Tz: TimeZone>(
+        &self,
+        start: &DateTime<Tz>,
+        end: &DateTime<Tz>,
+    ) -> Vec<DateTime<Tz>> {
+        self.schedule_in(start)
+            .take_while(|date| date < end)
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+/// An iterator over the occurrences of a [`Recurrence`], as produced by
+/// [`Recurrence::schedule`]
+pub struct Schedule {
+    recurrence: Recurrence,
+    /// The index of the next occurrence, `None` once out of range
+    n: Option<u32>,
+}
+
+impl Schedule {
+    /// The next occurrence, without advancing
+    pub fn peek(&self) -> Option<NaiveDateTime> {
+        self.recurrence.nth(self.n?)
+    }
+}
+
+impl Iterator for Schedule {
+    type Item = NaiveDateTime;
+
+    fn next(&mut self) -> Option<NaiveDateTime> {
+        let date = self.peek();
+        self.n = self
+            .n
+            .and_then(|n| n.checked_add(1))
+            .filter(|_| date.is_some());
+        date
+    }
+}
+
+#[derive(Debug, Clone)]
+/// An iterator over the occurrences of a [`Recurrence`] in a time zone, as
+/// produced by [`Recurrence::schedule_in`]
+pub struct ZonedSchedule<Tz: TimeZone> {
+    schedule: Schedule,
+    after: DateTime<Tz>,
+}
+
+impl<Tz: TimeZone> Iterator for ZonedSchedule<Tz> {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<DateTime<Tz>> {
+        loop {
+            let date = self.schedule.next()?;
+            let Some(date) = date.and_local_timezone(self.after.timezone()).earliest() else {
+                continue;
+            };
+
+            if date >= self.after {
+                return Some(date);
+            }
+        }
+    }
+}
+
+/// Parse an input string describing a recurrence, interpreting it
+/// according to the given options
+pub fn parse_recurrence_with_options(
+    input: impl AsRef<str>,
+    options: &ParseOptions,
+) -> Result<Recurrence, Error> {
+    let vocabulary = lexer::Vocabulary::new(options);
+    parse_recurrence_compiled(input.as_ref(), options, &vocabulary, None)
+}
+
+/// Parse an input string describing a recurrence with a vocabulary gathered
+/// from the options beforehand, starting from the given datetime or else
+/// the current time of the options
+pub(crate) fn parse_recurrence_compiled(
+    input: &str,
+    options: &ParseOptions,
+    vocabulary: &lexer::Vocabulary,
+    now: Option<NaiveDateTime>,
+) -> Result<Recurrence, Error> {
+    let (lexemes, spans, _) = lexer::Lexeme::lex_compiled(input, options, vocabulary)?;
+    let (tree, tokens) = ast::Recurrence::parse(lexemes.as_slice())
+        .ok_or_else(|| Error::parse_error_at(&spans, input.len()))?;
+    if options.minimal_grammar && tokens < lexemes.len() {
+        return Err(Error::trailing_tokens(&spans, tokens));
+    }
+
+    let now = now.map_or_else(|| options.now(), Ok)?;
+    tree.to_chrono(options.time(now), now, options)
+}
+
+/// Parse an input string describing a recurrence, treating the default as
+/// if it was the current time
+pub fn parse_recurrence_relative_to(
+    input: impl AsRef<str>,
+    default: NaiveDateTime,
+) -> Result<Recurrence, Error> {
+    parse_recurrence_with_options(input, &ParseOptions::new().relative_to(default))
+}
+
+/// Parse an input string describing a recurrence, starting from now
+#[cfg(feature = "clock")]
+pub fn parse_recurrence(input: impl AsRef<str>) -> Result<Recurrence, Error> {
+    parse_recurrence_with_options(input, &ParseOptions::new())
+}
+
+#[test]
+fn test_parse_recurrence() {
+    let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+    let date = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+
+    for (input, frequency, interval, start, second) in [
+        (
+            "daily at 9am",
+            Frequency::Daily,
+            1,
+            "2024-06-02 09:00",
+            "2024-06-03 09:00",
+        ),
+        (
+            "every day at 5pm",
+            Frequency::Daily,
+            1,
+            "2024-06-01 17:00",
+            "2024-06-02 17:00",
+        ),
+        (
+            "every 2 weeks",
+            Frequency::Weekly,
+            2,
+            "2024-06-01 12:00",
+            "2024-06-15 12:00",
+        ),
+        (
+            "every 90 minutes",
+            Frequency::Minutely,
+            90,
+            "2024-06-01 12:00",
+            "2024-06-01 13:30",
+        ),
+        (
+            "every saturday at noon",
+            Frequency::Weekly,
+            1,
+            "2024-06-01 12:00",
+            "2024-06-08 12:00",
+        ),
+        (
+            "every monday",
+            Frequency::Weekly,
+            1,
+            "2024-06-03 12:00",
+            "2024-06-10 12:00",
+        ),
+        (
+            "monthly at midnight",
+            Frequency::Monthly,
+            1,
+            "2024-07-01 00:00",
+            "2024-08-01 00:00",
+        ),
+        (
+            "yearly",
+            Frequency::Yearly,
+            1,
+            "2024-06-01 12:00",
+            "2025-06-01 12:00",
+        ),
+    ] {
+        let recurrence = parse_recurrence_relative_to(input, now).unwrap();
+        assert_eq!(recurrence.frequency, frequency, "{input}");
+        assert_eq!(recurrence.interval, interval, "{input}");
+        assert_eq!(recurrence.start, date(start), "{input}");
+        assert_eq!(recurrence.nth(1), Some(date(second)), "{input}");
+    }
+
+    let recurrence = parse_recurrence_relative_to("every friday at 9am", now).unwrap();
+    assert_eq!(recurrence.weekday, Some(Weekday::Fri));
+    assert_eq!(recurrence.time, NaiveTime::from_hms_opt(9, 0, 0));
+
+    #[cfg(feature = "lang-de")]
+    {
+        let options = ParseOptions::new()
+            .relative_to(now)
+            .language(&crate::lang::German);
+        let recurrence = parse_recurrence_with_options("jeden Dienstag um 9 Uhr", &options);
+        assert_eq!(recurrence.unwrap().start, date("2024-06-04 09:00"));
+    }
+
+    for input in ["every", "every 0 days", "weekly on", "tomorrow"] {
+        let options = ParseOptions::new().relative_to(now).minimal_grammar(true);
+        assert!(
+            parse_recurrence_with_options(input, &options).is_err(),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn test_schedule() {
+    let date = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+    let now = date("2024-06-01 12:00");
+
+    let recurrence = parse_recurrence_relative_to("every 2 days at 9am", now).unwrap();
+    let mut schedule = recurrence.schedule(date("2024-06-10 09:00"));
+    assert_eq!(recurrence.start, date("2024-06-03 09:00"));
+    assert_eq!(schedule.peek(), Some(date("2024-06-11 09:00")));
+    assert_eq!(schedule.nth(1), Some(date("2024-06-13 09:00")));
+
+    let dates = recurrence.between(date("2024-06-05 00:00"), date("2024-06-09 09:00"));
+    assert_eq!(dates, [date("2024-06-05 09:00"), date("2024-06-07 09:00")]);
+
+    // Months are counted from the start, not the previous occurrence
+    let recurrence = parse_recurrence_relative_to("monthly", date("2024-01-31 12:00")).unwrap();
+    let dates = recurrence.between(date("2024-02-01 00:00"), date("2024-05-01 00:00"));
+    assert_eq!(
+        dates,
+        [
+            date("2024-02-29 12:00"),
+            date("2024-03-31 12:00"),
+            date("2024-04-30 12:00")
+        ]
+    );
+}
+
+#[test]
+fn test_schedule_in() {
+    use chrono::FixedOffset;
+
+    let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+    let date = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+    let recurrence =
+        parse_recurrence_relative_to("daily at 9am", date("2024-06-01 12:00")).unwrap();
+
+    let start = date("2024-06-03 10:00").and_local_timezone(tz).unwrap();
+    let end = date("2024-06-06 00:00").and_local_timezone(tz).unwrap();
+    let dates: Vec<_> = recurrence
+        .between_in(&start, &end)
+        .iter()
+        .map(|d| d.to_rfc3339())
+        .collect();
+    assert_eq!(
+        dates,
+        ["2024-06-04T09:00:00+02:00", "2024-06-05T09:00:00+02:00"]
+    );
+}
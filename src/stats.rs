@@ -0,0 +1,87 @@
+//! Counters of the work done by a parse, enabled by the `stats` feature
+//!
+//! The parser counts into a thread local while the feature is enabled, and
+//! every counter is a no-op otherwise, so the instrumentation costs nothing
+//! unless asked for. Counters are reported by [`crate::parse_detailed`].
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// The work done by a single parse, for benchmarking and tuning the parser
+pub struct ParseStats {
+    /// Lexemes produced by the lexer
+    pub tokens: usize,
+    /// Grammar rules the parser tried to match
+    pub rule_attempts: usize,
+    /// Alternatives the parser abandoned to try the next one after they
+    /// had consumed some of the input
+    pub backtracks: usize,
+    /// Nodes of the tree allocated on the heap, including nodes of
+    /// alternatives which were abandoned
+    pub allocations: usize,
+    /// Rules whose result was reused from an earlier attempt at the same
+    /// position instead of being parsed again
+    pub memo_hits: usize,
+}
+
+#[cfg(feature = "stats")]
+std::thread_local! {
+    static STATS: core::cell::Cell<ParseStats> = const {
+        core::cell::Cell::new(ParseStats {
+            tokens: 0,
+            rule_attempts: 0,
+            backtracks: 0,
+            allocations: 0,
+            memo_hits: 0,
+        })
+    };
+}
+
+/// Update the counters of the current thread
+#[cfg(feature = "stats")]
+fn update(f: impl FnOnce(&mut ParseStats)) {
+    STATS.with(|stats| {
+        let mut current = stats.get();
+        f(&mut current);
+        stats.set(current);
+    });
+}
+
+#[cfg(not(feature = "stats"))]
+fn update(_f: impl FnOnce(&mut ParseStats)) {}
+
+/// Reset the counters before a parse
+pub(crate) fn reset() {
+    update(|stats| *stats = ParseStats::default());
+}
+
+/// The counters since the last reset
+#[cfg(feature = "stats")]
+pub(crate) fn take() -> ParseStats {
+    STATS.with(|stats| stats.take())
+}
+
+pub(crate) fn tokens(count: usize) {
+    update(|stats| stats.tokens += count);
+}
+
+pub(crate) fn attempt() {
+    update(|stats| stats.rule_attempts += 1);
+}
+
+/// Abandon an alternative which got as far as the given number of lexemes,
+/// which only counts as a backtrack if it consumed any
+pub(crate) fn backtrack(consumed: usize) {
+    if consumed > 0 {
+        update(|stats| stats.backtracks += 1);
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn memo_hit() {
+    update(|stats| stats.memo_hits += 1);
+}
+
+/// Allocate a node of the tree
+pub(crate) fn boxed<T>(value: T) -> alloc::boxed::Box<T> {
+    update(|stats| stats.allocations += 1);
+    alloc::boxed::Box::new(value)
+}
@@ -0,0 +1,219 @@
+//! Snapshot testing of interpretations, enabled by the `testing` feature
+//!
+//! Applications can record how a corpus of their phrases resolves against a
+//! fixed reference datetime, and check it again after upgrading, to find out
+//! which interpretations changed.
+//!
+//! ```rust
+//! use chrono::NaiveDateTime;
+//! use fuzzydate::testing::Corpus;
+//!
+//! let now = NaiveDateTime::parse_from_str("2024-06-01 12:00", "%Y-%m-%d %H:%M").unwrap();
+//! let snapshot = "\
+//!     tomorrow at 5pm => 2024-06-02 17:00:00
+//!     whenever => error
+//! ";
+//!
+//! let report = Corpus::from_snapshot(now, snapshot).unwrap().check();
+//! assert!(report.is_ok(), "{report}");
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use chrono::NaiveDateTime;
+
+use crate::{Error, ParseOptions};
+
+/// How datetimes are written in snapshots
+const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+#[derive(Debug, Clone)]
+/// A set of phrases with their expected resolutions against a fixed
+/// reference datetime
+pub struct Corpus {
+    options: ParseOptions,
+    cases: Vec<(String, Option<NaiveDateTime>)>,
+}
+
+impl Corpus {
+    /// Create an empty corpus resolved relative to `reference`
+    pub fn new(reference: NaiveDateTime) -> Self {
+        Self {
+            options: ParseOptions::new().relative_to(reference),
+            cases: Vec::new(),
+        }
+    }
+
+    /// Resolve the phrases with the given options, keeping the reference
+    pub fn options(mut self, options: ParseOptions) -> Self {
+        let reference = self.options.relative_to;
+        self.options = options;
+        self.options.relative_to = reference;
+        self
+    }
+
+    /// Register a phrase which should resolve to `expected`, or fail to
+    /// parse if it is `None`
+    pub fn case(mut self, phrase: impl Into<String>, expected: Option<NaiveDateTime>) -> Self {
+        self.cases.push((phrase.into(), expected));
+        self
+    }
+
+    /// Read a corpus from a snapshot written by [`Corpus::snapshot`], with
+    /// one `phrase => datetime` or `phrase => error` per line. Blank lines
+    /// and lines starting with `#` are skipped.
+    pub fn from_snapshot(reference: NaiveDateTime, snapshot: &str) -> Result<Self, Error> {
+        let mut corpus = Self::new(reference);
+
+        for (i, line) in snapshot.lines().map(str::trim).enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (phrase, expected) = line
+                .rsplit_once(" => ")
+                .ok_or_else(|| Error::invalid_snapshot(i + 1, format!("no \" => \" in {line}")))?;
+            let expected = match expected {
+                "error" => None,
+                date => Some(NaiveDateTime::parse_from_str(date, FORMAT).map_err(|_| {
+                    Error::invalid_snapshot(i + 1, format!("{date} is not a datetime"))
+                })?),
+            };
+
+            corpus = corpus.case(phrase, expected);
+        }
+
+        Ok(corpus)
+    }
+
+    /// Write the current resolution of every phrase in the format read by
+    /// [`Corpus::from_snapshot`], to record the corpus before an upgrade
+    pub fn snapshot(&self) -> String {
+        let mut snapshot = String::new();
+
+        for (phrase, _) in &self.cases {
+            let actual = crate::parse_with_options(phrase, &self.options);
+            snapshot += &format!("{phrase} => {}\n", Resolution(&actual));
+        }
+
+        snapshot
+    }
+
+    /// Resolve every phrase and compare it to its expected resolution
+    pub fn check(&self) -> Report {
+        let mismatches = self
+            .cases
+            .iter()
+            .filter_map(|(phrase, expected)| {
+                let actual = crate::parse_with_options(phrase, &self.options);
+                (actual.as_ref().ok() != expected.as_ref()).then(|| Mismatch {
+                    phrase: phrase.clone(),
+                    expected: *expected,
+                    actual,
+                })
+            })
+            .collect();
+
+        Report { mismatches }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A phrase whose resolution differs from the expected one
+pub struct Mismatch {
+    /// The phrase as registered
+    pub phrase: String,
+    /// The expected resolution, `None` if the phrase should fail to parse
+    pub expected: Option<NaiveDateTime>,
+    /// The actual resolution
+    pub actual: Result<NaiveDateTime, Error>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The result of checking a [`Corpus`]
+pub struct Report {
+    /// The phrases whose resolution changed, in the order they were
+    /// registered
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl Report {
+    /// Whether every phrase resolved as expected
+    pub fn is_ok(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+impl fmt::Display for Report {
+    /// Writes a diff of the changed resolutions, with the expected
+    /// resolution on a line starting with `-` and the actual one on a line
+    /// starting with `+`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for mismatch in &self.mismatches {
+            let expected = mismatch.expected.ok_or(Error::parse_error());
+            writeln!(f, "- {} => {}", mismatch.phrase, Resolution(&expected))?;
+            writeln!(
+                f,
+                "+ {} => {}",
+                mismatch.phrase,
+                Resolution(&mismatch.actual)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A resolution as written in snapshots and reports
+struct Resolution<'a>(&'a Result<NaiveDateTime, Error>);
+
+impl fmt::Display for Resolution<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Ok(date) => write!(f, "{}", date.format(FORMAT)),
+            Err(_) => f.write_str("error"),
+        }
+    }
+}
+
+#[test]
+fn test_corpus() {
+    let date = |s| NaiveDateTime::parse_from_str(s, FORMAT).unwrap();
+    let now = date("2024-06-01 12:00:00");
+
+    let corpus = Corpus::new(now)
+        .case("tomorrow", Some(date("2024-06-02 12:00:00")))
+        .case("3 days ago", Some(date("2024-05-28 12:00:00")))
+        .case("whenever", None);
+
+    let snapshot = corpus.snapshot();
+    assert_eq!(
+        snapshot,
+        "tomorrow => 2024-06-02 12:00:00\n3 days ago => 2024-05-29 12:00:00\nwhenever => error\n"
+    );
+
+    let report = corpus.check();
+    assert_eq!(report.mismatches.len(), 1);
+    assert_eq!(
+        report.to_string(),
+        "- 3 days ago => 2024-05-28 12:00:00\n+ 3 days ago => 2024-05-29 12:00:00\n"
+    );
+
+    // A recorded snapshot checks cleanly until the interpretation changes
+    let recorded = Corpus::from_snapshot(now, &snapshot).unwrap();
+    assert!(recorded.check().is_ok());
+    let options = ParseOptions::new().default_time(crate::DefaultTime::StartOfDay);
+    assert!(!recorded.options(options).check().is_ok());
+
+    match Corpus::from_snapshot(now, "tomorrow => 2024-06-02 12:00:00\n\ntomorrow") {
+        Err(Error::InvalidSnapshot(details)) => assert_eq!(details.line, 3),
+        other => panic!("unexpected result: {other:?}"),
+    }
+    assert!(matches!(
+        Corpus::from_snapshot(now, "tomorrow => soon"),
+        Err(Error::InvalidSnapshot(_))
+    ));
+}
@@ -0,0 +1,130 @@
+//! Parsing into the types of the `time` crate, enabled by the `time`
+//! feature
+//!
+//! These functions mirror the ones at the root of the crate. Input is lexed,
+//! parsed, and evaluated the same way, and only the reference and the result
+//! are converted.
+//!
+//! ```rust
+//! use time::macros::datetime;
+//!
+//! let now = datetime!(2024-06-01 12:00);
+//! let date = fuzzydate::time::parse_relative_to("tomorrow at 5pm", now).unwrap();
+//! assert_eq!(date, datetime!(2024-06-02 17:00));
+//! ```
+
+use ::time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+use alloc::format;
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, Timelike};
+
+use crate::{Error, ParseOptions};
+
+/// Convert a chrono datetime to a `time` datetime
+fn from_chrono(date: NaiveDateTime) -> Result<PrimitiveDateTime, Error> {
+    let invalid = |_| Error::invalid_date(format!("Out of range: {date}"));
+
+    let day = Date::from_ordinal_date(date.year(), date.ordinal() as u16).map_err(invalid)?;
+    let time = Time::from_hms_nano(
+        date.hour() as u8,
+        date.minute() as u8,
+        date.second() as u8,
+        date.nanosecond(),
+    )
+    .map_err(invalid)?;
+
+    Ok(PrimitiveDateTime::new(day, time))
+}
+
+/// Convert a `time` datetime to a chrono datetime
+fn to_chrono(date: PrimitiveDateTime) -> Result<NaiveDateTime, Error> {
+    NaiveDate::from_ymd_opt(
+        date.year(),
+        u8::from(date.month()) as u32,
+        date.day() as u32,
+    )
+    .and_then(|day| {
+        day.and_hms_nano_opt(
+            date.hour() as u32,
+            date.minute() as u32,
+            date.second() as u32,
+            date.nanosecond(),
+        )
+    })
+    .ok_or(Error::invalid_date(format!("Out of range: {date}")))
+}
+
+/// Parse an input string into a `time` datetime, interpreting it according
+/// to the given options
+pub fn parse_with_options(
+    input: impl AsRef<str>,
+    options: &ParseOptions,
+) -> Result<PrimitiveDateTime, Error> {
+    from_chrono(crate::parse_with_options(input, options)?)
+}
+
+/// Parse an input string into a `time` datetime, treating the default as if
+/// it was the current time
+pub fn parse_relative_to(
+    input: impl AsRef<str>,
+    default: PrimitiveDateTime,
+) -> Result<PrimitiveDateTime, Error> {
+    let options = ParseOptions::new().relative_to(to_chrono(default)?);
+    parse_with_options(input, &options)
+}
+
+/// Parse an input string into a `time` datetime relative to now
+#[cfg(feature = "clock")]
+pub fn parse(input: impl AsRef<str>) -> Result<PrimitiveDateTime, Error> {
+    parse_with_options(input, &ParseOptions::new())
+}
+
+/// Parse an input string into an offset `time` datetime, relative to `now`
+/// and at its offset, interpreting it according to the given options
+pub fn aware_parse_with_options(
+    input: impl AsRef<str>,
+    now: OffsetDateTime,
+    options: &ParseOptions,
+) -> Result<OffsetDateTime, Error> {
+    let offset = FixedOffset::east_opt(now.offset().whole_seconds()).ok_or(Error::invalid_date(
+        format!("Invalid offset: {}", now.offset()),
+    ))?;
+    let local = to_chrono(PrimitiveDateTime::new(now.date(), now.time()))?;
+    let now: DateTime<FixedOffset> = local
+        .and_local_timezone(offset)
+        .single()
+        .ok_or(Error::invalid_date(format!("Invalid datetime: {local}")))?;
+
+    let date = crate::aware_parse_with_options(input, now, options)?;
+    let offset = UtcOffset::from_whole_seconds(date.offset().local_minus_utc())
+        .map_err(|_| Error::invalid_date(format!("Invalid offset: {}", date.offset())))?;
+
+    Ok(from_chrono(date.naive_local())?.assume_offset(offset))
+}
+
+/// Parse an input string into an offset `time` datetime, relative to `now`
+/// and at its offset
+pub fn aware_parse(input: impl AsRef<str>, now: OffsetDateTime) -> Result<OffsetDateTime, Error> {
+    aware_parse_with_options(input, now, &ParseOptions::new())
+}
+
+#[test]
+fn test_conversion_round_trip() {
+    let date = NaiveDate::from_ymd_opt(2024, 2, 29)
+        .unwrap()
+        .and_hms_nano_opt(23, 59, 58, 123_456_789)
+        .unwrap();
+    let converted = from_chrono(date).unwrap();
+
+    assert_eq!(converted.month(), ::time::Month::February);
+    assert_eq!(converted.nanosecond(), 123_456_789);
+    assert_eq!(to_chrono(converted).unwrap(), date);
+}
+
+#[test]
+fn test_aware_parse() {
+    use ::time::macros::datetime;
+
+    let now = datetime!(2024-06-01 12:00 +02:00);
+    let date = aware_parse("3 days after tomorrow at 5pm", now).unwrap();
+    assert_eq!(date, datetime!(2024-06-05 17:00 +02:00));
+}